@@ -7,7 +7,7 @@ fn main() {
     table.set_width(85);
 
     let mut row = Row::new();
-    row.add_cell(Cell::new(format!(
+    row.add_cell(Cell::raw(format!(
         "List of devices:\n{}",
         console::style("Blockdevices\nCryptdevices").dim().blue()
     )));
@@ -16,7 +16,7 @@ fn main() {
     table.add_row(row);
 
     let mut row = Row::new();
-    row.add_cell(Cell::new(format!(
+    row.add_cell(Cell::raw(format!(
         "Block devices: \n/dev/{}\n/dev/{}",
         console::style("sda1").bold().red(),
         console::style("sda2").bold().red()
@@ -25,7 +25,7 @@ fn main() {
     table.add_row(row);
 
     let mut row = Row::new();
-    row.add_cell(Cell::new(format!(
+    row.add_cell(Cell::raw(format!(
         "Crypt devices: \n/dev/mapper/{}",
         console::style("cryptroot").bold().yellow()
     )));
@@ -1,6 +1,12 @@
-use crate::style::CellAlignment;
+use std::hash::{Hash, Hasher};
+
 #[cfg(feature = "tty")]
 use crate::{Attribute, Color};
+use crate::{
+    Table,
+    style::{CellAlignment, WidthTable},
+    utils::formatting::{content_split::measure_text_width, sanitize::strip_escape_sequences},
+};
 
 /// A stylable table cell with content.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -13,6 +19,17 @@ pub struct Cell {
     /// The default is ` `.
     pub(crate) delimiter: Option<char>,
     pub(crate) alignment: Option<CellAlignment>,
+    pub(crate) never_truncate: bool,
+    /// Whether the content of this cell is ignored when measuring the width of its column.
+    pub(crate) ignore_width: bool,
+    pub(crate) colspan: usize,
+    pub(crate) rowspan: usize,
+    /// The padding of this cell, which overrides the padding of its column.
+    pub(crate) padding: Option<(u16, u16)>,
+    /// A table that's displayed as the content of this cell.
+    pub(crate) nested: Option<NestedTable>,
+    /// Whether a box is drawn around the content of this cell.
+    pub(crate) boxed: bool,
     #[cfg(feature = "tty")]
     pub(crate) fg: Option<Color>,
     #[cfg(feature = "tty")]
@@ -29,7 +46,31 @@ impl Cell {
     }
 
     /// Create a new Cell from an owned String
+    ///
+    /// Terminal escape sequences and control characters are removed from the content, so
+    /// untrusted input cannot mess with the table or the terminal.
+    /// Use [Cell::raw] for content, which has already been styled.
     pub fn new_owned(content: String) -> Self {
+        Self::raw(strip_escape_sequences(&content))
+    }
+
+    /// Create a new Cell from trusted content, which is kept as it is.
+    ///
+    /// Use this for text that has already been styled with ANSI escape sequences.
+    /// With the `custom_styling` feature, the width of such content is measured correctly and its
+    /// styling is preserved when it is wrapped or truncated.
+    ///
+    /// ```
+    /// use comfy_table::Cell;
+    ///
+    /// let styled = "\u{1b}[31mred\u{1b}[0m";
+    ///
+    /// assert_eq!(Cell::new(styled).content(), "red");
+    /// assert_eq!(Cell::raw(styled).content(), styled);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn raw<T: ToString>(content: T) -> Self {
+        let content = content.to_string();
         #[cfg_attr(not(feature = "custom_styling"), allow(unused_mut))]
         let mut split_content: Vec<String> = content.split('\n').map(ToString::to_string).collect();
 
@@ -41,6 +82,13 @@ impl Cell {
             content: split_content,
             delimiter: None,
             alignment: None,
+            never_truncate: false,
+            ignore_width: false,
+            colspan: 1,
+            rowspan: 1,
+            padding: None,
+            nested: None,
+            boxed: false,
             #[cfg(feature = "tty")]
             fg: None,
             #[cfg(feature = "tty")]
@@ -50,6 +98,71 @@ impl Cell {
         }
     }
 
+    /// Create a new Cell, which displays another table as its content.
+    ///
+    /// The inner table is rendered with the width that's available in the column of the cell.
+    /// Give the inner table a [dynamic](crate::ContentArrangement::Dynamic) arrangement, so its
+    /// columns shrink to fit into narrow columns of the outer table.\
+    /// The inner table is rendered without any styling.
+    ///
+    /// ```
+    /// use comfy_table::{Cell, ContentArrangement, Table};
+    ///
+    /// let mut details = Table::new();
+    /// details
+    ///     .set_content_arrangement(ContentArrangement::Dynamic)
+    ///     .add_row(vec!["Vendor", "ACME"])
+    ///     .add_row(vec!["Firmware", "1.2.3"]);
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Device", "Details"])
+    ///     .add_row(vec![Cell::new("eth0"), Cell::from_table(details)]);
+    /// ```
+    pub fn from_table(table: Table) -> Self {
+        let nested = NestedTable(Box::new(table));
+        let mut cell = Self::raw(nested.render(None).join("\n"));
+        cell.nested = Some(nested);
+
+        cell
+    }
+
+    /// Create a new Cell from multiple fragments, which are styled independently.
+    ///
+    /// The text of each fragment is styled with the styling of the given [Cell].
+    /// The fragments are concatenated and measured and wrapped as a single text.
+    ///
+    /// ```
+    /// use comfy_table::{Cell, Color};
+    ///
+    /// let cell = Cell::composite(vec![
+    ///     Cell::new("3 passed").fg(Color::Green),
+    ///     Cell::new(", "),
+    ///     Cell::new("1 failed").fg(Color::Red),
+    /// ]);
+    ///
+    /// let content = console::strip_ansi_codes(&cell.content()).to_string();
+    /// assert_eq!(content, "3 passed, 1 failed");
+    /// ```
+    #[cfg(feature = "custom_styling")]
+    pub fn composite<I: IntoIterator<Item = Cell>>(fragments: I) -> Self {
+        use crate::utils::formatting::content_format::style_line;
+
+        let content: String = fragments
+            .into_iter()
+            .map(|fragment| {
+                fragment
+                    .content
+                    .iter()
+                    .map(|line| style_line(line.clone(), &fragment))
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            })
+            .collect();
+
+        Self::raw(content)
+    }
+
     /// Return a copy of the content contained in this cell.
     pub fn content(&self) -> String {
         self.content.join("\n")
@@ -81,6 +194,213 @@ impl Cell {
         self
     }
 
+    /// Make sure the content of this cell is never truncated or wrapped.
+    ///
+    /// The dynamic arrangement tries to give the cell's column enough space to display the whole
+    /// content and [Row::max_height](crate::Row::max_height) won't cut off any lines of this cell.\
+    /// If the table is too narrow to honor this, rendering a [strict](crate::Table::set_strict)
+    /// table via [Table::try_lines](crate::Table::try_lines) returns an error.
+    ///
+    /// ```
+    /// use comfy_table::Cell;
+    ///
+    /// let mut cell = Cell::new("a1b2c3d4-e5f6").set_never_truncate(true);
+    /// ```
+    #[must_use]
+    pub fn set_never_truncate(mut self, never_truncate: bool) -> Self {
+        self.never_truncate = never_truncate;
+
+        self
+    }
+
+    /// Returns whether this cell may be truncated or wrapped.
+    pub fn is_never_truncate(&self) -> bool {
+        self.never_truncate
+    }
+
+    /// Ignore the content of this cell when measuring the width of its column.
+    ///
+    /// The column is then arranged as if the cell was empty. The content is still wrapped or
+    /// truncated to fit into the arranged column.\
+    /// This is useful for huge cells, which would otherwise make their column as wide as possible.
+    ///
+    /// ```
+    /// use comfy_table::{Cell, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Name", "Description"])
+    ///     .add_row(vec![
+    ///         Cell::new("foo"),
+    ///         Cell::new("A huge blob of text").set_ignore_width(true),
+    ///     ]);
+    ///
+    /// let expected = "
+    /// +------+-------------+
+    /// | Name | Description |
+    /// +====================+
+    /// | foo  | A huge blob |
+    /// |      | of text     |
+    /// +------+-------------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    #[must_use]
+    pub fn set_ignore_width(mut self, ignore_width: bool) -> Self {
+        self.ignore_width = ignore_width;
+
+        self
+    }
+
+    /// Returns whether the content of this cell is ignored when measuring the width of its column.
+    pub fn is_ignore_width(&self) -> bool {
+        self.ignore_width
+    }
+
+    /// Let this cell span multiple columns.
+    ///
+    /// The following cells of the row are moved to the right accordingly.
+    /// The spanned columns are merged into a single cell without any vertical lines.
+    ///
+    /// ```
+    /// use comfy_table::{Cell, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec![
+    ///         Cell::new("Interface"),
+    ///         Cell::new("RX stats").set_colspan(2),
+    ///     ])
+    ///     .add_row(vec!["eth0", "1024 pkts", "2 MB"]);
+    /// ```
+    #[must_use]
+    pub fn set_colspan(mut self, colspan: usize) -> Self {
+        self.colspan = colspan.max(1);
+
+        self
+    }
+
+    /// Returns the amount of columns this cell spans.
+    pub fn colspan(&self) -> usize {
+        self.colspan
+    }
+
+    /// Let this cell span multiple rows.
+    ///
+    /// The spanned columns of the following rows are occupied by this cell. Cells of these rows
+    /// are placed in the remaining columns.\
+    /// Cells in the header cannot span into the rows of the table.
+    ///
+    /// ```
+    /// use comfy_table::{Cell, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .add_row(vec![Cell::new("eth0").set_rowspan(2), Cell::new("RX")])
+    ///     .add_row(vec!["TX"]);
+    /// ```
+    #[must_use]
+    pub fn set_rowspan(mut self, rowspan: usize) -> Self {
+        self.rowspan = rowspan.max(1);
+
+        self
+    }
+
+    /// Returns the amount of rows this cell spans.
+    pub fn rowspan(&self) -> usize {
+        self.rowspan
+    }
+
+    /// Set the padding of this cell, which overrides the padding of its column.
+    ///
+    /// Padding is provided in the form of (left, right).\
+    /// The column is widened, if the cell needs more space than the other cells of the column.
+    ///
+    /// ```
+    /// use comfy_table::Cell;
+    ///
+    /// let cell = Cell::new("Some content").set_padding((0, 2));
+    /// assert_eq!(cell.padding(), Some((0, 2)));
+    /// ```
+    #[must_use]
+    pub fn set_padding(mut self, padding: (u16, u16)) -> Self {
+        self.padding = Some(padding);
+
+        self
+    }
+
+    /// Get the padding of this cell, if it overrides the padding of its column.
+    pub fn padding(&self) -> Option<(u16, u16)> {
+        self.padding
+    }
+
+    /// Draw a box around the content of this cell to call attention to it.
+    ///
+    /// The box is drawn inside of the cell's padding with the border characters of the table.
+    /// If the table doesn't have any borders, ASCII characters are used instead.\
+    /// The box and its inner padding need four additional characters of width and two
+    /// additional lines.
+    ///
+    /// ```
+    /// use comfy_table::{Cell, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec![Cell::new("CPU"), Cell::new("99%").set_boxed(true)]);
+    ///
+    /// let expected = "
+    /// +-----+---------+
+    /// | CPU | +-----+ |
+    /// |     | | 99% | |
+    /// |     | +-----+ |
+    /// +-----+---------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    #[must_use]
+    pub fn set_boxed(mut self, boxed: bool) -> Self {
+        self.boxed = boxed;
+
+        self
+    }
+
+    /// Returns whether a box is drawn around the content of this cell.
+    pub fn is_boxed(&self) -> bool {
+        self.boxed
+    }
+
+    /// Get the width of the longest line of this cell.
+    /// The box of a [boxed](Cell::set_boxed) cell is included.
+    pub(crate) fn max_content_width(&self, widths: &WidthTable) -> usize {
+        let width = self
+            .content
+            .iter()
+            .map(|string| measure_text_width(string, widths))
+            .max()
+            .unwrap_or(0);
+
+        if self.boxed { width + 4 } else { width }
+    }
+
+    /// Get the width this cell needs inside of a column with the given padding.
+    ///
+    /// If the cell has its own padding, the difference to the column's padding is included.
+    pub(crate) fn max_content_width_with_padding(
+        &self,
+        widths: &WidthTable,
+        column_padding: (u16, u16),
+    ) -> usize {
+        if self.ignore_width {
+            return 0;
+        }
+
+        let width = self.max_content_width(widths);
+        let Some((left, right)) = self.padding else {
+            return width;
+        };
+
+        let padding = usize::from(left) + usize::from(right);
+        let column_padding = usize::from(column_padding.0) + usize::from(column_padding.1);
+        (width + padding).saturating_sub(column_padding)
+    }
+
     /// Set the foreground text color for this cell.
     ///
     /// Look at [Color](crate::Color) for a list of all possible Colors.
@@ -140,6 +460,40 @@ impl Cell {
     }
 }
 
+/// A table, which is displayed as the content of a cell.
+///
+/// Nested tables are compared and hashed by their rendered output.
+#[derive(Clone, Debug)]
+pub(crate) struct NestedTable(Box<Table>);
+
+impl NestedTable {
+    /// Render the table, so it fits into the given width if possible.
+    pub(crate) fn render(&self, width: Option<u16>) -> Vec<String> {
+        let mut table = (*self.0).clone();
+        #[cfg(feature = "tty")]
+        table.force_no_tty();
+        if let Some(width) = width {
+            table.set_width(width);
+        }
+
+        table.lines().collect()
+    }
+}
+
+impl PartialEq for NestedTable {
+    fn eq(&self, other: &Self) -> bool {
+        self.render(None) == other.render(None)
+    }
+}
+
+impl Eq for NestedTable {}
+
+impl Hash for NestedTable {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.render(None).hash(state);
+    }
+}
+
 /// Convert anything with [ToString] to a new [Cell].
 ///
 /// ```
@@ -190,4 +544,16 @@ mod tests {
 
         assert_eq!(cell.content(), content);
     }
+
+    #[test]
+    fn test_nested_table_equality() {
+        let nested = |content: &str| {
+            let mut table = Table::new();
+            table.add_row(vec![content]);
+            NestedTable(Box::new(table))
+        };
+
+        assert_eq!(nested("ACME"), nested("ACME"));
+        assert_ne!(nested("ACME"), nested("Initech"));
+    }
 }
@@ -1,4 +1,8 @@
-use crate::style::{CellAlignment, ColumnConstraint};
+use std::{fmt, sync::Arc};
+
+#[cfg(feature = "tty")]
+use crate::Color;
+use crate::style::{CellAlignment, ColumnConstraint, NumericFormat, TruncationStyle, WrapPolicy};
 
 /// A representation of a table's column.
 /// Useful for styling and specifying constraints how big a column should be.
@@ -39,6 +43,32 @@ pub struct Column {
     /// Define the [CellAlignment] for all cells of this column
     pub(crate) cell_alignment: Option<CellAlignment>,
     pub(crate) constraint: Option<ColumnConstraint>,
+    pub(crate) no_wrap: bool,
+    pub(crate) wrap_policy: WrapPolicy,
+    pub(crate) truncation: Option<TruncationStyle>,
+    /// A glyph displayed in front of the header and its plain ASCII fallback.
+    pub(crate) header_icon: Option<(String, String)>,
+    /// Checks whether the content of a cell in this column is valid.
+    pub(crate) validator: Option<Validator>,
+    /// Columns with a priority are hidden, if the table gets too narrow to display them.
+    pub(crate) hide_priority: Option<u8>,
+    /// The content width this column is expected to need, even if its content is narrower.
+    pub(crate) expected_width: Option<u16>,
+    /// How numbers in the cells of this column are displayed.
+    pub(crate) numeric_format: Option<NumericFormat>,
+    /// The background color, which is painted through all rows of this column.
+    #[cfg(feature = "tty")]
+    pub(crate) bg: Option<Color>,
+}
+
+/// A check for the content of a column's cells.
+#[derive(Clone)]
+pub(crate) struct Validator(Arc<dyn Fn(&str) -> bool + Send + Sync>);
+
+impl fmt::Debug for Validator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Validator")
+    }
 }
 
 impl Column {
@@ -49,6 +79,16 @@ impl Column {
             delimiter: None,
             constraint: None,
             cell_alignment: None,
+            no_wrap: false,
+            wrap_policy: WrapPolicy::Words,
+            truncation: None,
+            header_icon: None,
+            validator: None,
+            hide_priority: None,
+            expected_width: None,
+            numeric_format: None,
+            #[cfg(feature = "tty")]
+            bg: None,
         }
     }
 
@@ -103,6 +143,330 @@ impl Column {
         matches!(self.constraint, Some(ColumnConstraint::Hidden))
     }
 
+    /// Keep the content of this column's cells on a single line.
+    ///
+    /// Content that doesn't fit is cut off and marked with a `▶` at the cut point.
+    /// The same happens to cells with multiple lines, only the first line is displayed.\
+    /// The full content of a cell can still be retrieved via
+    /// [Table::cell_content](crate::Table::cell_content).
+    pub fn set_no_wrap(&mut self, no_wrap: bool) -> &mut Self {
+        self.no_wrap = no_wrap;
+
+        self
+    }
+
+    /// Returns whether the content of this column is kept on a single line.
+    pub fn is_no_wrap(&self) -> bool {
+        self.no_wrap
+    }
+
+    /// Control where the content of this column's cells may be split.
+    ///
+    /// ```
+    /// use comfy_table::{ContentArrangement, Table, WrapPolicy};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_content_arrangement(ContentArrangement::Dynamic)
+    ///     .set_width(20)
+    ///     .add_row(vec!["/usr/local/share/comfy-table"]);
+    /// table
+    ///     .column_mut(0)
+    ///     .unwrap()
+    ///     .set_wrap_policy(WrapPolicy::BreakOn(&['/']));
+    ///
+    /// let expected = "
+    /// +-------------+
+    /// | /usr/local/ |
+    /// | share/      |
+    /// | comfy-table |
+    /// +-------------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_wrap_policy(&mut self, policy: WrapPolicy) -> &mut Self {
+        self.wrap_policy = policy;
+
+        self
+    }
+
+    /// Returns where the content of this column's cells may be split.
+    pub fn wrap_policy(&self) -> WrapPolicy {
+        self.wrap_policy
+    }
+
+    /// Truncate the content of this column's cells instead of wrapping it.
+    ///
+    /// Each cell is displayed on a single line. Multiple lines of a cell are joined by spaces.\
+    /// Content that doesn't fit into the column is cut off and replaced by the indicator of the
+    /// given [TruncationStyle].
+    ///
+    /// ```
+    /// use comfy_table::{ColumnConstraint::Absolute, Table, TruncationStyle, Width::Fixed};
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["C:/Users/alice/Documents/report.pdf"]);
+    /// table
+    ///     .column_mut(0)
+    ///     .unwrap()
+    ///     .set_constraint(Absolute(Fixed(24)))
+    ///     .set_truncation(TruncationStyle::Middle("…".to_string()));
+    ///
+    /// let expected = "
+    /// +------------------------+
+    /// | C:/Users/al…report.pdf |
+    /// +------------------------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_truncation(&mut self, style: TruncationStyle) -> &mut Self {
+        self.truncation = Some(style);
+
+        self
+    }
+
+    /// Returns how the content of this column's cells is truncated, if it's truncated at all.
+    pub fn truncation(&self) -> Option<&TruncationStyle> {
+        self.truncation.as_ref()
+    }
+
+    /// Wrap the content of this column's cells again, instead of truncating it.
+    pub fn remove_truncation(&mut self) -> &mut Self {
+        self.truncation = None;
+
+        self
+    }
+
+    /// Display a small glyph in front of the header of this column, e.g. `⚠` for an alerts column.
+    ///
+    /// The `fallback` is displayed instead, if the table is rendered with plain ASCII characters
+    /// via [Table::to_copy_friendly_string](crate::Table::to_copy_friendly_string).\
+    /// The icon is separated by a space from the header and is part of the header's width.
+    /// Header cells that span multiple columns don't get an icon.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.set_header(vec!["Alerts"]).add_row(vec!["2"]);
+    /// table.column_mut(0).unwrap().set_header_icon("⚠", "!");
+    ///
+    /// assert!(table.to_string().contains("⚠ Alerts"));
+    /// assert!(table.to_copy_friendly_string().contains("! Alerts"));
+    /// ```
+    pub fn set_header_icon(&mut self, icon: &str, fallback: &str) -> &mut Self {
+        self.header_icon = Some((icon.to_string(), fallback.to_string()));
+
+        self
+    }
+
+    /// Returns the header icon of this column and its fallback, if any.
+    pub fn header_icon(&self) -> Option<(&str, &str)> {
+        self.header_icon
+            .as_ref()
+            .map(|(icon, fallback)| (icon.as_str(), fallback.as_str()))
+    }
+
+    /// Validate the content of all cells of this column, excluding the header and footer.
+    ///
+    /// The validator gets the content of a cell and returns whether it's valid.
+    /// Regular expressions can be used by calling their matching function inside of the closure.\
+    /// Invalid cells are displayed in red, if the table is styled and they don't have a foreground
+    /// color of their own.
+    /// All invalid cells are listed by [Table::validation_errors](crate::Table::validation_errors).
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Host", "Port"])
+    ///     .add_row(vec!["web-1", "443"])
+    ///     .add_row(vec!["web-2", "https"]);
+    /// table
+    ///     .column_mut(1)
+    ///     .unwrap()
+    ///     .set_validator(|content| content.parse::<u16>().is_ok());
+    ///
+    /// assert_eq!(table.validation_errors().len(), 1);
+    /// ```
+    pub fn set_validator<F>(&mut self, validator: F) -> &mut Self
+    where
+        F: Fn(&str) -> bool + Send + Sync + 'static,
+    {
+        self.validator = Some(Validator(Arc::new(validator)));
+
+        self
+    }
+
+    /// Remove the validator of this column.
+    pub fn remove_validator(&mut self) -> &mut Self {
+        self.validator = None;
+
+        self
+    }
+
+    /// Returns whether the given content is valid for this column.
+    /// Content is always valid, if the column doesn't have a validator.
+    pub fn is_valid(&self, content: &str) -> bool {
+        self.validator
+            .as_ref()
+            .is_none_or(|validator| (validator.0)(content))
+    }
+
+    /// Allow this column to be hidden, if a [dynamic](crate::ContentArrangement::Dynamic) table
+    /// gets too narrow to display all of its columns in a readable width.
+    ///
+    /// Columns with a lower priority are hidden first.
+    /// Columns without a priority are never hidden automatically.
+    ///
+    /// ```
+    /// use comfy_table::{ContentArrangement, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Name", "Description", "Notes"])
+    ///     .add_row(vec!["comfy-table", "Build beautiful terminal tables", "Rust"])
+    ///     .set_content_arrangement(ContentArrangement::Dynamic)
+    ///     .set_width(20);
+    /// table.column_mut(2).unwrap().set_hide_priority(0);
+    ///
+    /// assert!(!table.to_string().contains("Notes"));
+    /// ```
+    pub fn set_hide_priority(&mut self, priority: u8) -> &mut Self {
+        self.hide_priority = Some(priority);
+
+        self
+    }
+
+    /// Returns the priority with which this column is hidden in narrow tables, if any.
+    pub fn hide_priority(&self) -> Option<u8> {
+        self.hide_priority
+    }
+
+    /// Never hide this column automatically.
+    pub fn remove_hide_priority(&mut self) -> &mut Self {
+        self.hide_priority = None;
+
+        self
+    }
+
+    /// Paint the background of this column with the given color.
+    ///
+    /// The color is painted through the padding and all lines of all cells, including empty
+    /// cells and rows without a cell in this column. This results in colored column bands, which
+    /// make wide tables easier to scan.\
+    /// Background colors of [cells](crate::Cell::bg), [rows](crate::Row::set_bg) and the
+    /// [zebra striping](crate::Table::set_zebra_striping) take precedence.
+    ///
+    /// ```
+    /// use comfy_table::{Color, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Name", "Load", "Memory"])
+    ///     .add_row(vec!["web-1", "0.3", "12%"]);
+    /// table.column_mut(1).unwrap().set_bg(Color::DarkGrey);
+    /// ```
+    #[cfg(feature = "tty")]
+    pub fn set_bg(&mut self, color: Color) -> &mut Self {
+        self.bg = Some(color);
+
+        self
+    }
+
+    /// Returns the background color of this column, if any.
+    #[cfg(feature = "tty")]
+    pub fn bg(&self) -> Option<Color> {
+        self.bg
+    }
+
+    /// Reserve space for content of the given width in [dynamic](crate::ContentArrangement::Dynamic)
+    /// tables, even if the current content of this column is narrower.
+    ///
+    /// This is only a hint. The column may still be shrunk, if the table gets too narrow.\
+    /// Use it to keep the layout stable, when the same table is rendered again with changing data.
+    ///
+    /// ```
+    /// use comfy_table::{ContentArrangement, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_content_arrangement(ContentArrangement::Dynamic)
+    ///     .set_width(80)
+    ///     .add_row(vec!["CPU", "4%"]);
+    /// table.column_mut(1).unwrap().set_expected_width(4);
+    ///
+    /// assert_eq!(table.column_display_widths(), vec![5, 6]);
+    /// ```
+    pub fn set_expected_width(&mut self, width: u16) -> &mut Self {
+        self.expected_width = Some(width);
+
+        self
+    }
+
+    /// Returns the expected content width of this column, if any.
+    pub fn expected_width(&self) -> Option<u16> {
+        self.expected_width
+    }
+
+    /// Remove the expected content width of this column.
+    pub fn remove_expected_width(&mut self) -> &mut Self {
+        self.expected_width = None;
+
+        self
+    }
+
+    /// Display all numbers in this column's cells with the given [NumericFormat].
+    ///
+    /// Numbers are [anchored](CellAlignment::Anchor) at their decimal point, unless their cell
+    /// has its own alignment. The header, cells that span multiple columns and all other
+    /// content are left as they are.\
+    /// The format is only applied when displaying the table. The content of the cells and
+    /// exports such as CSV keep the original numbers.
+    ///
+    /// ```
+    /// use comfy_table::{NumericFormat, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Price"])
+    ///     .add_row(vec!["1234.5"])
+    ///     .add_row(vec!["0.249"])
+    ///     .add_row(vec!["n/a"]);
+    ///
+    /// let mut format = NumericFormat::new();
+    /// format.set_precision(2).set_thousands_separator(',');
+    /// table.column_mut(0).unwrap().set_numeric_format(format);
+    ///
+    /// let expected = "
+    /// +----------+
+    /// | Price    |
+    /// +==========+
+    /// | 1,234.50 |
+    /// |----------|
+    /// |     0.25 |
+    /// |----------|
+    /// | n/a      |
+    /// +----------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_numeric_format(&mut self, format: NumericFormat) -> &mut Self {
+        self.numeric_format = Some(format);
+
+        self
+    }
+
+    /// Returns the format of numbers in this column, if any.
+    pub fn numeric_format(&self) -> Option<&NumericFormat> {
+        self.numeric_format.as_ref()
+    }
+
+    /// Display the numbers in this column as they are.
+    pub fn remove_numeric_format(&mut self) -> &mut Self {
+        self.numeric_format = None;
+
+        self
+    }
+
     /// Set the alignment for content inside of cells for this column.\
     /// **Note:** Alignment on a cell will always overwrite the column's setting.
     pub fn set_cell_alignment(&mut self, alignment: CellAlignment) {
@@ -0,0 +1,209 @@
+use std::fmt;
+
+#[cfg(feature = "tty")]
+use crate::Color;
+
+/// Errors that may occur while arranging the content of a table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LayoutError {
+    /// The table doesn't fit into the requested width without wrapping content.
+    TooWide {
+        /// The width the table has to fit into.
+        width: u16,
+        /// The width the table needs to display all content without wrapping.
+        required: usize,
+        /// All columns whose content doesn't fit into their arranged width.
+        columns: Vec<ColumnOverflow>,
+    },
+    /// The table is narrower than its borders, the padding of its columns and a single character
+    /// per column.
+    TooNarrow {
+        /// The width the table has to fit into.
+        width: u16,
+        /// The smallest width the table can be displayed in.
+        minimum: usize,
+    },
+    /// A cell marked via [Cell::set_never_truncate](crate::Cell::set_never_truncate) doesn't fit
+    /// into its column.
+    CellTruncated {
+        /// The index of the cell's row. `None` if the cell is part of the header.
+        row: Option<usize>,
+        /// The index of the cell's column.
+        column: usize,
+        /// The width of the cell's content.
+        width: usize,
+        /// The content width of the column after arrangement.
+        available: usize,
+        /// The source of the cell's row, if it has been set via
+        /// [Row::set_source](crate::Row::set_source).
+        source: Option<String>,
+    },
+}
+
+/// A column whose content is wider than the space it got during arrangement.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ColumnOverflow {
+    /// The index of the column.
+    pub index: usize,
+    /// The amount of characters that don't fit into the column.
+    pub overflow: usize,
+}
+
+/// A rendered line, whose width differs from the width of the table.
+///
+/// Mismatches are found via [Table::audit_widths](crate::Table::audit_widths).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WidthMismatch {
+    /// The index of the line in the rendered table.
+    pub line: usize,
+    /// The width of the table.
+    pub expected: usize,
+    /// The actual width of the line.
+    pub actual: usize,
+}
+
+/// A cell, whose content doesn't fit into its column and is therefore wrapped or truncated.
+///
+/// Overflowing cells are listed by [Table::overflow_report](crate::Table::overflow_report).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CellOverflow {
+    /// The index of the cell's row. `None` if the cell is part of the header.
+    pub row: Option<usize>,
+    /// The index of the cell's column.
+    pub column: usize,
+    /// The width of the cell's content.
+    pub width: usize,
+    /// The content width of the column after arrangement.
+    pub available: usize,
+    /// Whether the content is cut off instead of being wrapped.
+    pub truncated: bool,
+}
+
+/// A combination of styles, which likely makes a table hard or impossible to read.
+///
+/// Warnings are listed by [Table::lint_styles](crate::Table::lint_styles).
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StyleWarning {
+    /// The text of a cell has the same color as its background.
+    #[cfg(feature = "tty")]
+    SameColors {
+        /// The index of the cell's row. `None` if the cell is part of the header.
+        row: Option<usize>,
+        /// The index of the cell's column.
+        column: usize,
+        /// The color of both the text and the background.
+        color: Color,
+    },
+    /// The table is colored, but colors are disabled via the `NO_COLOR` environment variable.
+    #[cfg(feature = "tty")]
+    ColorsDisabled,
+    /// A column is too narrow to display any part of its header.\
+    /// This happens, if the header of a column, which cuts off its content, doesn't fit and only
+    /// the truncation indicator is left.
+    HiddenHeader {
+        /// The index of the column.
+        column: usize,
+    },
+}
+
+impl fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LayoutError::TooWide {
+                width,
+                required,
+                columns,
+            } => {
+                write!(
+                    f,
+                    "Table requires a width of {required}, but only {width} is available"
+                )?;
+                for column in columns {
+                    write!(
+                        f,
+                        "\n  column {} overflows by {}",
+                        column.index, column.overflow
+                    )?;
+                }
+
+                Ok(())
+            }
+            LayoutError::TooNarrow { width, minimum } => write!(
+                f,
+                "Table requires a width of at least {minimum}, but only {width} is available"
+            ),
+            LayoutError::CellTruncated {
+                row,
+                column,
+                width,
+                available,
+                source,
+            } => {
+                match row {
+                    Some(row) => write!(f, "Cell at row {row}, column {column}")?,
+                    None => write!(f, "Header cell in column {column}")?,
+                }
+                if let Some(source) = source {
+                    write!(f, " ({source})")?;
+                }
+                write!(
+                    f,
+                    " must not be truncated, but needs a width of {width} with only {available} available"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for LayoutError {}
+
+impl fmt::Display for StyleWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(feature = "tty")]
+            StyleWarning::SameColors { row, column, color } => {
+                match row {
+                    Some(row) => write!(f, "Cell at row {row}, column {column}")?,
+                    None => write!(f, "Header cell in column {column}")?,
+                }
+                write!(f, " has the same text and background color {color:?}")
+            }
+            #[cfg(feature = "tty")]
+            StyleWarning::ColorsDisabled => {
+                write!(f, "Table is colored, but colors are disabled via NO_COLOR")
+            }
+            StyleWarning::HiddenHeader { column } => {
+                write!(f, "Column {column} has no space to display its header")
+            }
+        }
+    }
+}
+
+/// A cell whose content has been rejected by the
+/// [validator of its column](crate::Column::set_validator).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationError {
+    /// The index of the cell's row.
+    pub row: usize,
+    /// The index of the cell's column.
+    pub column: usize,
+    /// The content of the cell.
+    pub content: String,
+    /// The source of the cell's row, if it has been set via
+    /// [Row::set_source](crate::Row::set_source).
+    pub source: Option<String>,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Cell at row {}, column {}", self.row, self.column)?;
+        if let Some(source) = &self.source {
+            write!(f, " ({source})")?;
+        }
+        write!(f, " has an invalid value: {:?}", self.content)
+    }
+}
+
+impl std::error::Error for ValidationError {}
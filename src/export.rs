@@ -0,0 +1,93 @@
+use std::io::{self, Write};
+
+use crate::Table;
+#[cfg(feature = "csv")]
+use crate::utils::csv;
+
+/// A format, which a table can be exported to via [Table::export].
+///
+/// Implement this trait to add formats, which aren't supported by this crate.
+///
+/// ```
+/// use std::io::{self, Write};
+///
+/// use comfy_table::{Table, TableExporter};
+///
+/// /// Write the content of each row on a separate line.
+/// struct Lines;
+///
+/// impl TableExporter for Lines {
+///     fn export(&self, table: &Table, writer: &mut dyn Write) -> io::Result<()> {
+///         for row in table.row_iter() {
+///             let cells: Vec<String> = row.cell_iter().map(|cell| cell.content()).collect();
+///             writeln!(writer, "{}", cells.join(" "))?;
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// let mut table = Table::new();
+/// table.add_row(vec!["One", "Two"]);
+///
+/// let mut output = Vec::new();
+/// table.export(&Lines, &mut output).unwrap();
+/// assert_eq!(String::from_utf8(output).unwrap(), "One Two\n");
+/// ```
+pub trait TableExporter {
+    /// Write the table in the format of this exporter.
+    fn export(&self, table: &Table, writer: &mut dyn Write) -> io::Result<()>;
+}
+
+/// Export a table as a GitHub-flavored Markdown table, see [Table::to_markdown].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MarkdownExporter;
+
+impl TableExporter for MarkdownExporter {
+    fn export(&self, table: &Table, writer: &mut dyn Write) -> io::Result<()> {
+        writeln!(writer, "{}", table.to_markdown())
+    }
+}
+
+/// Export a table as a HTML `<table>`, see [Table::to_html].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HtmlExporter;
+
+impl TableExporter for HtmlExporter {
+    fn export(&self, table: &Table, writer: &mut dyn Write) -> io::Result<()> {
+        writeln!(writer, "{}", table.to_html())
+    }
+}
+
+/// Export a table as delimiter-separated values, see [Table::to_csv].
+#[cfg(feature = "csv")]
+#[derive(Clone, Copy, Debug)]
+pub struct CsvExporter {
+    delimiter: char,
+}
+
+#[cfg(feature = "csv")]
+impl CsvExporter {
+    /// Export comma-separated values.
+    pub fn new() -> Self {
+        Self { delimiter: ',' }
+    }
+
+    /// Export tab-separated values, see [Table::to_tsv].
+    pub fn tsv() -> Self {
+        Self { delimiter: '\t' }
+    }
+}
+
+#[cfg(feature = "csv")]
+impl Default for CsvExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "csv")]
+impl TableExporter for CsvExporter {
+    fn export(&self, table: &Table, writer: &mut dyn Write) -> io::Result<()> {
+        csv::write_records(table, writer, self.delimiter)
+    }
+}
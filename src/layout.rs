@@ -0,0 +1,218 @@
+use std::ops::Range;
+
+use crate::{
+    Table,
+    style::{ColumnConstraint, Width},
+};
+
+/// The positions of all cells in a rendered table.
+///
+/// A layout is created via [Table::layout](crate::Table::layout) and maps positions in the output
+/// of [Table::lines](crate::Table::lines) back to the cells of the table.
+/// This is useful for terminal UIs, which need to handle mouse clicks on a table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Layout {
+    /// The part of the table each rendered line belongs to. `None` for borders and separators.
+    pub(crate) lines: Vec<Option<LineOwner>>,
+    /// The index and the horizontal range of each visible column, including its padding.
+    pub(crate) columns: Vec<(usize, Range<usize>)>,
+}
+
+impl Layout {
+    /// Map a position in the rendered table to the `(row_index, column_index)` of the cell at
+    /// this position.
+    ///
+    /// `line` is the index of the rendered line and `column` the terminal column inside that line,
+    /// both starting at `0`.\
+    /// `None` is returned for borders, the header, the footer and any position outside of the
+    /// table.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Header1", "Header2"])
+    ///     .add_row(vec!["One", "Two"]);
+    ///
+    /// // +---------+---------+
+    /// // | Header1 | Header2 |
+    /// // +===================+
+    /// // | One     | Two     |
+    /// // +---------+---------+
+    /// let layout = table.layout();
+    /// assert_eq!(layout.hit_test(3, 13), Some((0, 1)));
+    /// assert_eq!(layout.hit_test(3, 10), None);
+    /// assert_eq!(layout.hit_test(1, 2), None);
+    /// ```
+    pub fn hit_test(&self, line: usize, column: usize) -> Option<(usize, usize)> {
+        let Some(LineOwner::Row(row_index)) = *self.lines.get(line)? else {
+            return None;
+        };
+        let (column_index, _) = self
+            .columns
+            .iter()
+            .find(|(_, range)| range.contains(&column))?;
+
+        Some((row_index, *column_index))
+    }
+}
+
+/// The final arrangement of a single column, as determined when rendering the table.
+///
+/// Columns are arranged via [Table::arranged_columns](crate::Table::arranged_columns).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ArrangedColumn {
+    pub(crate) index: usize,
+    pub(crate) padding: (u16, u16),
+    pub(crate) content_width: u16,
+    pub(crate) is_hidden: bool,
+}
+
+impl ArrangedColumn {
+    /// The index of the column.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The padding of the column in the form of (left, right).
+    pub fn padding(&self) -> (u16, u16) {
+        self.padding
+    }
+
+    /// The width that's available for the content of the column's cells.
+    pub fn content_width(&self) -> u16 {
+        self.content_width
+    }
+
+    /// Whether the column is hidden.
+    ///
+    /// Besides [hidden](crate::ColumnConstraint::Hidden) columns, this is also the case for the
+    /// [details column](crate::Table::set_details_column), if it's displayed below the rows.
+    pub fn is_hidden(&self) -> bool {
+        self.is_hidden
+    }
+
+    /// The final width of the column, including its padding.
+    /// Hidden columns have a width of `0`.
+    pub fn width(&self) -> u16 {
+        if self.is_hidden {
+            return 0;
+        }
+
+        self.content_width
+            .saturating_add(self.padding.0)
+            .saturating_add(self.padding.1)
+    }
+}
+
+/// The part of the table a rendered line belongs to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum LineOwner {
+    /// A line of the header's cells.
+    Header,
+    /// A line of the footer's cells.
+    Footer,
+    /// A line of the cells of the data row with the given index.
+    Row(usize),
+    /// A line of the details column below the data row with the given index.
+    Details(usize),
+    /// A line of an annotation.
+    Annotation {
+        /// The index of the data row. `None` if the annotation belongs to the header.
+        row: Option<usize>,
+        /// The index of the annotation in its row.
+        index: usize,
+    },
+    /// A line, which is drawn as is without the frame of the table.
+    /// This is a [raw line](crate::Table::add_raw_line), the title or the caption.
+    Text,
+}
+
+/// Stack the given tables on top of each other, separated by an empty line.
+///
+/// This is useful for reports, which consist of several sections of the same kind.\
+/// With `share_widths`, the columns of all tables line up, as long as all tables have the same
+/// header and the same amount of visible columns. Each column is then as wide as the widest
+/// column at the same position in any of the tables. Columns with their own
+/// [constraint](crate::Column::set_constraint) keep it and aren't widened.
+///
+/// ```
+/// use comfy_table::{Table, layout};
+///
+/// let mut eu = Table::new();
+/// eu.set_header(vec!["Host", "Load"]).add_row(vec!["eu-1", "12%"]);
+/// let mut us = Table::new();
+/// us.set_header(vec!["Host", "Load"]).add_row(vec!["us-east-1", "80%"]);
+///
+/// let expected = "
+/// +-----------+------+
+/// | Host      | Load |
+/// +==================+
+/// | eu-1      | 12%  |
+/// +-----------+------+
+///
+/// +-----------+------+
+/// | Host      | Load |
+/// +==================+
+/// | us-east-1 | 80%  |
+/// +-----------+------+";
+/// assert_eq!(expected, "\n".to_string() + &layout::vertical(&[&eu, &us], true));
+/// ```
+pub fn vertical(tables: &[&Table], share_widths: bool) -> String {
+    let widths: Vec<Vec<(usize, u16)>> = tables
+        .iter()
+        .map(|table| {
+            table
+                .arranged_columns()
+                .iter()
+                .filter(|column| !column.is_hidden())
+                .map(|column| (column.index(), column.width()))
+                .collect()
+        })
+        .collect();
+    let headers: Vec<Option<Vec<String>>> = tables
+        .iter()
+        .map(|table| {
+            let header = table.header()?;
+            Some(header.cell_iter().map(|cell| cell.content()).collect())
+        })
+        .collect();
+    let schemas_match = widths.windows(2).all(|pair| pair[0].len() == pair[1].len())
+        && headers.windows(2).all(|pair| pair[0] == pair[1]);
+    if !share_widths || !schemas_match {
+        return tables
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\n\n");
+    }
+
+    let shared_widths: Vec<u16> = (0..widths.first().map_or(0, Vec::len))
+        .map(|position| {
+            widths
+                .iter()
+                .map(|columns| columns[position].1)
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    tables
+        .iter()
+        .zip(widths.iter())
+        .map(|(table, columns)| {
+            let mut table = (*table).clone();
+            for ((index, _), width) in columns.iter().zip(shared_widths.iter()) {
+                // Columns with their own constraint are left as they are.
+                if let Some(column) = table.column_mut(*index)
+                    && column.constraint().is_none()
+                {
+                    column.set_constraint(ColumnConstraint::Absolute(Width::Fixed(*width)));
+                }
+            }
+            table.to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
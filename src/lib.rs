@@ -7,7 +7,18 @@
 
 mod cell;
 mod column;
+mod error;
+mod export;
+/// Inspect the layout of rendered tables and combine several tables into one output.
+pub mod layout;
+/// Measure and truncate text with the same width calculations that are used for tables.
+pub mod measure;
+mod measured;
+mod partial;
+mod rendered;
 mod row;
+mod row_template;
+mod sort;
 mod style;
 mod table;
 #[cfg(feature = "_integration_test")]
@@ -17,12 +28,26 @@ mod table;
 pub mod utils;
 #[cfg(not(feature = "_integration_test"))]
 mod utils;
+mod view_state;
 
+#[cfg(feature = "csv")]
+pub use export::CsvExporter;
 pub use style::*;
 
 pub use crate::{
     cell::{Cell, Cells},
     column::Column,
+    error::{
+        CellOverflow, ColumnOverflow, LayoutError, StyleWarning, ValidationError, WidthMismatch,
+    },
+    export::{HtmlExporter, MarkdownExporter, TableExporter},
+    layout::{ArrangedColumn, Layout},
+    measured::MeasuredTable,
+    partial::{PartialRender, RenderContinuation},
+    rendered::RenderedTable,
     row::Row,
+    row_template::RowTemplate,
+    sort::SortOrder,
     table::{ColumnCellIter, Table},
+    view_state::TableViewState,
 };
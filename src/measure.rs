@@ -0,0 +1,50 @@
+use crate::{
+    style::WidthTable,
+    utils::formatting::content_split::{measure_text_width, split_long_word},
+};
+
+/// The display width of the given text, as it's measured when rendering a table.
+///
+/// With the `custom_styling` feature, ANSI escape sequences don't count towards the width.
+/// Without it, they're counted like any other text, just like they are inside of tables.
+///
+/// ```
+/// use comfy_table::measure;
+///
+/// assert_eq!(measure::width("Hello"), 5);
+/// assert_eq!(measure::width("宽字符"), 6);
+/// ```
+pub fn width(text: &str) -> usize {
+    width_with(text, &WidthTable::default())
+}
+
+/// Same as [width], but uses the given [WidthTable] instead of the default widths.
+///
+/// Pass [Table::width_table](crate::Table::width_table) to measure text the same way a specific
+/// table does.
+pub fn width_with(text: &str, widths: &WidthTable) -> usize {
+    measure_text_width(text, widths)
+}
+
+/// Cut off the given text after the given display width.
+///
+/// Graphemes are never split. If a wide character doesn't fit into the remaining space, the
+/// result is narrower than `width`.\
+/// With the `custom_styling` feature, ANSI escape sequences are kept intact and styles, which are
+/// still active at the cut, are reset at the end of the result.
+///
+/// ```
+/// use comfy_table::measure;
+///
+/// assert_eq!(measure::truncate_visible("Hello world", 5), "Hello");
+/// assert_eq!(measure::truncate_visible("宽字符", 3), "宽");
+/// assert_eq!(measure::truncate_visible("Hi", 5), "Hi");
+/// ```
+pub fn truncate_visible(text: &str, width: usize) -> String {
+    truncate_visible_with(text, width, &WidthTable::default())
+}
+
+/// Same as [truncate_visible], but uses the given [WidthTable] instead of the default widths.
+pub fn truncate_visible_with(text: &str, width: usize, widths: &WidthTable) -> String {
+    split_long_word(width, text, widths).0
+}
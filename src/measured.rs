@@ -0,0 +1,50 @@
+use crate::Table;
+
+/// A snapshot of a table, whose content has been measured once.
+///
+/// Created via [Table::measure]. Rendering the snapshot with another width reuses the measured
+/// width of the content. This is useful for terminal UIs, which render the same table whenever
+/// the terminal is resized.\
+/// Only the widths are cached. The columns are arranged again and the content is split into lines
+/// for the new column widths on every render.\
+/// The snapshot doesn't reflect any changes made to the original table afterwards.
+#[derive(Clone, Debug)]
+pub struct MeasuredTable {
+    table: Table,
+}
+
+impl MeasuredTable {
+    pub(crate) fn new(table: Table) -> Self {
+        Self { table }
+    }
+
+    /// Render the table with the given width.
+    ///
+    /// ```
+    /// use comfy_table::{ContentArrangement, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_content_arrangement(ContentArrangement::Dynamic)
+    ///     .add_row(vec!["Some long content", "Two"]);
+    ///
+    /// let mut measured = table.measure();
+    /// let expected = "
+    /// +-----------+-----+
+    /// | Some long | Two |
+    /// | content   |     |
+    /// +-----------+-----+";
+    /// assert_eq!(expected, "\n".to_string() + &measured.rerender_with_width(19));
+    /// assert_eq!(measured.column_display_widths(), vec![11, 5]);
+    /// ```
+    pub fn rerender_with_width(&mut self, width: u16) -> String {
+        self.table.set_width(width);
+        self.table.to_string()
+    }
+
+    /// The final width of each column including its padding for the width, with which the table
+    /// has been rendered last. The width of hidden columns is `0`.
+    pub fn column_display_widths(&self) -> Vec<u16> {
+        self.table.column_display_widths()
+    }
+}
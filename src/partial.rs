@@ -0,0 +1,107 @@
+use std::{
+    time::{Duration, Instant},
+    vec,
+};
+
+use crate::{Row, utils::RowStream};
+
+/// The output of [Table::render_with_deadline](crate::Table::render_with_deadline), which may
+/// only contain the first rows of a table.
+///
+/// If the time budget has been used up before the table has been rendered completely, the
+/// [continuation](PartialRender::continuation) renders the remaining rows.
+#[derive(Clone, Debug)]
+pub struct PartialRender {
+    lines: Vec<String>,
+    continuation: Option<RenderContinuation>,
+}
+
+impl PartialRender {
+    /// The output of a table, which has been rendered completely in one go.
+    pub(crate) fn complete(lines: Vec<String>) -> Self {
+        Self {
+            lines,
+            continuation: None,
+        }
+    }
+
+    /// The lines that have been rendered within the time budget.
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// Returns whether the end of the table has been rendered.
+    pub fn is_complete(&self) -> bool {
+        self.continuation.is_none()
+    }
+
+    /// The token to render the rest of the table, if it isn't complete yet.
+    pub fn continuation(&self) -> Option<&RenderContinuation> {
+        self.continuation.as_ref()
+    }
+
+    /// Split the output into its lines and the token to render the rest of the table.
+    pub fn into_parts(self) -> (Vec<String>, Option<RenderContinuation>) {
+        (self.lines, self.continuation)
+    }
+}
+
+/// The state of a table, whose rendering has been interrupted after its time budget.
+///
+/// The token holds the arranged columns and all rows, which haven't been rendered yet.
+/// It doesn't borrow the table, so the table may be changed, while the rendering is continued
+/// with the content the table had when the rendering started.
+#[derive(Clone, Debug)]
+pub struct RenderContinuation {
+    stream: RowStream,
+    rows: vec::IntoIter<Row>,
+}
+
+impl RenderContinuation {
+    pub(crate) fn new(stream: RowStream, rows: Vec<Row>) -> Self {
+        Self {
+            stream,
+            rows: rows.into_iter(),
+        }
+    }
+
+    /// Continue rendering the table for the given amount of time.
+    ///
+    /// The returned lines directly follow the lines, which have been rendered before.
+    pub fn resume(self, budget: Duration) -> PartialRender {
+        self.render_until(Instant::now().checked_add(budget))
+    }
+
+    /// The amount of rows, which have been rendered so far.
+    pub fn rendered_rows(&self) -> usize {
+        self.stream.rendered_rows()
+    }
+
+    /// The amount of rows, which are still left to be rendered.
+    pub fn remaining_rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Render rows, until the deadline has passed or the table is complete.
+    ///
+    /// At least one row is rendered, so every call makes progress.
+    pub(crate) fn render_until(mut self, deadline: Option<Instant>) -> PartialRender {
+        let mut lines = Vec::new();
+        while let Some(row) = self.rows.next() {
+            lines.extend(self.stream.render_row(row));
+            let is_due = deadline.is_some_and(|deadline| Instant::now() >= deadline);
+            if is_due && self.rows.len() > 0 {
+                return PartialRender {
+                    lines,
+                    continuation: Some(self),
+                };
+            }
+        }
+
+        lines.extend(self.stream.finish());
+        PartialRender {
+            lines,
+            continuation: None,
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use std::{fmt, ops::Range};
+
+use crate::layout::{Layout, LineOwner};
+
+/// The output of [Table::render](crate::Table::render) together with some facts about it.
+///
+/// This saves callers from parsing the rendered output to find out e.g. how high the table is or
+/// which lines belong to a certain row.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RenderedTable {
+    pub(crate) lines: Vec<String>,
+    /// The final width of each column, including its padding.
+    pub(crate) column_widths: Vec<u16>,
+    pub(crate) layout: Layout,
+}
+
+impl RenderedTable {
+    /// All lines of the rendered table.
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// The amount of lines of the rendered table.
+    pub fn height(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// The final width of each column, including its padding.
+    ///
+    /// The width of hidden columns is `0`.
+    pub fn column_widths(&self) -> &[u16] {
+        &self.column_widths
+    }
+
+    /// The range of lines the header occupies.
+    ///
+    /// Returns `None`, if the table has no header or the header isn't drawn.
+    pub fn header_lines(&self) -> Option<Range<usize>> {
+        self.line_range(|owner| matches!(owner, LineOwner::Header))
+    }
+
+    /// The range of lines the footer occupies.
+    ///
+    /// Returns `None`, if the table has no footer.
+    pub fn footer_lines(&self) -> Option<Range<usize>> {
+        self.line_range(|owner| matches!(owner, LineOwner::Footer))
+    }
+
+    /// The range of lines the data row with the given index occupies.
+    ///
+    /// This includes the lines of its [annotations](crate::Row::add_annotation) and of its
+    /// [details](crate::Table::set_details_column), but no borders.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Header1", "Header2"])
+    ///     .add_row(vec!["One", "Two"])
+    ///     .add_row(vec!["Three", "Four\nlines"]);
+    ///
+    /// let rendered = table.render();
+    /// assert_eq!(rendered.height(), 8);
+    /// assert_eq!(rendered.row_lines(1), Some(5..7));
+    /// assert_eq!(rendered.row_lines(2), None);
+    /// ```
+    pub fn row_lines(&self, index: usize) -> Option<Range<usize>> {
+        self.line_range(|owner| match owner {
+            LineOwner::Row(row) | LineOwner::Details(row) => row == index,
+            LineOwner::Annotation { row, .. } => row == Some(index),
+            LineOwner::Header | LineOwner::Footer | LineOwner::Text => false,
+        })
+    }
+
+    /// The positions of all cells in the rendered table.
+    pub fn layout(&self) -> &Layout {
+        &self.layout
+    }
+
+    /// The range of all lines whose owner matches the predicate.
+    fn line_range(&self, predicate: impl Fn(LineOwner) -> bool) -> Option<Range<usize>> {
+        let mut lines = self
+            .layout
+            .lines
+            .iter()
+            .enumerate()
+            .filter(|(_, owner)| owner.is_some_and(&predicate))
+            .map(|(index, _)| index);
+        let first = lines.next()?;
+        let last = lines.next_back().unwrap_or(first);
+
+        Some(first..last + 1)
+    }
+}
+
+impl fmt::Display for RenderedTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.lines.join("\n"))
+    }
+}
@@ -1,8 +1,11 @@
 use std::slice::Iter;
 
+#[cfg(feature = "tty")]
+use crate::{Attribute, Color};
 use crate::{
     cell::{Cell, Cells},
-    utils::formatting::content_split::measure_text_width,
+    column::Column,
+    style::WidthTable,
 };
 
 /// Each row contains [Cells](crate::Cell) and can be added to a [Table](crate::Table).
@@ -13,6 +16,16 @@ pub struct Row {
     pub(crate) index: Option<usize>,
     pub(crate) cells: Vec<Cell>,
     pub(crate) max_height: Option<usize>,
+    pub(crate) min_height: Option<usize>,
+    pub(crate) annotations: Vec<Cell>,
+    /// Where the data of this row originates from, e.g. a file and line number.
+    pub(crate) source: Option<String>,
+    #[cfg(feature = "tty")]
+    pub(crate) fg: Option<Color>,
+    #[cfg(feature = "tty")]
+    pub(crate) bg: Option<Color>,
+    #[cfg(feature = "tty")]
+    pub(crate) attributes: Vec<Attribute>,
 }
 
 impl Row {
@@ -54,23 +67,141 @@ impl Row {
         self
     }
 
-    /// Get the longest content width for all cells of this row
-    pub(crate) fn max_content_widths(&self) -> Vec<usize> {
-        // Iterate over all cells
+    /// Let this row occupy at least X lines of space.
+    ///
+    /// Cells with less content are topped up with empty lines.
+    /// Combined with [Row::max_height], this allows rows with a fixed height.
+    ///
+    /// ```
+    /// use comfy_table::{Row, Table};
+    ///
+    /// let mut row = Row::from(vec!["One"]);
+    /// row.min_height(2).max_height(2);
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(row);
+    ///
+    /// let expected = "
+    /// +-----+
+    /// | One |
+    /// |     |
+    /// +-----+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn min_height(&mut self, lines: usize) -> &mut Self {
+        self.min_height = Some(lines);
+
+        self
+    }
+
+    /// Add an annotation to this row.
+    ///
+    /// Annotations are displayed as indented lines directly below the row without any borders.
+    /// They're useful for additional information such as stack traces or explanations.\
+    /// Annotations don't influence the arrangement of the table.
+    /// Their text is styled with the styling of the given [Cell].
+    ///
+    /// ```
+    /// use comfy_table::{Cell, Row};
+    ///
+    /// let mut row = Row::from(vec!["Job 3", "failed"]);
+    /// row.add_annotation("error: disk quota exceeded");
+    /// ```
+    pub fn add_annotation<T: Into<Cell>>(&mut self, annotation: T) -> &mut Self {
+        self.annotations.push(annotation.into());
+
+        self
+    }
+
+    /// Tag this row with the location or ID of the data it originates from.
+    ///
+    /// The source is included in [LayoutError](crate::LayoutError)s caused by this row, which
+    /// helps to find the offending input when loading large amounts of data.
+    ///
+    /// ```
+    /// use comfy_table::Row;
+    ///
+    /// let mut row = Row::from(vec!["One", "Two"]);
+    /// row.set_source("data.csv:1287");
+    ///
+    /// assert_eq!(row.source(), Some("data.csv:1287"));
+    /// ```
+    pub fn set_source<T: ToString>(&mut self, source: T) -> &mut Self {
+        self.source = Some(source.to_string());
+
+        self
+    }
+
+    /// Get the source of this row, if it has been set via [Row::set_source].
+    pub fn source(&self) -> Option<&str> {
+        self.source.as_deref()
+    }
+
+    /// Set the foreground text color for all cells of this row.
+    ///
+    /// The color of single cells can still be changed via [Cell::fg].
+    /// ```
+    /// use comfy_table::{Color, Row};
+    ///
+    /// let mut row = Row::from(vec!["Job 3", "failed"]);
+    /// row.set_fg(Color::Red);
+    /// ```
+    #[cfg(feature = "tty")]
+    pub fn set_fg(&mut self, color: Color) -> &mut Self {
+        self.fg = Some(color);
+
+        self
+    }
+
+    /// Set the background color for all cells of this row.
+    ///
+    /// The color of single cells can still be changed via [Cell::bg].
+    #[cfg(feature = "tty")]
+    pub fn set_bg(&mut self, color: Color) -> &mut Self {
+        self.bg = Some(color);
+
+        self
+    }
+
+    /// Add a styling attribute to all cells of this row.\
+    /// The attributes of a cell are applied after the attributes of its row.
+    #[cfg(feature = "tty")]
+    pub fn add_attribute(&mut self, attribute: Attribute) -> &mut Self {
+        self.attributes.push(attribute);
+
+        self
+    }
+
+    /// Returns whether this row has any styling, which applies to all of its cells.
+    #[cfg(feature = "tty")]
+    pub(crate) fn is_styled(&self) -> bool {
+        self.fg.is_some() || self.bg.is_some() || !self.attributes.is_empty()
+    }
+
+    /// Returns an iterator over all annotations of this row.
+    pub fn annotation_iter(&self) -> Iter<'_, Cell> {
+        self.annotations.iter()
+    }
+
+    /// Get the longest content width for all cells of this row.
+    ///
+    /// Cells with their own padding include the difference to the padding of their column.
+    pub(crate) fn max_content_widths(&self, widths: &WidthTable, columns: &[Column]) -> Vec<usize> {
         self.cells
             .iter()
-            .map(|cell| {
-                // Iterate over all content strings and return a vector of string widths.
-                // Each entry represents the longest string width for a cell.
-                cell.content
-                    .iter()
-                    .map(|string| measure_text_width(string))
-                    .max()
-                    .unwrap_or(0)
+            .enumerate()
+            .map(|(index, cell)| {
+                let padding = columns.get(index).map_or((0, 0), |column| column.padding);
+                cell.max_content_width_with_padding(widths, padding)
             })
             .collect()
     }
 
+    /// The amount of columns the cells of this row span.
+    pub(crate) fn spanned_columns(&self) -> usize {
+        self.cells.iter().map(|cell| cell.colspan).sum()
+    }
+
     /// Get the amount of cells on this row.
     pub fn cell_count(&self) -> usize {
         self.cells.len()
@@ -100,6 +231,15 @@ impl<T: Into<Cells>> From<T> for Row {
             index: None,
             cells: cells.into().0,
             max_height: None,
+            min_height: None,
+            annotations: Vec::new(),
+            source: None,
+            #[cfg(feature = "tty")]
+            fg: None,
+            #[cfg(feature = "tty")]
+            bg: None,
+            #[cfg(feature = "tty")]
+            attributes: Vec::new(),
         }
     }
 }
@@ -118,7 +258,7 @@ mod tests {
             "11 but with\na newline",
         ]);
 
-        let max_content_widths = row.max_content_widths();
+        let max_content_widths = row.max_content_widths(&WidthTable::default(), &[]);
 
         assert_eq!(max_content_widths, vec![0, 4, 5, 6, 11]);
     }
@@ -0,0 +1,107 @@
+use std::{fmt, sync::Arc};
+
+use crate::{Cell, Row};
+
+/// Default formatting for the cells of rows, which are added to a table.
+///
+/// A template holds a formatter for each of its columns. Every cell that's added to such a
+/// column is passed through the formatter, which returns the styled cell.
+/// This is useful when loading many rows at once, as the cells don't have to be styled one by one.
+///
+/// Templates are set via [Table::set_row_template](crate::Table::set_row_template) and only
+/// apply to rows that are added afterwards.
+///
+/// ```
+/// use comfy_table::{CellAlignment, RowTemplate, Table};
+///
+/// let mut template = RowTemplate::new();
+/// template.set_column(1, |cell| cell.set_alignment(CellAlignment::Right));
+///
+/// let mut table = Table::new();
+/// table
+///     .set_header(vec!["Item", "Price"])
+///     .set_row_template(template)
+///     .add_row(vec!["Apple", "1.20"])
+///     .add_row(vec!["Melon", "12.50"]);
+///
+/// let expected = "
+/// +-------+-------+
+/// | Item  | Price |
+/// +===============+
+/// | Apple |  1.20 |
+/// |-------+-------|
+/// | Melon | 12.50 |
+/// +-------+-------+";
+/// assert_eq!(expected, "\n".to_string() + &table.to_string());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct RowTemplate {
+    formatters: Vec<(usize, CellFormatter)>,
+}
+
+/// A function, which styles the cells of a column.
+#[derive(Clone)]
+struct CellFormatter(Arc<dyn Fn(Cell) -> Cell + Send + Sync>);
+
+impl fmt::Debug for CellFormatter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CellFormatter")
+    }
+}
+
+impl RowTemplate {
+    /// Create a new template, which doesn't change any cells.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the formatter for the cells of the column with the given index.
+    ///
+    /// A previous formatter of this column is replaced.\
+    /// Cells that span multiple columns are formatted by the formatter of their first column.
+    pub fn set_column<F>(&mut self, column_index: usize, formatter: F) -> &mut Self
+    where
+        F: Fn(Cell) -> Cell + Send + Sync + 'static,
+    {
+        self.remove_column(column_index);
+        self.formatters
+            .push((column_index, CellFormatter(Arc::new(formatter))));
+
+        self
+    }
+
+    /// Remove the formatter of the column with the given index.
+    pub fn remove_column(&mut self, column_index: usize) -> &mut Self {
+        self.formatters.retain(|(index, _)| *index != column_index);
+
+        self
+    }
+
+    /// Returns whether the column with the given index has a formatter.
+    pub fn has_column(&self, column_index: usize) -> bool {
+        self.formatters
+            .iter()
+            .any(|(index, _)| *index == column_index)
+    }
+
+    /// Pass all cells of the row through the formatter of their column.
+    pub(crate) fn apply(&self, row: &mut Row) {
+        if self.formatters.is_empty() {
+            return;
+        }
+
+        let mut column_index = 0;
+        let cells = std::mem::take(&mut row.cells);
+        row.cells = cells
+            .into_iter()
+            .map(|cell| {
+                let index = column_index;
+                column_index += cell.colspan();
+                match self.formatters.iter().find(|(column, _)| *column == index) {
+                    Some((_, formatter)) => (formatter.0)(cell),
+                    None => cell,
+                }
+            })
+            .collect();
+    }
+}
@@ -0,0 +1,95 @@
+use std::cmp::Ordering;
+
+/// The order in which [rows are sorted](crate::Table::sort_rows).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum SortOrder {
+    /// Sort from the smallest to the largest value.
+    Ascending,
+    /// Sort from the largest to the smallest value.
+    Descending,
+}
+
+impl SortOrder {
+    /// Apply this order to the result of an ascending comparison.
+    pub(crate) fn apply(self, ordering: Ordering) -> Ordering {
+        match self {
+            SortOrder::Ascending => ordering,
+            SortOrder::Descending => ordering.reverse(),
+        }
+    }
+}
+
+/// Compare two strings, while treating each sequence of digits as a number.
+///
+/// That way, `"9"` is sorted before `"10"` and `"file2"` before `"file10"`.
+pub(crate) fn natural_cmp(left: &str, right: &str) -> Ordering {
+    let mut left = Chunks(left);
+    let mut right = Chunks(right);
+    loop {
+        let ordering = match (left.next(), right.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(left), Some(right)) => match (is_number(left), is_number(right)) {
+                (true, true) => numeric_cmp(left, right),
+                _ => left.cmp(right),
+            },
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+/// Compare two sequences of digits by their numeric value.
+/// Leading zeros are ignored, so arbitrarily large numbers can be compared.
+fn numeric_cmp(left: &str, right: &str) -> Ordering {
+    let left_digits = left.trim_start_matches('0');
+    let right_digits = right.trim_start_matches('0');
+    left_digits
+        .len()
+        .cmp(&right_digits.len())
+        .then_with(|| left_digits.cmp(right_digits))
+        .then_with(|| left.len().cmp(&right.len()))
+}
+
+fn is_number(chunk: &str) -> bool {
+    chunk.starts_with(|character: char| character.is_ascii_digit())
+}
+
+/// Splits a string into alternating sequences of digits and other characters.
+struct Chunks<'a>(&'a str);
+
+impl<'a> Iterator for Chunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let first = self.0.chars().next()?;
+        let digits = first.is_ascii_digit();
+        let end = self
+            .0
+            .find(|character: char| character.is_ascii_digit() != digits)
+            .unwrap_or(self.0.len());
+        let (chunk, rest) = self.0.split_at(end);
+        self.0 = rest;
+
+        Some(chunk)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn natural_order() {
+        let mut values = vec!["file10", "file2", "10", "9", "b", "a", "file02", "007"];
+        values.sort_by(|left, right| natural_cmp(left, right));
+
+        assert_eq!(
+            values,
+            vec!["007", "9", "10", "a", "b", "file2", "file02", "file10"]
+        );
+    }
+}
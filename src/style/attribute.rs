@@ -28,6 +28,7 @@
 ///
 /// Check [crate::Cell::add_attribute] on how to use it.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[non_exhaustive]
 pub enum Attribute {
     /// Resets all the attributes.
@@ -14,9 +14,33 @@
 /// |                right |
 /// +----------------------+
 /// ```
+///
+/// Cells with multiple lines, e.g. due to wrapping, are aligned line by line.
+/// Right aligned numbers thereby line up on their last line, even if they wrap.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum CellAlignment {
     Left,
     Right,
     Center,
+    /// Line up the first occurrence of the given character in all lines of the column.
+    ///
+    /// This is useful for decimal numbers (`'.'`), times (`':'`) or network prefixes (`'/'`).
+    /// The column is widened, if that's needed to line up all anchors.
+    ///
+    /// ```text
+    /// +----------------+
+    /// | Network        |
+    /// +================+
+    /// |     10.0.0.0/8 |
+    /// |----------------|
+    /// | 192.168.1.0/24 |
+    /// |----------------|
+    /// |        ::1/128 |
+    /// +----------------+
+    /// ```
+    ///
+    /// Lines without the anchor end, where the anchor would be, e.g. `12` next to `3.5`.\
+    /// Cells that span multiple columns are aligned left.
+    /// Exports, which can't line up characters, align these cells right instead.
+    Anchor(char),
 }
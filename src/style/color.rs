@@ -26,6 +26,7 @@
 ///
 /// Check [crate::Cell::bg], [crate::Cell::fg] and  on how to use it.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum Color {
     /// Resets the terminal color.
     Reset,
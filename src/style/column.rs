@@ -26,6 +26,17 @@ pub enum ColumnConstraint {
     UpperBoundary(Width),
     /// Specify both, an upper and a lower boundary.
     Boundaries { lower: Width, upper: Width },
+    /// The column is at least as wide as its header, so the header is never wrapped.
+    /// The column may take more space, if its content is wider.
+    ///
+    /// Header cells that span multiple columns are ignored.
+    AtLeastHeaderWidth,
+    /// The column is never narrower than the given amount of characters plus the truncation
+    /// indicator, unless its content is narrower than that.
+    ///
+    /// If a dynamic arrangement can't fit the column into the table's width, the column is hidden
+    /// instead of being squeezed into an unreadable sliver.
+    MinReadable(u16),
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -40,3 +51,80 @@ pub enum Width {
     /// - the width of the table/terminal cannot be determined.
     Percentage(u16),
 }
+
+/// Controls where the content of a column's cells may be split, if it doesn't fit into a line.
+///
+/// Can be set via [Column::set_wrap_policy](crate::Column::set_wrap_policy).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum WrapPolicy {
+    /// Split the content at the delimiter, which is a space by default.
+    /// Words that are longer than a line are split wherever the line is full.
+    #[default]
+    Words,
+    /// Split the content at the delimiter.
+    /// Words that are longer than a line are split after the last of the given characters that
+    /// fits into the line, e.g. `/` for paths or `:` for IPv6 addresses.
+    BreakOn(&'static [char]),
+    /// Ignore the delimiter and fill each line completely.
+    BreakAnywhere,
+    /// Never split words.
+    /// The dynamic arrangement keeps the column at least as wide as its longest word.
+    /// Words are only split, if the table is too narrow to fit them.
+    NoBreak,
+}
+
+/// Controls which part of a cell's content is cut off, if it doesn't fit into its column.
+///
+/// Each variant holds the indicator, which is displayed in place of the removed content.\
+/// Can be set via [Column::set_truncation](crate::Column::set_truncation).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TruncationStyle {
+    /// Cut off the end of the content, e.g. `long tex…`.
+    End(String),
+    /// Cut off the start of the content, e.g. `…ng text`.
+    Start(String),
+    /// Cut off the middle of the content, e.g. `long…ext`.
+    /// This keeps both the drive and the file name of paths.
+    Middle(String),
+}
+
+/// Controls how numbers in the cells of a column are displayed.
+///
+/// Can be set via [Column::set_numeric_format](crate::Column::set_numeric_format).
+/// By default, numbers are only aligned on their decimal point.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NumericFormat {
+    precision: Option<usize>,
+    thousands_separator: Option<char>,
+}
+
+impl NumericFormat {
+    /// Create a format, which keeps the digits of all numbers as they are.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Round all numbers to the given amount of digits after the decimal point.
+    pub fn set_precision(&mut self, precision: usize) -> &mut Self {
+        self.precision = Some(precision);
+
+        self
+    }
+
+    /// The amount of digits after the decimal point, if numbers are rounded.
+    pub fn precision(&self) -> Option<usize> {
+        self.precision
+    }
+
+    /// Group the digits before the decimal point in thousands, separated by the given character.
+    pub fn set_thousands_separator(&mut self, separator: char) -> &mut Self {
+        self.thousands_separator = Some(separator);
+
+        self
+    }
+
+    /// The character, which separates the thousands, if any.
+    pub fn thousands_separator(&self) -> Option<char> {
+        self.thousands_separator
+    }
+}
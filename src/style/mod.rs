@@ -8,18 +8,33 @@ mod column;
 /// For instance, the [UTF8_ROUND_CORNERS](modifiers::UTF8_ROUND_CORNERS) replaces all corners with
 /// round UTF8 box corners.
 pub mod modifiers;
+mod overrides;
 /// This module provides styling presets for tables.\
 /// Every preset has an example preview.
 pub mod presets;
+#[cfg(feature = "tty")]
+mod span;
 mod table;
+mod theme;
+/// Ready-made [themes](Theme), which bundle a preset with colors and attributes.\
+/// They're applied via [Table::apply_theme](crate::Table::apply_theme) and every part of them can
+/// be overridden afterwards.
+#[cfg(feature = "tty")]
+pub mod themes;
+mod width_table;
 
 pub use cell::CellAlignment;
-pub use column::{ColumnConstraint, Width};
+pub use column::{ColumnConstraint, NumericFormat, TruncationStyle, Width, WrapPolicy};
+pub use overrides::StyleOverrides;
+#[cfg(feature = "tty")]
+pub use span::StyleSpan;
 #[cfg(feature = "tty")]
 pub use styling_enums::{Attribute, Color};
 #[cfg(feature = "tty")]
 pub(crate) use styling_enums::{map_attribute, map_color};
-pub use table::{ContentArrangement, TableComponent};
+pub use table::{BorderCollision, ContentArrangement, HeaderStyle, TableComponent};
+pub use theme::Theme;
+pub use width_table::WidthTable;
 
 /// Convenience module to have cleaner and "identical" conditional re-exports for style enums.
 #[cfg(all(feature = "tty", not(feature = "reexport_crossterm")))]
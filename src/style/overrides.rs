@@ -0,0 +1,79 @@
+use super::Theme;
+
+/// Styling, which temporarily replaces the styling of a table for a single render.
+///
+/// Overrides are applied via
+/// [Table::with_style_overrides](crate::Table::with_style_overrides). The table itself isn't
+/// changed, so there's nothing to restore afterwards.
+///
+/// ```
+/// use comfy_table::{StyleOverrides, Table, presets::ASCII_MARKDOWN};
+///
+/// let mut table = Table::new();
+/// table.add_row(vec!["One", "Two"]);
+///
+/// let mut overrides = StyleOverrides::new();
+/// overrides.set_preset(ASCII_MARKDOWN);
+///
+/// let markdown = table.with_style_overrides(&overrides, |table| table.to_string());
+/// assert_eq!(markdown, "| One | Two |");
+/// assert_eq!(table.to_string().lines().count(), 3);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StyleOverrides {
+    theme: Option<Theme>,
+    preset: Option<String>,
+    #[cfg(feature = "tty")]
+    styling: Option<bool>,
+}
+
+impl StyleOverrides {
+    /// Create new overrides, which don't replace anything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply the given theme, see [Table::apply_theme](crate::Table::apply_theme).
+    pub fn set_theme(&mut self, theme: Theme) -> &mut Self {
+        self.theme = Some(theme);
+
+        self
+    }
+
+    /// The theme, which is applied to the table.
+    pub fn theme(&self) -> Option<&Theme> {
+        self.theme.as_ref()
+    }
+
+    /// Load the given [preset](super::presets), see
+    /// [Table::load_preset](crate::Table::load_preset).
+    ///
+    /// The preset is loaded after the [theme](StyleOverrides::set_theme).
+    pub fn set_preset(&mut self, preset: &str) -> &mut Self {
+        self.preset = Some(preset.to_string());
+
+        self
+    }
+
+    /// The preset, which is loaded for the table.
+    pub fn preset(&self) -> Option<&str> {
+        self.preset.as_deref()
+    }
+
+    /// Enforce or disable terminal styling, independent of whether the output is a tty.
+    ///
+    /// This is useful to print the same table once styled to the terminal and once without
+    /// styling to a file.
+    #[cfg(feature = "tty")]
+    pub fn set_styling(&mut self, styling: bool) -> &mut Self {
+        self.styling = Some(styling);
+
+        self
+    }
+
+    /// Whether terminal styling is enforced or disabled.
+    #[cfg(feature = "tty")]
+    pub fn styling(&self) -> Option<bool> {
+        self.styling
+    }
+}
@@ -0,0 +1,17 @@
+use std::ops::Range;
+
+use super::{Attribute, Color};
+
+/// The styling of a part of a table, which has been rendered as plain text via
+/// [Table::to_string_with_spans](crate::Table::to_string_with_spans).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StyleSpan {
+    /// The byte range of the styled text.
+    pub range: Range<usize>,
+    /// The foreground color of the text.
+    pub fg: Option<Color>,
+    /// The background color of the text.
+    pub bg: Option<Color>,
+    /// The attributes of the text, such as bold or italic.
+    pub attributes: Vec<Attribute>,
+}
@@ -26,6 +26,83 @@ pub enum ContentArrangement {
     /// always use as much space as it's given. Any surplus space will be distributed between
     /// all columns.
     DynamicFullWidth,
+    /// Choose the arrangement depending on the content.\
+    /// Content that fits into the table is displayed as is, otherwise it's wrapped just like with
+    /// the [Dynamic](ContentArrangement::Dynamic) arrangement.\
+    /// If wrapping would result in more than `max_height` lines of content (borders excluded),
+    /// the content of all cells is kept on a single line and cut off instead, as if all columns
+    /// were [no-wrap](crate::Column::set_no_wrap) columns.
+    ///
+    /// **Warning:** If the width of the table cannot be determined, this option falls back to
+    /// [Disabled](ContentArrangement::Disabled), just like [Dynamic](ContentArrangement::Dynamic).
+    Auto {
+        /// The maximum amount of content lines the table may have.
+        max_height: usize,
+    },
+}
+
+/// Controls how characters of the cell content are displayed, which are also used to draw the
+/// borders of the table.
+///
+/// Can be set via [Table::set_border_collision](crate::Table::set_border_collision).
+///
+/// ```
+/// use comfy_table::{BorderCollision, Table};
+///
+/// let mut table = Table::new();
+/// table.set_border_collision(BorderCollision::Escape);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BorderCollision {
+    /// Display the characters as they are.
+    #[default]
+    Keep,
+    /// Prefix the characters with a backslash, e.g. `a \| b`.\
+    /// Backslashes are escaped as well, so the original content can always be restored.
+    Escape,
+    /// Replace the characters with the given character.
+    Substitute(char),
+}
+
+/// The naming scheme of headers, which are generated via
+/// [Table::generate_headers](crate::Table::generate_headers).
+///
+/// ```
+/// use comfy_table::{HeaderStyle, Table};
+///
+/// let mut table = Table::new();
+/// table
+///     .add_row(vec!["web-1", "up"])
+///     .generate_headers(HeaderStyle::Numbered("col_".to_string()));
+///
+/// assert_eq!(table.header().unwrap().cell_iter().next().unwrap().content(), "col_1");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HeaderStyle {
+    /// Name the columns like a spreadsheet: `A`, `B`, ..., `Z`, `AA`, `AB`, ...
+    Letters,
+    /// Number the columns starting at 1 and prefix the numbers with the given string,
+    /// e.g. `col_1`, `col_2`, ...
+    Numbered(String),
+}
+
+impl HeaderStyle {
+    /// The name of the column with the given index.
+    pub(crate) fn name(&self, index: usize) -> String {
+        match self {
+            HeaderStyle::Letters => {
+                let mut name = Vec::new();
+                let mut remaining = index + 1;
+                while remaining > 0 {
+                    remaining -= 1;
+                    name.push(char::from(b'A' + (remaining % 26) as u8));
+                    remaining /= 26;
+                }
+                name.iter().rev().collect()
+            }
+            HeaderStyle::Numbered(prefix) => format!("{prefix}{}", index + 1),
+        }
+    }
 }
 
 /// All configurable table components.
@@ -68,6 +145,19 @@ pub enum TableComponent {
     TopRightCorner,
     BottomLeftCorner,
     BottomRightCorner,
+    /// The line between the rows and the [footer](crate::Table::set_footer).\
+    /// The footer components aren't part of presets. If they aren't set, the respective header
+    /// component is used instead.
+    FooterLines,
+    LeftFooterIntersection,
+    MiddleFooterIntersections,
+    RightFooterIntersection,
+    /// The line in place of the bottom border, if the table is cut off and continues, e.g. on
+    /// all but the last page of [Table::render_pages](crate::Table::render_pages).\
+    /// The continuation components aren't part of presets. The continuation border is only drawn
+    /// if this component is set. Its intersections fall back to the bottom border intersections.
+    ContinuationBorder,
+    ContinuationBorderIntersections,
 }
 
 impl TableComponent {
@@ -95,7 +185,38 @@ impl TableComponent {
         ]
     }
 
+    /// Iterate over all components, which are part of presets, in the order of preset strings.
     pub fn iter() -> impl Iterator<Item = TableComponent> {
         TableComponent::components().into_iter()
     }
+
+    /// The header component, which is used in place of this footer component, if it isn't set.
+    pub(crate) const fn header_equivalent(&self) -> Option<TableComponent> {
+        match self {
+            TableComponent::FooterLines => Some(TableComponent::HeaderLines),
+            TableComponent::LeftFooterIntersection => Some(TableComponent::LeftHeaderIntersection),
+            TableComponent::MiddleFooterIntersections => {
+                Some(TableComponent::MiddleHeaderIntersections)
+            }
+            TableComponent::RightFooterIntersection => {
+                Some(TableComponent::RightHeaderIntersection)
+            }
+            _ => None,
+        }
+    }
+
+    /// The ASCII character that's used in place of non-ASCII characters for this component.
+    pub(crate) const fn ascii_fallback(&self) -> char {
+        match self {
+            TableComponent::LeftBorder
+            | TableComponent::RightBorder
+            | TableComponent::VerticalLines => '|',
+            TableComponent::TopBorder
+            | TableComponent::BottomBorder
+            | TableComponent::HorizontalLines
+            | TableComponent::ContinuationBorder => '-',
+            TableComponent::HeaderLines | TableComponent::FooterLines => '=',
+            _ => '+',
+        }
+    }
 }
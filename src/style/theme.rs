@@ -0,0 +1,286 @@
+#[cfg(feature = "serde")]
+use std::str::FromStr;
+
+use super::presets::ASCII_FULL;
+#[cfg(feature = "tty")]
+use super::{Attribute, Color};
+
+/// A reusable look of a table, which bundles a [preset](super::presets), the padding of columns
+/// and the styling of the header, the rows and the selection.
+///
+/// Ready-made themes with colors can be found in the [themes](super::themes) module.
+/// Themes are applied via [Table::apply_theme](crate::Table::apply_theme).\
+/// With the `serde` feature, themes can be (de)serialized and loaded from JSON via
+/// [str::parse]. Missing fields fall back to their defaults.
+///
+/// ```
+/// use comfy_table::{Table, Theme, presets::UTF8_FULL};
+///
+/// let mut theme = Theme::new(UTF8_FULL);
+/// theme.set_padding((0, 0));
+///
+/// let mut table = Table::new();
+/// table.apply_theme(&theme).add_row(vec!["One", "Two"]);
+///
+/// let expected = "
+/// ┌───┬───┐
+/// │One┆Two│
+/// └───┴───┘";
+/// assert_eq!(expected, "\n".to_string() + &table.to_string());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Theme {
+    preset: String,
+    padding: Option<(u16, u16)>,
+    #[cfg(feature = "tty")]
+    #[cfg_attr(feature = "serde", serde(with = "color_serde::optional"))]
+    header_fg: Option<Color>,
+    #[cfg(feature = "tty")]
+    #[cfg_attr(feature = "serde", serde(with = "color_serde::optional"))]
+    header_bg: Option<Color>,
+    #[cfg(feature = "tty")]
+    header_attributes: Vec<Attribute>,
+    #[cfg(feature = "tty")]
+    #[cfg_attr(feature = "serde", serde(with = "color_serde::optional_pair"))]
+    zebra_striping: Option<(Color, Color)>,
+    #[cfg(feature = "tty")]
+    #[cfg_attr(feature = "serde", serde(with = "color_serde::optional"))]
+    selection_fg: Option<Color>,
+    #[cfg(feature = "tty")]
+    #[cfg_attr(feature = "serde", serde(with = "color_serde::optional"))]
+    selection_bg: Option<Color>,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::new(ASCII_FULL)
+    }
+}
+
+impl Theme {
+    /// Create a new theme with the given [preset](super::presets) string and without any further
+    /// styling.
+    pub fn new(preset: &str) -> Self {
+        Self {
+            preset: preset.to_string(),
+            padding: None,
+            #[cfg(feature = "tty")]
+            header_fg: None,
+            #[cfg(feature = "tty")]
+            header_bg: None,
+            #[cfg(feature = "tty")]
+            header_attributes: Vec::new(),
+            #[cfg(feature = "tty")]
+            zebra_striping: None,
+            #[cfg(feature = "tty")]
+            selection_fg: None,
+            #[cfg(feature = "tty")]
+            selection_bg: None,
+        }
+    }
+
+    /// The preset string, which is loaded via [Table::load_preset](crate::Table::load_preset).
+    pub fn preset(&self) -> &str {
+        &self.preset
+    }
+
+    /// Set the padding of all columns in the form of (left, right).
+    pub fn set_padding(&mut self, padding: (u16, u16)) -> &mut Self {
+        self.padding = Some(padding);
+
+        self
+    }
+
+    /// The padding of all columns, if it has been set via [Theme::set_padding].
+    pub fn padding(&self) -> Option<(u16, u16)> {
+        self.padding
+    }
+
+    /// Set the foreground color of the header.
+    #[cfg(feature = "tty")]
+    pub fn set_header_fg(&mut self, color: Color) -> &mut Self {
+        self.header_fg = Some(color);
+
+        self
+    }
+
+    /// The foreground color of the header.
+    #[cfg(feature = "tty")]
+    pub fn header_fg(&self) -> Option<Color> {
+        self.header_fg
+    }
+
+    /// Set the background color of the header.
+    #[cfg(feature = "tty")]
+    pub fn set_header_bg(&mut self, color: Color) -> &mut Self {
+        self.header_bg = Some(color);
+
+        self
+    }
+
+    /// The background color of the header.
+    #[cfg(feature = "tty")]
+    pub fn header_bg(&self) -> Option<Color> {
+        self.header_bg
+    }
+
+    /// Add a text attribute to the header.
+    #[cfg(feature = "tty")]
+    pub fn add_header_attribute(&mut self, attribute: Attribute) -> &mut Self {
+        self.header_attributes.push(attribute);
+
+        self
+    }
+
+    /// The text attributes of the header.
+    #[cfg(feature = "tty")]
+    pub fn header_attributes(&self) -> &[Attribute] {
+        &self.header_attributes
+    }
+
+    /// Set the alternating background colors of even and odd rows, see
+    /// [Table::set_zebra_striping](crate::Table::set_zebra_striping).
+    #[cfg(feature = "tty")]
+    pub fn set_zebra_striping(&mut self, even: Color, odd: Color) -> &mut Self {
+        self.zebra_striping = Some((even, odd));
+
+        self
+    }
+
+    /// The alternating background colors of even and odd rows.
+    #[cfg(feature = "tty")]
+    pub fn zebra_striping(&self) -> Option<(Color, Color)> {
+        self.zebra_striping
+    }
+
+    /// Set the foreground color of the row, which is selected via a
+    /// [TableViewState](crate::TableViewState).
+    ///
+    /// Without any selection colors, the selected row is displayed with reversed colors.
+    #[cfg(feature = "tty")]
+    pub fn set_selection_fg(&mut self, color: Color) -> &mut Self {
+        self.selection_fg = Some(color);
+
+        self
+    }
+
+    /// The foreground color of the selected row.
+    #[cfg(feature = "tty")]
+    pub fn selection_fg(&self) -> Option<Color> {
+        self.selection_fg
+    }
+
+    /// Set the background color of the row, which is selected via a
+    /// [TableViewState](crate::TableViewState).
+    #[cfg(feature = "tty")]
+    pub fn set_selection_bg(&mut self, color: Color) -> &mut Self {
+        self.selection_bg = Some(color);
+
+        self
+    }
+
+    /// The background color of the selected row.
+    #[cfg(feature = "tty")]
+    pub fn selection_bg(&self) -> Option<Color> {
+        self.selection_bg
+    }
+}
+
+/// Load a theme from its JSON definition.
+///
+/// ```
+/// use comfy_table::{Theme, presets::UTF8_FULL};
+///
+/// let theme: Theme = r#"{ "preset": "││──╞═╪╡┆╌┼├┤┬┴┌┐└┘", "padding": [0, 1] }"#
+///     .parse()
+///     .unwrap();
+///
+/// assert_eq!(theme.preset(), UTF8_FULL);
+/// assert_eq!(theme.padding(), Some((0, 1)));
+/// ```
+#[cfg(feature = "serde")]
+impl FromStr for Theme {
+    type Err = serde_json::Error;
+
+    fn from_str(definition: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(definition)
+    }
+}
+
+/// (De)serialization of the colors of a theme.
+///
+/// With the `reexport_crossterm` feature, [Color] is crossterm's type, which has its own serde
+/// format. Going through [ColorDef] keeps theme definitions identical with and without it.
+#[cfg(all(feature = "serde", feature = "tty"))]
+mod color_serde {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::Color;
+
+    #[derive(Deserialize, Serialize)]
+    #[serde(remote = "Color")]
+    enum ColorDef {
+        Reset,
+        Black,
+        DarkGrey,
+        Red,
+        DarkRed,
+        Green,
+        DarkGreen,
+        Yellow,
+        DarkYellow,
+        Blue,
+        DarkBlue,
+        Magenta,
+        DarkMagenta,
+        Cyan,
+        DarkCyan,
+        White,
+        Grey,
+        Rgb { r: u8, g: u8, b: u8 },
+        AnsiValue(u8),
+    }
+
+    #[derive(Deserialize, Serialize)]
+    struct SerdeColor(#[serde(with = "ColorDef")] Color);
+
+    pub mod optional {
+        use super::*;
+
+        pub fn serialize<S: Serializer>(
+            color: &Option<Color>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            color.map(SerdeColor).serialize(serializer)
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<Color>, D::Error> {
+            let color = Option::<SerdeColor>::deserialize(deserializer)?;
+            Ok(color.map(|color| color.0))
+        }
+    }
+
+    pub mod optional_pair {
+        use super::*;
+
+        pub fn serialize<S: Serializer>(
+            colors: &Option<(Color, Color)>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            colors
+                .map(|(first, second)| (SerdeColor(first), SerdeColor(second)))
+                .serialize(serializer)
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<(Color, Color)>, D::Error> {
+            let colors = Option::<(SerdeColor, SerdeColor)>::deserialize(deserializer)?;
+            Ok(colors.map(|(first, second)| (first.0, second.0)))
+        }
+    }
+}
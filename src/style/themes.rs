@@ -0,0 +1,45 @@
+use std::sync::LazyLock;
+
+use super::{
+    Attribute, Color, Theme,
+    presets::{UTF8_FULL_CONDENSED, UTF8_HORIZONTAL_ONLY},
+};
+
+/// Blue header on a dark background with subtly striped rows.
+///
+/// ```
+/// use comfy_table::{Table, themes::OCEAN};
+///
+/// let mut table = Table::new();
+/// table.apply_theme(&OCEAN).set_header(vec!["Header"]);
+/// ```
+pub static OCEAN: LazyLock<Theme> = LazyLock::new(|| {
+    let mut theme = Theme::new(UTF8_FULL_CONDENSED);
+    theme
+        .set_header_fg(Color::Cyan)
+        .set_header_bg(Color::DarkBlue)
+        .add_header_attribute(Attribute::Bold)
+        .set_zebra_striping(
+            Color::Rgb { r: 0, g: 30, b: 50 },
+            Color::Rgb { r: 0, g: 45, b: 70 },
+        )
+        .set_selection_fg(Color::Black)
+        .set_selection_bg(Color::Cyan);
+
+    theme
+});
+
+/// No colors at all, only a bold header and horizontal lines.
+///
+/// ```
+/// use comfy_table::{Table, themes::MONO_BOLD_HEADER};
+///
+/// let mut table = Table::new();
+/// table.apply_theme(&MONO_BOLD_HEADER).set_header(vec!["Header"]);
+/// ```
+pub static MONO_BOLD_HEADER: LazyLock<Theme> = LazyLock::new(|| {
+    let mut theme = Theme::new(UTF8_HORIZONTAL_ONLY);
+    theme.add_header_attribute(Attribute::Bold);
+
+    theme
+});
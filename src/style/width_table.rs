@@ -0,0 +1,137 @@
+use std::ops::RangeInclusive;
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Custom display widths for characters.
+///
+/// The display width of characters is determined via the Unicode version that's shipped with the
+/// `unicode-width` crate. Terminals, especially older ones, don't always agree with that version.
+/// For instance, newer emojis might be displayed with a width of 1 instead of 2.
+///
+/// A width table allows to override the width of specific characters to match the behavior of
+/// your terminal. The overrides are applied to each grapheme based on its first character.
+///
+/// Emoji presentation sequences (a character followed by the variation selector `U+FE0F`) are
+/// displayed with a width of 2 by default. Since terminals handle these sequences inconsistently,
+/// their width can be changed via [WidthTable::set_emoji_presentation_width].
+///
+/// ```
+/// use comfy_table::{Table, WidthTable};
+///
+/// // This terminal displays the "Symbols and Pictographs Extended-A" block with a width of 1.
+/// let widths = WidthTable::new().set_width('\u{1FA70}'..='\u{1FAFF}', 1);
+///
+/// let mut table = Table::new();
+/// table.set_width_table(widths);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WidthTable {
+    overrides: Vec<(RangeInclusive<char>, usize)>,
+    emoji_presentation_width: Option<usize>,
+}
+
+impl WidthTable {
+    /// Create a new width table without any overrides.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the display width of all characters in the given range.
+    ///
+    /// Overrides that are added later take precedence over previous overrides.
+    #[must_use]
+    pub fn set_width(mut self, range: RangeInclusive<char>, width: usize) -> Self {
+        self.overrides.push((range, width));
+
+        self
+    }
+
+    /// Set the display width of graphemes that contain the emoji presentation selector `U+FE0F`.
+    ///
+    /// Most terminals display these with a width of 2, some however only use a single column.
+    /// Overrides of specific characters via [WidthTable::set_width] take precedence.
+    ///
+    /// ```
+    /// use comfy_table::WidthTable;
+    ///
+    /// let widths = WidthTable::new().set_emoji_presentation_width(1);
+    /// ```
+    #[must_use]
+    pub fn set_emoji_presentation_width(mut self, width: usize) -> Self {
+        self.emoji_presentation_width = Some(width);
+
+        self
+    }
+
+    /// Returns whether this table contains no overrides.
+    pub fn is_empty(&self) -> bool {
+        self.overrides.is_empty() && self.emoji_presentation_width.is_none()
+    }
+
+    /// The display width of a single grapheme.
+    pub(crate) fn grapheme_width(&self, grapheme: &str) -> usize {
+        if let Some(first) = grapheme.chars().next() {
+            let width = self
+                .overrides
+                .iter()
+                .rev()
+                .find(|(range, _)| range.contains(&first));
+            if let Some((_, width)) = width {
+                return *width;
+            }
+        }
+
+        if let Some(width) = self.emoji_presentation_width
+            && grapheme.contains('\u{FE0F}')
+        {
+            return width;
+        }
+
+        grapheme.width()
+    }
+
+    /// The display width of a string without any escape sequences.
+    pub(crate) fn str_width(&self, string: &str) -> usize {
+        if self.is_empty() {
+            return string.width();
+        }
+
+        string
+            .graphemes(true)
+            .map(|grapheme| self.grapheme_width(grapheme))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_width_overrides() {
+        let widths = WidthTable::new();
+        assert_eq!(widths.str_width("a🪨b"), 4);
+
+        let widths = widths.set_width('\u{1FA70}'..='\u{1FAFF}', 1);
+        assert_eq!(widths.str_width("a🪨b"), 3);
+
+        // Later overrides take precedence.
+        let widths = widths.set_width('🪨'..='🪨', 3);
+        assert_eq!(widths.str_width("a🪨b"), 5);
+    }
+
+    #[test]
+    fn test_emoji_presentation_width() {
+        let text = "\u{2764}\u{FE0F} \u{2764}";
+        let widths = WidthTable::new();
+        assert_eq!(widths.str_width(text), 4);
+
+        let widths = widths.set_emoji_presentation_width(1);
+        assert_eq!(widths.str_width(text), 3);
+
+        // Character overrides take precedence over the emoji presentation width.
+        let widths = widths.set_width('\u{2764}'..='\u{2764}', 2);
+        assert_eq!(widths.str_width(text), 5);
+    }
+}
@@ -1,18 +1,47 @@
+#[cfg(feature = "csv")]
+use std::io::Read;
 #[cfg(feature = "tty")]
 use std::sync::OnceLock;
 use std::{
+    cmp::Ordering,
     collections::HashMap,
-    fmt,
+    fmt::{self, Write as _},
+    io::{self, Write},
     iter::IntoIterator,
+    ops::Range,
     slice::{Iter, IterMut},
+    sync::Arc,
+    time::Duration,
 };
 
+#[cfg(feature = "csv")]
+use crate::utils::csv;
+#[cfg(feature = "tty")]
+use crate::{Attribute, Color, style::StyleSpan, utils::build_style_spans};
 use crate::{
     cell::Cell,
     column::Column,
+    error::{CellOverflow, LayoutError, StyleWarning, ValidationError, WidthMismatch},
+    export::TableExporter,
+    layout::{ArrangedColumn, Layout},
+    measured::MeasuredTable,
+    partial::PartialRender,
+    rendered::RenderedTable,
     row::Row,
-    style::{ColumnConstraint, ContentArrangement, TableComponent, presets::ASCII_FULL},
-    utils::build_table,
+    row_template::RowTemplate,
+    sort::{SortOrder, natural_cmp},
+    style::{
+        BorderCollision, CellAlignment, ColumnConstraint, ContentArrangement, HeaderStyle,
+        StyleOverrides, TableComponent, Theme, WidthTable, presets::ASCII_FULL,
+    },
+    utils::{
+        build_arranged_columns, build_html, build_layout, build_linear, build_markdown,
+        build_overflow_report, build_pages, build_partial, build_rendered, build_rows_stream,
+        build_skeleton, build_style_lint, build_table, build_width_audit, check_fits,
+        formatting::{borders::should_draw_vertical_lines, content_split::measure_text_width},
+        grid, measure_content, try_build_table,
+    },
+    view_state::TableViewState,
 };
 
 /// This is the main interface for building a table.
@@ -25,10 +54,24 @@ pub struct Table {
     pub(crate) columns: Vec<Column>,
     style: HashMap<TableComponent, char>,
     pub(crate) header: Option<Row>,
+    /// Translates the content of the header cells, when the table is displayed.
+    pub(crate) header_translator: Option<HeaderTranslator>,
+    /// Whether the header has been [generated](Table::generate_headers).
+    pub(crate) header_generated: bool,
+    /// The labels above the header and the amount of columns each of them spans.
+    pub(crate) header_groups: Vec<(Cell, usize)>,
     pub(crate) rows: Vec<Row>,
+    pub(crate) footer: Option<Row>,
+    /// Pre-formatted lines and the index of the row they're drawn above.
+    pub(crate) raw_lines: Vec<(usize, String)>,
+    /// The index of the first row of each row group and its label.
+    pub(crate) row_groups: Vec<(usize, Cell)>,
+    pub(crate) title: Option<Cell>,
+    pub(crate) caption: Option<Cell>,
     pub(crate) arrangement: ContentArrangement,
     pub(crate) delimiter: Option<char>,
     pub(crate) truncation_indicator: String,
+    pub(crate) border_collision: BorderCollision,
     #[cfg(feature = "tty")]
     no_tty: bool,
     #[cfg(feature = "tty")]
@@ -42,11 +85,58 @@ pub struct Table {
     /// or whether only the text should be styled.
     #[cfg(feature = "tty")]
     pub(crate) style_text_only: bool,
+    /// The alternating background colors of even and odd rows.
+    #[cfg(feature = "tty")]
+    pub(crate) zebra_striping: Option<(Color, Color)>,
+    /// The theme that has been applied last, which determines the styling of the header.
+    pub(crate) theme: Option<Theme>,
+    /// The max content widths of all columns, if the table has been [measured](Table::measure).
+    measured_widths: Option<Vec<u16>>,
+    /// The positions of spanned cells, which are used to look up cells by their coordinates.
+    grid_cache: grid::GridCache,
+    deterministic: bool,
+    strict: bool,
+    pub(crate) width_table: WidthTable,
+    pub(crate) details_column: Option<usize>,
+    pub(crate) equal_column_widths: bool,
+    /// Whether repeated separator lines are collapsed into a single one.
+    pub(crate) collapse_separators: bool,
+    /// Whether the footer is displayed on every page.
+    pub(crate) repeat_footer: bool,
+    /// Whether header icons are replaced by their ASCII fallback.
+    pub(crate) ascii_icons: bool,
+    /// The padding of newly created columns.
+    default_padding: (u16, u16),
+    /// The formatting of cells in rows, which are added to the table.
+    row_template: Option<RowTemplate>,
+}
+
+/// A translation of the header's content, which is applied when displaying the table.
+#[derive(Clone)]
+pub(crate) struct HeaderTranslator(Arc<dyn Fn(&str) -> String + Send + Sync>);
+
+impl HeaderTranslator {
+    pub(crate) fn translate(&self, key: &str) -> String {
+        (self.0)(key)
+    }
+}
+
+impl fmt::Debug for HeaderTranslator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("HeaderTranslator")
+    }
 }
 
 impl fmt::Display for Table {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.lines().collect::<Vec<_>>().join("\n"))
+        for (index, line) in self.lines().enumerate() {
+            if index > 0 {
+                f.write_char('\n')?;
+            }
+            f.write_str(&line)?;
+        }
+
+        Ok(())
     }
 }
 
@@ -62,10 +152,19 @@ impl Table {
         let mut table = Self {
             columns: Vec::new(),
             header: None,
+            header_translator: None,
+            header_generated: false,
+            header_groups: Vec::new(),
             rows: Vec::new(),
+            footer: None,
+            raw_lines: Vec::new(),
+            row_groups: Vec::new(),
+            title: None,
+            caption: None,
             arrangement: ContentArrangement::Disabled,
             delimiter: None,
             truncation_indicator: "...".to_string(),
+            border_collision: BorderCollision::Keep,
             #[cfg(feature = "tty")]
             no_tty: false,
             #[cfg(feature = "tty")]
@@ -78,6 +177,21 @@ impl Table {
             enforce_styling: false,
             #[cfg(feature = "tty")]
             style_text_only: false,
+            #[cfg(feature = "tty")]
+            zebra_striping: None,
+            theme: None,
+            measured_widths: None,
+            grid_cache: grid::GridCache::default(),
+            deterministic: false,
+            strict: false,
+            width_table: WidthTable::default(),
+            details_column: None,
+            equal_column_widths: false,
+            collapse_separators: false,
+            repeat_footer: false,
+            ascii_icons: false,
+            default_padding: (1, 1),
+            row_template: None,
         };
 
         table.load_preset(ASCII_FULL);
@@ -100,6 +214,341 @@ impl Table {
         build_table(self)
     }
 
+    /// Write the table line by line to the given writer, without building one large string first.
+    ///
+    /// Each line is terminated by a newline, just like when printing the table via `println!`.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["One", "Two"]);
+    ///
+    /// let mut output = Vec::new();
+    /// table.write_to(&mut output).unwrap();
+    ///
+    /// assert_eq!(String::from_utf8(output).unwrap(), table.to_string() + "\n");
+    /// ```
+    pub fn write_to<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        for line in self.lines() {
+            writer.write_all(line.as_bytes())?;
+            writer.write_all(b"\n")?;
+        }
+
+        Ok(())
+    }
+
+    /// Same as [Table::write_to], but for writers implementing [fmt::Write], such as [String].
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["One", "Two"]);
+    ///
+    /// let mut output = String::new();
+    /// table.write_to_fmt(&mut output).unwrap();
+    ///
+    /// assert_eq!(output, table.to_string() + "\n");
+    /// ```
+    pub fn write_to_fmt<W: fmt::Write>(&self, mut writer: W) -> fmt::Result {
+        for line in self.lines() {
+            writer.write_str(&line)?;
+            writer.write_char('\n')?;
+        }
+
+        Ok(())
+    }
+
+    /// Same as [Table::lines], but the layout is validated before rendering, if the table is in
+    /// [strict mode](Table::set_strict).
+    ///
+    /// ```
+    /// use comfy_table::{Cell, ContentArrangement, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_strict(true)
+    ///     .set_content_arrangement(ContentArrangement::Dynamic)
+    ///     .set_width(10)
+    ///     .add_row(vec![Cell::new("a1b2c3d4-e5f6").set_never_truncate(true)]);
+    ///
+    /// assert!(table.try_lines().is_err());
+    /// ```
+    pub fn try_lines(&self) -> Result<impl Iterator<Item = String>, LayoutError> {
+        try_build_table(self)
+    }
+
+    /// Compute the positions of all cells in the rendered table.
+    ///
+    /// The returned [Layout] allows to map a position in the output of [Table::lines] back to a
+    /// cell via [Layout::hit_test].\
+    /// The layout is only valid as long as the table isn't modified.
+    pub fn layout(&self) -> Layout {
+        build_layout(self)
+    }
+
+    /// Arrange the columns of the table, just like it's done when rendering the table.
+    ///
+    /// This allows to inspect the final width of each column without rendering the table.
+    ///
+    /// ```
+    /// use comfy_table::{ColumnConstraint, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["One", "Two", "Three"]);
+    /// table
+    ///     .column_mut(1)
+    ///     .unwrap()
+    ///     .set_constraint(ColumnConstraint::Hidden);
+    ///
+    /// let columns = table.arranged_columns();
+    /// assert_eq!(columns[0].content_width(), 3);
+    /// assert_eq!(columns[0].width(), 5);
+    /// assert!(columns[1].is_hidden());
+    /// assert_eq!(columns[2].padding(), (1, 1));
+    /// ```
+    pub fn arranged_columns(&self) -> Vec<ArrangedColumn> {
+        build_arranged_columns(self)
+    }
+
+    /// The final width of each column including its padding, just like it's arranged when rendering
+    /// the table. The width of hidden columns is `0`.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["One", "Three"]);
+    ///
+    /// assert_eq!(table.column_display_widths(), vec![5, 7]);
+    /// ```
+    pub fn column_display_widths(&self) -> Vec<u16> {
+        self.arranged_columns()
+            .iter()
+            .map(ArrangedColumn::width)
+            .collect()
+    }
+
+    /// List all cells of the header and the rows, whose content doesn't fit into their column and
+    /// is therefore wrapped or truncated when rendering the table.
+    ///
+    /// This allows to hint users at options to display the full content.
+    ///
+    /// ```
+    /// use comfy_table::{ContentArrangement, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_content_arrangement(ContentArrangement::Dynamic)
+    ///     .set_width(20)
+    ///     .add_row(vec!["id", "A description, which doesn't fit"]);
+    ///
+    /// let overflows = table.overflow_report();
+    /// assert_eq!(overflows.len(), 1);
+    /// assert_eq!(overflows[0].column, 1);
+    /// assert!(!overflows[0].truncated);
+    /// println!("{} values wrapped, pass --wide to see the full output", overflows.len());
+    /// ```
+    pub fn overflow_report(&self) -> Vec<CellOverflow> {
+        build_overflow_report(self)
+    }
+
+    /// Render the table and check whether every line is exactly as wide as the table.
+    ///
+    /// Each line is measured as a whole, while the content of cells is measured piece by piece
+    /// during rendering. Mismatches indicate that some characters, e.g. exotic Unicode sequences,
+    /// aren't measured consistently and that the table will look broken in a terminal.\
+    /// Annotations, [raw lines](Table::add_raw_line), the title and the caption are skipped.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["One", "Two"]);
+    ///
+    /// assert!(table.audit_widths().is_empty());
+    /// ```
+    pub fn audit_widths(&self) -> Vec<WidthMismatch> {
+        build_width_audit(&self.unstyled())
+    }
+
+    /// Check the styling of the table for combinations, which are likely unreadable.
+    ///
+    /// This finds cells whose text has the same color as their background, colors that won't be
+    /// displayed due to the `NO_COLOR` environment variable, unless the table is
+    /// [deterministic](Table::set_deterministic), and columns that are too narrow to display any
+    /// part of their header.
+    /// The checks are based on the styling after the rows and the [theme](Table::apply_theme)
+    /// have been applied. The footer is skipped.
+    ///
+    /// ```
+    /// use comfy_table::{Cell, Color, StyleWarning, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec![Cell::new("Invisible").fg(Color::Black).bg(Color::Black)]);
+    ///
+    /// assert_eq!(
+    ///     table.lint_styles()[0],
+    ///     StyleWarning::SameColors {
+    ///         row: Some(0),
+    ///         column: 0,
+    ///         color: Color::Black,
+    ///     }
+    /// );
+    /// ```
+    pub fn lint_styles(&self) -> Vec<StyleWarning> {
+        build_style_lint(self)
+    }
+
+    /// Measure the content of the table once, so it can be rendered with different widths
+    /// without measuring it again.
+    ///
+    /// Only the width of the content is reused. The columns are still arranged and the content is
+    /// still split into lines for every width. See [MeasuredTable::rerender_with_width].
+    pub fn measure(&self) -> MeasuredTable {
+        let mut table = self.clone();
+        table.measured_widths = Some(measure_content(self));
+
+        MeasuredTable::new(table)
+    }
+
+    /// Render the table and keep facts about the output, such as the final column widths and the
+    /// lines of each row.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["One", "Two"]);
+    ///
+    /// let rendered = table.render();
+    /// assert_eq!(rendered.column_widths(), &[5, 5]);
+    /// assert_eq!(rendered.to_string(), table.to_string());
+    /// ```
+    pub fn render(&self) -> RenderedTable {
+        build_rendered(self)
+    }
+
+    /// Render the structure of the table with `rows` blank data rows.
+    ///
+    /// The column widths are computed from the table's current content, such as the header.
+    /// This allows to display a placeholder table, while the actual data is still being loaded.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.set_header(vec!["Name", "Status"]);
+    ///
+    /// let skeleton: Vec<String> = table.render_skeleton(2).collect();
+    /// assert_eq!(skeleton.len(), 7);
+    /// ```
+    pub fn render_skeleton(&self, rows: usize) -> impl Iterator<Item = String> {
+        build_skeleton(self, rows)
+    }
+
+    /// Render the table in pages, which contain the given amount of rows each.
+    ///
+    /// Every page is a complete table with the header and all borders, which is useful for
+    /// printing fixed-height reports or paging long output. The columns are arranged only once,
+    /// so they have the same width on all pages.\
+    /// The title is only displayed on the first page, the caption only on the last one.
+    /// The footer is displayed on the last page, unless it's [repeated](Table::set_repeat_footer)
+    /// on every page.\
+    /// If the [ContinuationBorder](TableComponent::ContinuationBorder) is set, it's drawn in place
+    /// of the bottom border on all pages but the last one.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Host", "Status"])
+    ///     .add_row(vec!["web-1", "up"])
+    ///     .add_row(vec!["web-2", "up"])
+    ///     .add_row(vec!["database", "down"]);
+    ///
+    /// let pages: Vec<String> = table.render_pages(2).collect();
+    /// let expected = "
+    /// +----------+--------+
+    /// | Host     | Status |
+    /// +===================+
+    /// | database | down   |
+    /// +----------+--------+";
+    /// assert_eq!(pages.len(), 2);
+    /// assert_eq!(expected, "\n".to_string() + &pages[1]);
+    /// ```
+    pub fn render_pages(&self, rows_per_page: usize) -> impl Iterator<Item = String> {
+        build_pages(self, rows_per_page)
+    }
+
+    /// Render the header of the table, followed by the given rows, one row at a time.
+    ///
+    /// The columns are arranged only once, based on the current content of the table, e.g. the
+    /// header and a few sample rows. The rows of the table itself aren't rendered.\
+    /// Each of the given rows is then formatted with these column widths as soon as the next line
+    /// is requested. This allows to print huge amounts of rows without keeping them in memory.
+    ///
+    /// Cells that span multiple rows are cut off at the end of their row.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Level", "Message"])
+    ///     .add_row(vec!["WARN", "A typical message"]);
+    ///
+    /// let logs = (0..100_000).map(|index| vec!["INFO".to_string(), format!("Message {index}")]);
+    /// let mut lines = table.render_rows_iter(logs);
+    /// assert_eq!(lines.nth(3).unwrap(), "| INFO  | Message 0         |");
+    /// ```
+    pub fn render_rows_iter<I, T>(&self, rows: I) -> impl Iterator<Item = String>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Row>,
+    {
+        build_rows_stream(self, rows.into_iter().map(Into::into))
+    }
+
+    /// Render the table, until the given time budget is used up.
+    ///
+    /// This prevents interactive applications from freezing on huge tables. The returned
+    /// [PartialRender] contains all lines that have been rendered in time. If the table isn't
+    /// complete, its [continuation](PartialRender::continuation) renders the next rows with
+    /// another budget.\
+    /// The columns are arranged once for the whole table, which isn't interrupted. All following
+    /// calls only render rows. At least one row is rendered per call, so the rendering always
+    /// makes progress.
+    ///
+    /// Tables with cells that [span multiple rows](Cell::set_rowspan) can't be split between
+    /// their rows. They're rendered completely in one go, regardless of the budget.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.set_header(vec!["Index"]);
+    /// for index in 0..1000 {
+    ///     table.add_row(vec![index]);
+    /// }
+    ///
+    /// let (mut lines, mut continuation) = table.render_with_deadline(Duration::ZERO).into_parts();
+    /// while let Some(token) = continuation {
+    ///     // Process input events, then continue rendering.
+    ///     let (next_lines, next) = token.resume(Duration::from_millis(10)).into_parts();
+    ///     lines.extend(next_lines);
+    ///     continuation = next;
+    /// }
+    /// assert_eq!(lines.join("\n"), table.to_string());
+    /// ```
+    pub fn render_with_deadline(&self, budget: Duration) -> PartialRender {
+        build_partial(self, budget)
+    }
+
     /// Set the header row of the table. This is usually the title of each column.\
     /// There'll be no header unless you explicitly set it with this function.
     ///
@@ -113,7 +562,9 @@ impl Table {
     pub fn set_header<T: Into<Row>>(&mut self, row: T) -> &mut Self {
         let row = row.into();
         self.autogenerate_columns(&row);
+        self.grid_cache.reset();
         self.header = Some(row);
+        self.header_generated = false;
 
         self
     }
@@ -122,6 +573,258 @@ impl Table {
         self.header.as_ref()
     }
 
+    /// Set a header, whose cells are named after the index of their column.
+    ///
+    /// This is useful for data without a header, e.g. plain rows of values.
+    /// The header has a cell for each column that's currently known to the table.\
+    /// The table remembers that the header has been generated. Such headers aren't written by
+    /// [Table::to_csv], so the data can be written back the way it was read. Custom
+    /// [exporters](TableExporter) can check for them via [Table::is_header_generated].
+    ///
+    /// ```
+    /// use comfy_table::{HeaderStyle, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .add_row(vec!["web-1", "up", "3"])
+    ///     .generate_headers(HeaderStyle::Letters);
+    ///
+    /// let expected = "
+    /// +-------+----+---+
+    /// | A     | B  | C |
+    /// +================+
+    /// | web-1 | up | 3 |
+    /// +-------+----+---+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// assert!(table.is_header_generated());
+    /// ```
+    pub fn generate_headers(&mut self, style: HeaderStyle) -> &mut Self {
+        let names: Vec<String> = (0..self.column_count())
+            .map(|index| style.name(index))
+            .collect();
+        self.set_header(names);
+        self.header_generated = true;
+
+        self
+    }
+
+    /// Returns whether the header has been generated via [Table::generate_headers].
+    pub fn is_header_generated(&self) -> bool {
+        self.header_generated
+    }
+
+    /// Translate the content of all header cells, whenever the table is displayed.
+    ///
+    /// This allows to store stable keys in the header, which are then displayed in the language of
+    /// the user. The translator gets the content of a header cell and returns its translation.\
+    /// [Table::header] and the CSV export keep the original keys.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["name", "size"])
+    ///     .add_row(vec!["notes.txt", "4 KiB"])
+    ///     .set_header_translator(|key| match key {
+    ///         "name" => "Nom".to_string(),
+    ///         "size" => "Taille".to_string(),
+    ///         other => other.to_string(),
+    ///     });
+    ///
+    /// let expected = "
+    /// +-----------+--------+
+    /// | Nom       | Taille |
+    /// +====================+
+    /// | notes.txt | 4 KiB  |
+    /// +-----------+--------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_header_translator<F>(&mut self, translator: F) -> &mut Self
+    where
+        F: Fn(&str) -> String + Send + Sync + 'static,
+    {
+        self.header_translator = Some(HeaderTranslator(Arc::new(translator)));
+
+        self
+    }
+
+    /// Remove the translator of the header and display its original content again.
+    pub fn remove_header_translator(&mut self) -> &mut Self {
+        self.header_translator = None;
+
+        self
+    }
+
+    /// Group the columns of the table below common labels.
+    ///
+    /// The groups are drawn as an additional row above the header. Each group consists of a label
+    /// and the amount of consecutive columns it spans, starting with the first column.
+    /// Labels are centered above their columns by default, which are widened if a label doesn't
+    /// fit otherwise. Columns after the last group don't have a label.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header_groups(vec![("RX", 2), ("TX", 2)])
+    ///     .set_header(vec!["bytes", "packets", "bytes", "packets"])
+    ///     .add_row(vec!["1024", "8", "512", "4"]);
+    ///
+    /// let expected = "
+    /// +-----------------+-----------------+
+    /// |        RX       |        TX       |
+    /// |-------+---------+-------+---------|
+    /// | bytes | packets | bytes | packets |
+    /// +===================================+
+    /// | 1024  | 8       | 512   | 4       |
+    /// +-------+---------+-------+---------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_header_groups<I, T>(&mut self, groups: I) -> &mut Self
+    where
+        I: IntoIterator<Item = (T, usize)>,
+        T: Into<Cell>,
+    {
+        self.header_groups = groups
+            .into_iter()
+            .map(|(label, columns)| (label.into(), columns))
+            .collect();
+
+        self
+    }
+
+    /// The groups of columns, which have been set via [Table::set_header_groups].
+    pub fn header_groups(&self) -> &[(Cell, usize)] {
+        &self.header_groups
+    }
+
+    /// Set the footer row of the table, e.g. for totals or a summary.\
+    /// The footer is displayed below all rows and is separated from them by a line, which is
+    /// styled via the footer components of [TableComponent], e.g. [TableComponent::FooterLines].
+    /// Annotations of the footer aren't displayed.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Item", "Price"])
+    ///     .add_row(vec!["Apple", "1.20"])
+    ///     .add_row(vec!["Pear", "0.80"])
+    ///     .set_footer(vec!["Total", "2.00"]);
+    ///
+    /// let expected = "
+    /// +-------+-------+
+    /// | Item  | Price |
+    /// +===============+
+    /// | Apple | 1.20  |
+    /// |-------+-------|
+    /// | Pear  | 0.80  |
+    /// +===============+
+    /// | Total | 2.00  |
+    /// +-------+-------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_footer<T: Into<Row>>(&mut self, row: T) -> &mut Self {
+        let row = row.into();
+        self.autogenerate_columns(&row);
+        self.grid_cache.reset();
+        self.footer = Some(row);
+
+        self
+    }
+
+    /// The footer row of the table, if it has been set via [Table::set_footer].
+    pub fn footer(&self) -> Option<&Row> {
+        self.footer.as_ref()
+    }
+
+    /// Display the footer on every page, when the table is rendered via [Table::render_pages].
+    ///
+    /// That way, a footer with totals stays visible on each page of a report.
+    /// By default, the footer is only displayed on the last page.
+    pub fn set_repeat_footer(&mut self, repeat: bool) -> &mut Self {
+        self.repeat_footer = repeat;
+
+        self
+    }
+
+    /// Returns whether the footer is [displayed on every page](Table::set_repeat_footer).
+    pub fn is_repeat_footer(&self) -> bool {
+        self.repeat_footer
+    }
+
+    /// Set a title, which is displayed above the table.
+    ///
+    /// The title spans the full width of the table and is centered by default.
+    /// Use [Cell::set_alignment] to align it differently. Titles that are wider than the table
+    /// are wrapped.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_title("Fruits")
+    ///     .set_header(vec!["Item", "Price"])
+    ///     .add_row(vec!["Apple", "1.20"]);
+    ///
+    /// let expected = "
+    ///       Fruits
+    /// +-------+-------+
+    /// | Item  | Price |
+    /// +===============+
+    /// | Apple | 1.20  |
+    /// +-------+-------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_title<T: Into<Cell>>(&mut self, title: T) -> &mut Self {
+        self.title = Some(title.into());
+
+        self
+    }
+
+    /// The title of the table, if it has been set via [Table::set_title].
+    pub fn title(&self) -> Option<&Cell> {
+        self.title.as_ref()
+    }
+
+    /// Set a caption, which is displayed below the table.
+    ///
+    /// Just like the [title](Table::set_title), the caption spans the full width of the table
+    /// and is centered by default.
+    ///
+    /// ```
+    /// use comfy_table::{Cell, CellAlignment, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Item", "Price"])
+    ///     .add_row(vec!["Apple", "1.20"])
+    ///     .set_caption(Cell::new("Prices in EUR").set_alignment(CellAlignment::Right));
+    ///
+    /// let expected = "
+    /// +-------+-------+
+    /// | Item  | Price |
+    /// +===============+
+    /// | Apple | 1.20  |
+    /// +-------+-------+
+    ///     Prices in EUR";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_caption<T: Into<Cell>>(&mut self, caption: T) -> &mut Self {
+        self.caption = Some(caption.into());
+
+        self
+    }
+
+    /// The caption of the table, if it has been set via [Table::set_caption].
+    pub fn caption(&self) -> Option<&Cell> {
+        self.caption.as_ref()
+    }
+
     /// Returns the number of currently present columns.
     ///
     /// ```
@@ -143,17 +846,171 @@ impl Table {
     /// use comfy_table::{Row, Table};
     ///
     /// let mut table = Table::new();
-    /// table.add_row(vec!["One", "Two"]);
+    /// table.add_row(vec!["One", "Two"]);
+    /// ```
+    pub fn add_row<T: Into<Row>>(&mut self, row: T) -> &mut Self {
+        let mut row = row.into();
+        if let Some(template) = &self.row_template {
+            template.apply(&mut row);
+        }
+        self.autogenerate_columns(&row);
+        self.grid_cache.reset();
+        row.index = Some(self.rows.len());
+        self.rows.push(row);
+
+        self
+    }
+
+    /// Format the cells of all rows, which are added from now on, via the given [RowTemplate].
+    ///
+    /// Rows that have already been added aren't changed. The header and the footer are never
+    /// formatted by the template.
+    ///
+    /// ```
+    /// use comfy_table::{RowTemplate, Table};
+    ///
+    /// let mut template = RowTemplate::new();
+    /// template.set_column(0, |cell| cell.set_never_truncate(true));
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .add_row(vec!["Before"])
+    ///     .set_row_template(template)
+    ///     .add_row(vec!["After"]);
+    ///
+    /// assert!(!table.cell(0, 0).unwrap().is_never_truncate());
+    /// assert!(table.cell(1, 0).unwrap().is_never_truncate());
+    /// ```
+    pub fn set_row_template(&mut self, template: RowTemplate) -> &mut Self {
+        self.row_template = Some(template);
+
+        self
+    }
+
+    /// Remove the row template. Rows that are added afterwards are kept as they are.
+    pub fn remove_row_template(&mut self) -> &mut Self {
+        self.row_template = None;
+
+        self
+    }
+
+    /// The template, which formats newly added rows.
+    pub fn row_template(&self) -> Option<&RowTemplate> {
+        self.row_template.as_ref()
+    }
+
+    /// Insert a pre-formatted line below the rows that have been added so far.
+    ///
+    /// The line is drawn verbatim between the rows. It isn't measured, padded or styled and
+    /// doesn't influence the arrangement of the table.\
+    /// This is intended for out-of-band content such as page-feed markers.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .add_row(vec!["One", "Two"])
+    ///     .add_raw_line("--- page 2 ---")
+    ///     .add_row(vec!["Three", "Four"]);
+    ///
+    /// let expected = "
+    /// +-------+------+
+    /// | One   | Two  |
+    /// |-------+------|
+    /// --- page 2 ---
+    /// | Three | Four |
+    /// +-------+------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn add_raw_line<T: ToString>(&mut self, line: T) -> &mut Self {
+        let position = self.rows.len();
+        for line in line.to_string().split('\n') {
+            self.raw_lines.push((position, line.to_string()));
+        }
+
+        self
+    }
+
+    /// Add a group of rows, which is displayed below a label that spans the whole table.
+    ///
+    /// Groups are separated by a horizontal line. Presets without lines between rows use the line
+    /// below the header instead, if they have one.\
+    /// Rows that are added afterwards belong to this group, until the next group is added.
+    /// Groups without any rows aren't displayed.
+    ///
+    /// ```
+    /// use comfy_table::{Table, presets::UTF8_FULL_CONDENSED};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .load_preset(UTF8_FULL_CONDENSED)
+    ///     .set_header(vec!["Name", "Price"])
+    ///     .add_row_group("Fruits", vec![vec!["Apple", "1.20"], vec!["Pear", "0.80"]])
+    ///     .add_row_group("Vegetables", vec![vec!["Leek", "2.10"]]);
+    ///
+    /// let expected = "
+    /// ┌───────┬───────┐
+    /// │ Name  ┆ Price │
+    /// ╞═══════╪═══════╡
+    /// │ Fruits        │
+    /// │ Apple ┆ 1.20  │
+    /// │ Pear  ┆ 0.80  │
+    /// ╞═══════╪═══════╡
+    /// │ Vegetables    │
+    /// │ Leek  ┆ 2.10  │
+    /// └───────┴───────┘";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn add_row_group<L, I>(&mut self, label: L, rows: I) -> &mut Self
+    where
+        L: Into<Cell>,
+        I: IntoIterator,
+        I::Item: Into<Row>,
+    {
+        let start = self.rows.len();
+        self.add_rows(rows);
+        if self.rows.len() > start {
+            self.row_groups.push((start, label.into()));
+        }
+
+        self
+    }
+
+    /// Collapse consecutive identical lines, which don't belong to any cells, into a single line.
+    ///
+    /// This prevents double rules, if a [raw line](Table::add_raw_line) repeats the separator of
+    /// the rows it's placed between.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_collapse_separators(true)
+    ///     .add_row(vec!["One", "Two"])
+    ///     .add_raw_line("|-------+------|")
+    ///     .add_row(vec!["Three", "Four"]);
+    ///
+    /// let expected = "
+    /// +-------+------+
+    /// | One   | Two  |
+    /// |-------+------|
+    /// | Three | Four |
+    /// +-------+------+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
     /// ```
-    pub fn add_row<T: Into<Row>>(&mut self, row: T) -> &mut Self {
-        let mut row = row.into();
-        self.autogenerate_columns(&row);
-        row.index = Some(self.rows.len());
-        self.rows.push(row);
+    pub fn set_collapse_separators(&mut self, collapse: bool) -> &mut Self {
+        self.collapse_separators = collapse;
 
         self
     }
 
+    /// Returns whether repeated separator lines are collapsed into a single line.
+    pub fn is_collapse_separators(&self) -> bool {
+        self.collapse_separators
+    }
+
     /// Add a new row to the table if the predicate evaluates to `true`.
     ///
     /// ```
@@ -190,10 +1047,14 @@ impl Table {
     {
         for row in rows.into_iter() {
             let mut row = row.into();
+            if let Some(template) = &self.row_template {
+                template.apply(&mut row);
+            }
             self.autogenerate_columns(&row);
             row.index = Some(self.rows.len());
             self.rows.push(row);
         }
+        self.grid_cache.reset();
 
         self
     }
@@ -286,6 +1147,111 @@ impl Table {
         self.width
     }
 
+    /// Check whether the table can be displayed within `width` characters without wrapping any
+    /// content.
+    ///
+    /// The table is arranged just like it would be when rendered with
+    /// [set_width](Table::set_width). If any column has to wrap its content or the table ends up
+    /// wider than `width`, a [LayoutError] is returned, which lists all overflowing columns.
+    ///
+    /// ```
+    /// use comfy_table::{ContentArrangement, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_content_arrangement(ContentArrangement::Dynamic)
+    ///     .add_row(vec!["One", "Two"]);
+    ///
+    /// assert!(table.assert_fits(20).is_ok());
+    /// assert!(table.assert_fits(8).is_err());
+    /// ```
+    pub fn assert_fits(&self, width: u16) -> Result<(), LayoutError> {
+        let mut table = self.clone();
+        table.set_width(width);
+
+        check_fits(&table, width)
+    }
+
+    /// Enable strict mode.
+    ///
+    /// In strict mode, [Table::try_lines] returns a [LayoutError] instead of rendering a table,
+    /// whose layout breaks guarantees such as [Cell::set_never_truncate].\
+    /// The normal [Display](std::fmt::Display) implementation and [Table::lines] aren't affected
+    /// and always render the table as good as possible.
+    pub fn set_strict(&mut self, strict: bool) -> &mut Self {
+        self.strict = strict;
+
+        self
+    }
+
+    /// Returns whether the table is in [strict mode](Table::set_strict).
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Override the display width of specific characters.
+    ///
+    /// Check [WidthTable] for more information.
+    pub fn set_width_table(&mut self, widths: WidthTable) -> &mut Self {
+        self.width_table = widths;
+
+        self
+    }
+
+    /// Get the [WidthTable] that's used to determine the display width of content.
+    pub fn width_table(&self) -> &WidthTable {
+        &self.width_table
+    }
+
+    /// Divide the width of the table equally between all visible columns, regardless of their
+    /// content.
+    ///
+    /// This results in a stable layout, which doesn't change when the content changes.
+    /// Columns with a fixed width via constraints, such as [ColumnConstraint::Absolute], keep
+    /// their width.\
+    /// The width of the table needs to be known, i.e. either a tty is detected or the width is
+    /// set via [set_width](Table::set_width). Otherwise, this setting is ignored.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_width(40)
+    ///     .set_equal_column_widths(true)
+    ///     .add_row(vec!["One", "Two", "Three"]);
+    /// ```
+    pub fn set_equal_column_widths(&mut self, equal: bool) -> &mut Self {
+        self.equal_column_widths = equal;
+
+        self
+    }
+
+    /// Designate a column, whose content is displayed below each row, if it doesn't fit into the
+    /// table.
+    ///
+    /// This is useful for columns with long free text, such as messages in a log table.\
+    /// If the content of this column would need to be wrapped, the column is removed from the
+    /// table and its content is displayed as an indented block below each row instead.
+    /// That block spans the full width of the table.
+    ///
+    /// ```
+    /// use comfy_table::{ContentArrangement, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_content_arrangement(ContentArrangement::Dynamic)
+    ///     .set_width(30)
+    ///     .set_header(vec!["Level", "Message"])
+    ///     .add_row(vec!["ERROR", "Connection to the database was lost"])
+    ///     .set_details_column(1);
+    /// ```
+    pub fn set_details_column(&mut self, index: usize) -> &mut Self {
+        self.details_column = Some(index);
+
+        self
+    }
+
     /// Specify how Comfy Table should arrange the content in your table.
     ///
     /// ```
@@ -325,6 +1291,38 @@ impl Table {
         self
     }
 
+    /// Set how characters of the cell content are displayed, which are also used to draw the
+    /// borders of the table.
+    ///
+    /// That way, the structure of the table stays unambiguous for tools that parse the output,
+    /// e.g. a `|` inside of a cell of an ASCII table.
+    /// This applies to the header, the rows and the footer.
+    ///
+    /// ```
+    /// use comfy_table::{BorderCollision, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_border_collision(BorderCollision::Escape)
+    ///     .add_row(vec!["a|b", "c"]);
+    ///
+    /// let expected = "
+    /// +------+---+
+    /// | a\\|b | c |
+    /// +------+---+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_string());
+    /// ```
+    pub fn set_border_collision(&mut self, collision: BorderCollision) -> &mut Self {
+        self.border_collision = collision;
+
+        self
+    }
+
+    /// How characters of the cell content are displayed, which are also used to draw the borders.
+    pub fn border_collision(&self) -> BorderCollision {
+        self.border_collision
+    }
+
     /// In case you are sure you don't want export tables to a tty or you experience
     /// problems with tty specific code, you can enforce a non_tty mode.
     ///
@@ -360,52 +1358,442 @@ impl Table {
     pub fn is_tty(&self) -> bool {
         use std::io::IsTerminal;
 
-        if self.no_tty {
-            return false;
+        if self.no_tty || self.deterministic {
+            return false;
+        }
+
+        *self.is_tty_cache.get_or_init(|| {
+            if self.use_stderr {
+                std::io::stderr().is_terminal()
+            } else {
+                std::io::stdout().is_terminal()
+            }
+        })
+    }
+
+    /// Make the output of this table independent of the environment it's rendered in.
+    ///
+    /// This disables every input that's looked up at render time:
+    ///
+    /// - width lookup from the current tty
+    /// - tty detection for styling (styling still works via [Table::enforce_styling])
+    /// - the `NO_COLOR` environment variable, colors of styled tables are always written
+    ///
+    /// The output then only depends on the table's content and configuration, which is useful
+    /// for snapshot tests and reproducible builds. If you use the
+    /// [dynamic content arrangement](ContentArrangement::Dynamic), set the width of the table
+    /// via [set_width](Table::set_width).
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.set_deterministic(true);
+    /// assert!(table.width().is_none());
+    /// ```
+    pub fn set_deterministic(&mut self, deterministic: bool) -> &mut Self {
+        self.deterministic = deterministic;
+
+        self
+    }
+
+    /// Returns whether environment dependent inputs are disabled via [Table::set_deterministic].
+    pub fn is_deterministic(&self) -> bool {
+        self.deterministic
+    }
+
+    /// Enforce terminal styling.
+    ///
+    /// Only useful if you forcefully disabled tty, but still want those fancy terminal styles.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.force_no_tty().enforce_styling();
+    /// ```
+    #[cfg(feature = "tty")]
+    pub fn enforce_styling(&mut self) -> &mut Self {
+        self.enforce_styling = true;
+
+        self
+    }
+
+    /// Returns whether the content of this table should be styled with the current settings and
+    /// environment.
+    #[cfg(feature = "tty")]
+    pub fn should_style(&self) -> bool {
+        if self.enforce_styling {
+            return true;
+        }
+        self.is_tty()
+    }
+
+    /// By default, the whole content of a cells will be styled.
+    /// Calling this function disables this behavior for all cells, resulting in
+    /// only the text of cells being styled.
+    #[cfg(feature = "tty")]
+    pub fn style_text_only(&mut self) {
+        self.style_text_only = true;
+    }
+
+    /// Alternate the background color of the rows.
+    ///
+    /// The first row gets the `even` color, the second row the `odd` color and so on.\
+    /// Background colors of [rows](Row::set_bg) and [cells](Cell::bg) take precedence.
+    ///
+    /// ```
+    /// use comfy_table::{Color, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_zebra_striping(Color::Black, Color::DarkGrey)
+    ///     .add_row(vec!["One", "Two"])
+    ///     .add_row(vec!["Three", "Four"]);
+    /// ```
+    #[cfg(feature = "tty")]
+    pub fn set_zebra_striping(&mut self, even: Color, odd: Color) -> &mut Self {
+        self.zebra_striping = Some((even, odd));
+
+        self
+    }
+
+    /// List all cells, whose content is rejected by the [validator](Column::set_validator) of
+    /// their column.
+    ///
+    /// Cells that span multiple columns are validated by their first column.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["web-1", "443"]).add_row(vec!["web-2", "https"]);
+    /// table
+    ///     .column_mut(1)
+    ///     .unwrap()
+    ///     .set_validator(|content| content.parse::<u16>().is_ok());
+    ///
+    /// let errors = table.validation_errors();
+    /// assert_eq!(errors[0].row, 1);
+    /// assert_eq!(errors[0].column, 1);
+    /// assert_eq!(errors[0].content, "https");
+    /// ```
+    pub fn validation_errors(&self) -> Vec<ValidationError> {
+        self.invalid_cells()
+            .into_iter()
+            .map(|(row, cell, column)| ValidationError {
+                row,
+                column,
+                content: self.rows[row].cells[cell].content(),
+                source: self.rows[row].source.clone(),
+            })
+            .collect()
+    }
+
+    /// The positions of all cells, whose content is rejected by the validator of their column.
+    ///
+    /// Returns the index of the row, the index of the cell in its row and the index of its column.
+    pub(crate) fn invalid_cells(&self) -> Vec<(usize, usize, usize)> {
+        if self.columns.iter().all(|column| column.validator.is_none()) {
+            return Vec::new();
+        }
+
+        let header_rows = usize::from(self.header.is_some());
+        grid::place_cells(self)
+            .iter()
+            .enumerate()
+            .skip(header_rows)
+            .take(self.rows.len())
+            .flat_map(|(row_index, slots)| {
+                // Cells that span multiple rows are only validated in their first row.
+                slots
+                    .iter()
+                    .filter(move |slot| slot.row == row_index)
+                    .map(move |slot| (row_index - header_rows, slot.cell, slot.columns.start))
+            })
+            .filter(|(row, cell, column)| {
+                let content = self.rows[*row].cells[*cell].content();
+                !self.columns[*column].is_valid(&content)
+            })
+            .collect()
+    }
+
+    /// Render the table as plain text and return the styling of its cells separately.
+    ///
+    /// Each [StyleSpan] references a byte range of the returned text.
+    /// This allows other tools, such as HTML converters, to apply the styling without having
+    /// to parse ANSI escape sequences.
+    ///
+    /// ```
+    /// use comfy_table::{Cell, Color, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec![Cell::new("Error").fg(Color::Red), Cell::new("Details")]);
+    ///
+    /// let (text, spans) = table.to_string_with_spans();
+    /// assert_eq!(&text[spans[0].range.clone()], " Error ");
+    /// assert_eq!(spans[0].fg, Some(Color::Red));
+    /// ```
+    #[cfg(feature = "tty")]
+    pub fn to_string_with_spans(&self) -> (String, Vec<StyleSpan>) {
+        build_style_spans(&self.unstyled())
+    }
+
+    /// Render the table in a way that's safe to paste into tickets, emails or chat messages.
+    ///
+    /// All non-ASCII border characters, such as box-drawing characters, are replaced with their
+    /// ASCII counterparts and all styling is removed.
+    /// [Header icons](Column::set_header_icon) are replaced by their fallback.
+    ///
+    /// ```
+    /// use comfy_table::{Table, presets::UTF8_FULL};
+    ///
+    /// let mut table = Table::new();
+    /// table.load_preset(UTF8_FULL).add_row(vec!["One", "Two"]);
+    ///
+    /// let expected = "
+    /// +-----+-----+
+    /// | One | Two |
+    /// +-----+-----+";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_copy_friendly_string());
+    /// ```
+    pub fn to_copy_friendly_string(&self) -> String {
+        let mut table = self.unstyled();
+        table.ascii_icons = true;
+        for (component, character) in table.style.iter_mut() {
+            if !character.is_ascii() {
+                *character = component.ascii_fallback();
+            }
+        }
+
+        let output = table.to_string();
+        #[cfg(feature = "custom_styling")]
+        let output = console::strip_ansi_codes(&output).to_string();
+
+        output
+    }
+
+    /// Render the table as a GitHub-flavored Markdown table.
+    ///
+    /// The alignment row is derived from each column's [CellAlignment](crate::CellAlignment).
+    /// Pipes in the content are escaped, newlines are replaced by `<br>` and cells aren't padded.
+    /// Styling, presets and the content arrangement don't apply to Markdown tables.
+    ///
+    /// ```
+    /// use comfy_table::{CellAlignment, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Name", "Size"])
+    ///     .add_row(vec!["a|b", "10 MB"]);
+    /// table
+    ///     .column_mut(1)
+    ///     .unwrap()
+    ///     .set_cell_alignment(CellAlignment::Right);
+    ///
+    /// let expected = "
+    /// | Name | Size |
+    /// | --- | ---: |
+    /// | a\\|b | 10 MB |";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_markdown());
+    /// ```
+    pub fn to_markdown(&self) -> String {
+        build_markdown(self)
+    }
+
+    /// Render each row of the table as a single line of values, which are labeled by their
+    /// header.
+    ///
+    /// This is intended for screen readers and other accessible output, as it doesn't rely on
+    /// the two-dimensional layout of the table. Empty cells and hidden columns are skipped.
+    /// The title and the caption are displayed on their own lines.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Host", "Status"])
+    ///     .add_row(vec!["web-1", "up"])
+    ///     .add_row(vec!["database", "down"]);
+    ///
+    /// let expected = "
+    /// Host: web-1; Status: up
+    /// Host: database; Status: down";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_linear_string());
+    /// ```
+    pub fn to_linear_string(&self) -> String {
+        build_linear(self)
+    }
+
+    /// Load a table from comma-separated values.
+    ///
+    /// The first record is used as the header, all other records are added as rows.
+    /// Fields may be enclosed in double quotes, which allows them to contain commas and newlines.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let input = "Name,Size\nfoo.txt,10 MB\n\"bar, baz.txt\",2 KB\n";
+    /// let table = Table::from_csv(input.as_bytes()).unwrap();
+    ///
+    /// assert_eq!(table.header().unwrap().cell_count(), 2);
+    /// assert_eq!(table.row_count(), 2);
+    /// ```
+    #[cfg(feature = "csv")]
+    pub fn from_csv<R: Read>(reader: R) -> io::Result<Self> {
+        Self::from_delimited(reader, ',')
+    }
+
+    /// Load a table from tab-separated values.
+    ///
+    /// This behaves just like [Table::from_csv], but fields are separated by tabs.
+    #[cfg(feature = "csv")]
+    pub fn from_tsv<R: Read>(reader: R) -> io::Result<Self> {
+        Self::from_delimited(reader, '\t')
+    }
+
+    #[cfg(feature = "csv")]
+    fn from_delimited<R: Read>(mut reader: R, delimiter: char) -> io::Result<Self> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+
+        let mut records = csv::read_records(&input, delimiter)?.into_iter();
+        let mut table = Table::new();
+        if let Some(header) = records.next() {
+            table.set_header(header);
+        }
+        table.add_rows(records);
+
+        Ok(table)
+    }
+
+    /// Write the header and all rows of the table as comma-separated values.
+    ///
+    /// The raw content of the cells is written without any truncation or styling.
+    /// Hidden columns, annotations, the footer and [generated headers](Table::generate_headers)
+    /// are omitted.\
+    /// Fields that contain commas, double quotes or newlines are enclosed in double quotes.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .set_header(vec!["Name", "Size"])
+    ///     .add_row(vec!["bar, baz.txt", "2 KB"]);
+    ///
+    /// let mut output = Vec::new();
+    /// table.to_csv(&mut output).unwrap();
+    ///
+    /// let expected = "Name,Size\n\"bar, baz.txt\",2 KB\n";
+    /// assert_eq!(expected, String::from_utf8(output).unwrap());
+    /// ```
+    #[cfg(feature = "csv")]
+    pub fn to_csv<W: Write>(&self, writer: W) -> io::Result<()> {
+        csv::write_records(self, writer, ',')
+    }
+
+    /// Write the header and all rows of the table as tab-separated values.
+    ///
+    /// This behaves just like [Table::to_csv], but fields are separated by tabs.
+    #[cfg(feature = "csv")]
+    pub fn to_tsv<W: Write>(&self, writer: W) -> io::Result<()> {
+        csv::write_records(self, writer, '\t')
+    }
+
+    /// Render the table as a HTML `<table>` with a `<thead>` and a `<tbody>`.
+    ///
+    /// Colors, attributes and alignments of cells are converted into inline styles.
+    /// Columns with an [absolute](ColumnConstraint::Absolute) width get a `width` hint.
+    /// Hidden columns are omitted.
+    ///
+    /// ```
+    /// use comfy_table::{CellAlignment, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table.set_header(vec!["Name", "Size"]).add_row(vec!["<a>", "10"]);
+    /// table
+    ///     .column_mut(1)
+    ///     .unwrap()
+    ///     .set_cell_alignment(CellAlignment::Right);
+    ///
+    /// let expected = "
+    /// <table>
+    ///   <thead>
+    ///     <tr>
+    ///       <th>Name</th>
+    ///       <th style=\"text-align: right\">Size</th>
+    ///     </tr>
+    ///   </thead>
+    ///   <tbody>
+    ///     <tr>
+    ///       <td>&lt;a&gt;</td>
+    ///       <td style=\"text-align: right\">10</td>
+    ///     </tr>
+    ///   </tbody>
+    /// </table>";
+    /// assert_eq!(expected, "\n".to_string() + &table.to_html());
+    /// ```
+    pub fn to_html(&self) -> String {
+        build_html(self)
+    }
+
+    /// Export the table via the given [TableExporter].
+    ///
+    /// ```
+    /// use comfy_table::{MarkdownExporter, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table.set_header(vec!["Name", "Size"]).add_row(vec!["foo", "10"]);
+    ///
+    /// let mut output = Vec::new();
+    /// table.export(&MarkdownExporter, &mut output).unwrap();
+    ///
+    /// let expected = "| Name | Size |\n| --- | --- |\n| foo | 10 |\n";
+    /// assert_eq!(String::from_utf8(output).unwrap(), expected);
+    /// ```
+    pub fn export<W: Write>(&self, exporter: &dyn TableExporter, mut writer: W) -> io::Result<()> {
+        exporter.export(self, &mut writer)
+    }
+
+    /// Clone this table with styling disabled, while keeping the width of the tty.
+    fn unstyled(&self) -> Table {
+        let mut table = self.clone();
+        // Keep the width of the tty, since it's no longer looked up.
+        if let Some(width) = self.width() {
+            table.set_width(width);
+        }
+        #[cfg(feature = "tty")]
+        {
+            table.no_tty = true;
+            table.enforce_styling = false;
         }
 
-        *self.is_tty_cache.get_or_init(|| {
-            if self.use_stderr {
-                std::io::stderr().is_terminal()
-            } else {
-                std::io::stdout().is_terminal()
-            }
-        })
+        table
     }
 
-    /// Enforce terminal styling.
+    /// Set the padding of all current and future columns.
     ///
-    /// Only useful if you forcefully disabled tty, but still want those fancy terminal styles.
+    /// Padding is provided in the form of (left, right).\
+    /// The padding of single columns can still be changed via [Column::set_padding] afterwards.
+    /// Default is `(1, 1)`.
     ///
     /// ```
     /// use comfy_table::Table;
     ///
     /// let mut table = Table::new();
-    /// table.force_no_tty().enforce_styling();
+    /// table.set_default_padding((0, 0)).add_row(vec!["one", "two"]);
+    ///
+    /// assert_eq!(table.column(1).unwrap().padding_width(), 0);
     /// ```
-    #[cfg(feature = "tty")]
-    pub fn enforce_styling(&mut self) -> &mut Self {
-        self.enforce_styling = true;
-
-        self
-    }
-
-    /// Returns whether the content of this table should be styled with the current settings and
-    /// environment.
-    #[cfg(feature = "tty")]
-    pub fn should_style(&self) -> bool {
-        if self.enforce_styling {
-            return true;
+    pub fn set_default_padding(&mut self, padding: (u16, u16)) -> &mut Self {
+        self.default_padding = padding;
+        for column in self.columns.iter_mut() {
+            column.set_padding(padding);
         }
-        self.is_tty()
-    }
 
-    /// By default, the whole content of a cells will be styled.
-    /// Calling this function disables this behavior for all cells, resulting in
-    /// only the text of cells being styled.
-    #[cfg(feature = "tty")]
-    pub fn style_text_only(&mut self) {
-        self.style_text_only = true;
+        self
     }
 
     /// Convenience method to set a [ColumnConstraint] for all columns at once.
@@ -503,6 +1891,88 @@ impl Table {
         preset_string
     }
 
+    /// Apply a [Theme] to the table.
+    ///
+    /// This loads the preset of the theme and sets the padding of all current and future
+    /// columns, if the theme has one. The styling of the header and the zebra striping of the
+    /// theme are applied when the table is rendered, so the header may also be set afterwards.
+    ///
+    /// ```
+    /// use comfy_table::{Attribute, Table, Theme, presets::UTF8_FULL};
+    ///
+    /// let mut theme = Theme::new(UTF8_FULL);
+    /// theme.add_header_attribute(Attribute::Bold);
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .apply_theme(&theme)
+    ///     .set_header(vec!["Header"])
+    ///     .add_row(vec!["Content"]);
+    /// ```
+    pub fn apply_theme(&mut self, theme: &Theme) -> &mut Self {
+        self.load_preset(theme.preset());
+        if let Some(padding) = theme.padding() {
+            self.set_default_padding(padding);
+        }
+        #[cfg(feature = "tty")]
+        {
+            self.zebra_striping = theme.zebra_striping();
+        }
+        self.theme = Some(theme.clone());
+
+        self
+    }
+
+    /// The theme, which has been applied last via [Table::apply_theme].
+    pub fn theme(&self) -> Option<&Theme> {
+        self.theme.as_ref()
+    }
+
+    /// Render the table with temporarily replaced styling.
+    ///
+    /// The overrides are applied to a copy of this table, which is passed to `render`.
+    /// This table isn't changed, so a single render can use a different preset or styling without
+    /// restoring the previous state by hand.
+    ///
+    /// ```
+    /// use comfy_table::{StyleOverrides, Table, presets::NOTHING};
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["One", "Two"]);
+    ///
+    /// let mut overrides = StyleOverrides::new();
+    /// overrides.set_preset(NOTHING);
+    ///
+    /// let plain = table.with_style_overrides(&overrides, |table| table.to_string());
+    /// assert_eq!(plain, " One  Two ");
+    /// assert_eq!(table.current_style_as_preset(), comfy_table::presets::ASCII_FULL);
+    /// ```
+    pub fn with_style_overrides<F, R>(&self, overrides: &StyleOverrides, render: F) -> R
+    where
+        F: FnOnce(&Table) -> R,
+    {
+        let mut table = self.clone();
+        if let Some(theme) = overrides.theme() {
+            table.apply_theme(theme);
+        }
+        if let Some(preset) = overrides.preset() {
+            table.load_preset(preset);
+        }
+        #[cfg(feature = "tty")]
+        match overrides.styling() {
+            Some(true) => {
+                table.enforce_styling = true;
+            }
+            Some(false) => {
+                table.enforce_styling = false;
+                table.no_tty = true;
+            }
+            None => {}
+        }
+
+        render(&table)
+    }
+
     /// Modify a preset with a modifier string from [modifiers](crate::style::modifiers).
     ///
     /// For instance, the [UTF8_ROUND_CORNERS](crate::style::modifiers::UTF8_ROUND_CORNERS) modifies
@@ -694,6 +2164,175 @@ impl Table {
         }
     }
 
+    /// Get an iterator over the cells of a column, without the header.
+    ///
+    /// In contrast to [Table::column_cells_iter], rows that are missing this specific cell are
+    /// skipped. This is convenient for aggregating or validating the values of a column.\
+    /// Cells are assigned to columns the same way as by [Table::cell], i.e. cells after a cell
+    /// that spans multiple columns are shifted accordingly. Cells that span multiple columns
+    /// themselves aren't included, cells that span multiple rows only once.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    /// let mut table = Table::new();
+    /// table.set_header(vec!["Name", "Size"]);
+    /// table.add_row(vec!["a", "10"]);
+    /// table.add_row(vec!["b"]);
+    /// table.add_row(vec!["c", "32"]);
+    ///
+    /// let total: u32 = table
+    ///     .column_values(1)
+    ///     .map(|cell| cell.content().parse::<u32>().unwrap())
+    ///     .sum();
+    /// assert_eq!(total, 42);
+    /// ```
+    pub fn column_values(&self, column_index: usize) -> impl Iterator<Item = &Cell> {
+        grid::body_column_cells(self, column_index).into_iter()
+    }
+
+    /// Get an iterator over all columns of the table.
+    /// Each column is returned as an iterator over its cells, as by [Table::column_values].
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["a", "b"]);
+    /// table.add_row(vec!["c"]);
+    ///
+    /// let columns: Vec<Vec<String>> = table
+    ///     .columns_values()
+    ///     .map(|column| column.map(|cell| cell.content()).collect())
+    ///     .collect();
+    /// assert_eq!(columns, vec![vec!["a", "c"], vec!["b"]]);
+    /// ```
+    pub fn columns_values(&self) -> impl Iterator<Item = impl Iterator<Item = &Cell>> {
+        (0..self.columns.len()).map(|column_index| self.column_values(column_index))
+    }
+
+    /// Get the full content of the cell, which is displayed in the given row and column.
+    ///
+    /// This is useful to display content that has been cut off in the rendered table, e.g. for
+    /// columns with [no wrapping](Column::set_no_wrap).\
+    /// See [Table::cell] for how the coordinates are interpreted.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["One", "Two"]);
+    ///
+    /// assert_eq!(table.cell_content(0, 1), Some("Two".to_string()));
+    /// assert_eq!(table.cell_content(0, 2), None);
+    /// ```
+    pub fn cell_content(&self, row_index: usize, column_index: usize) -> Option<String> {
+        self.cell(row_index, column_index).map(Cell::content)
+    }
+
+    /// Reference to the cell, which is displayed in the given row and column.
+    ///
+    /// The coordinates are the same as the ones returned by [Layout::hit_test].
+    /// A cell that [spans multiple columns](Cell::set_colspan) or [rows](Cell::set_rowspan) is
+    /// found via all positions it occupies.
+    ///
+    /// ```
+    /// use comfy_table::{Cell, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .add_row(vec![Cell::new("Wide").set_colspan(2), Cell::new("Three")])
+    ///     .add_row(vec!["One", "Two", "Three"]);
+    ///
+    /// assert_eq!(table.cell(0, 1).unwrap().content(), "Wide");
+    /// assert_eq!(table.cell(0, 2).unwrap().content(), "Three");
+    /// assert!(table.cell(2, 0).is_none());
+    /// ```
+    pub fn cell(&self, row_index: usize, column_index: usize) -> Option<&Cell> {
+        let (row_index, cell_index) = self.cell_position(row_index, column_index)?;
+        self.rows[row_index].cells.get(cell_index)
+    }
+
+    /// Mutable reference to the cell, which is displayed in the given row and column.
+    ///
+    /// See [Table::cell] for how the coordinates are interpreted.\
+    /// The cell may be changed in any way, so the positions of spanned cells have to be looked up
+    /// again afterwards. Use [Table::set_cell] to update many cells of tables with spans.
+    pub fn cell_mut(&mut self, row_index: usize, column_index: usize) -> Option<&mut Cell> {
+        let (row_index, cell_index) = self.cell_position(row_index, column_index)?;
+        self.grid_cache.reset();
+        self.rows[row_index].cells.get_mut(cell_index)
+    }
+
+    /// Replace the cell, which is displayed in the given row and column.
+    ///
+    /// If the row has fewer cells, it's filled up with empty cells and new columns are added
+    /// as needed. Nothing happens, if the row doesn't exist.\
+    /// On tables with [spanned](Cell::set_colspan) cells, only existing cells can be replaced.
+    /// Nothing happens, if no cell is displayed at the given position, as new cells would shift
+    /// the spans of the following rows. Use [Table::cell] to check for a cell beforehand.
+    ///
+    /// ```
+    /// use comfy_table::{Cell, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["CPU", "12%"]);
+    ///
+    /// table.set_cell(0, 1, "87%").set_cell(0, 3, Cell::new("hot"));
+    /// assert_eq!(table.cell_content(0, 1), Some("87%".to_string()));
+    /// assert_eq!(table.cell_content(0, 2), Some("".to_string()));
+    /// assert_eq!(table.column_iter().count(), 4);
+    /// ```
+    pub fn set_cell<T: Into<Cell>>(
+        &mut self,
+        row_index: usize,
+        column_index: usize,
+        cell: T,
+    ) -> &mut Self {
+        let cell = cell.into();
+        if let Some((row_index, cell_index)) = self.cell_position(row_index, column_index) {
+            let existing = &mut self.rows[row_index].cells[cell_index];
+            // The positions of all cells only change, if the spans of the cell change.
+            if (existing.colspan, existing.rowspan) != (cell.colspan, cell.rowspan) {
+                self.grid_cache.reset();
+            }
+            *existing = cell;
+            return self;
+        }
+        if self.grid_cache.get(self).is_some() {
+            return self;
+        }
+
+        let Some(row) = self.rows.get_mut(row_index) else {
+            return self;
+        };
+        while row.cells.len() < column_index {
+            row.cells.push(Cell::new(""));
+        }
+        row.cells.push(cell);
+        self.discover_columns();
+
+        self
+    }
+
+    /// The index of the row and the index of the cell in that row, which is displayed at the
+    /// given position.
+    ///
+    /// The positions of spanned cells are only computed once, until the table is changed.
+    fn cell_position(&self, row_index: usize, column_index: usize) -> Option<(usize, usize)> {
+        let row = self.rows.get(row_index)?;
+        let Some(grid) = self.grid_cache.get(self) else {
+            return (column_index < row.cells.len()).then_some((row_index, column_index));
+        };
+
+        // The header is the first row of the grid.
+        let header_rows = usize::from(self.header.is_some());
+        let slot = grid
+            .get(row_index + header_rows)?
+            .iter()
+            .find(|slot| slot.columns.contains(&column_index))?;
+
+        Some((slot.row - header_rows, slot.cell))
+    }
+
     /// Reference to a specific row
     pub fn row(&self, index: usize) -> Option<&Row> {
         self.rows.get(index)
@@ -701,6 +2340,7 @@ impl Table {
 
     /// Mutable reference to a specific row
     pub fn row_mut(&mut self, index: usize) -> Option<&mut Row> {
+        self.grid_cache.reset();
         self.rows.get_mut(index)
     }
 
@@ -723,16 +2363,218 @@ impl Table {
     /// assert!(table.row_iter_mut().len() == 1);
     /// ```
     pub fn row_iter_mut(&mut self) -> IterMut<'_, Row> {
+        self.grid_cache.reset();
         self.rows.iter_mut()
     }
 
+    /// Sort the rows by the content of the given column.
+    ///
+    /// The content is compared as plain text. Use [Table::sort_rows_numeric], if the column
+    /// contains numbers. Rows without a cell in this column are treated as empty.\
+    /// The column is resolved like in [Table::cell], so cells that span multiple columns are
+    /// compared in each of these columns.
+    /// The sort is stable, rows with equal content keep their order.
+    ///
+    /// ```
+    /// use comfy_table::{SortOrder, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .add_row(vec!["b", "2"])
+    ///     .add_row(vec!["c", "1"])
+    ///     .add_row(vec!["a", "3"]);
+    ///
+    /// table.sort_rows(0, SortOrder::Descending);
+    /// assert_eq!(table.cell_content(0, 0), Some("c".to_string()));
+    /// ```
+    pub fn sort_rows(&mut self, column_index: usize, order: SortOrder) -> &mut Self {
+        let texts = self.sort_texts(column_index);
+        self.sort_rows_by(|left, right| {
+            order.apply(sort_text(&texts, left).cmp(sort_text(&texts, right)))
+        })
+    }
+
+    /// Same as [Table::sort_rows], but sequences of digits are compared by their numeric value.
+    ///
+    /// That way, `"10"` is sorted after `"9"` and `"file10"` after `"file2"`.
+    ///
+    /// ```
+    /// use comfy_table::{SortOrder, Table};
+    ///
+    /// let mut table = Table::new();
+    /// table
+    ///     .add_row(vec!["10 MB"])
+    ///     .add_row(vec!["9 MB"]);
+    ///
+    /// table.sort_rows_numeric(0, SortOrder::Ascending);
+    /// assert_eq!(table.cell_content(0, 0), Some("9 MB".to_string()));
+    /// ```
+    pub fn sort_rows_numeric(&mut self, column_index: usize, order: SortOrder) -> &mut Self {
+        let texts = self.sort_texts(column_index);
+        self.sort_rows_by(|left, right| {
+            order.apply(natural_cmp(
+                sort_text(&texts, left),
+                sort_text(&texts, right),
+            ))
+        })
+    }
+
+    /// Sort the rows with a custom comparison function.
+    ///
+    /// The header isn't affected. The sort is stable, equal rows keep their order.\
+    /// Rows are only sorted within their [row group](Table::add_row_group). [Raw
+    /// lines](Table::add_raw_line) stay in place and the rows above and below them are sorted
+    /// separately.\
+    /// Tables with cells that [span multiple rows](Cell::set_rowspan) aren't sorted at all, as
+    /// these cells can't be separated from the rows they span.
+    ///
+    /// ```
+    /// use comfy_table::Table;
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["one", "two"]).add_row(vec!["three"]);
+    ///
+    /// // Sort rows by their amount of cells.
+    /// table.sort_rows_by(|left, right| left.cell_count().cmp(&right.cell_count()));
+    /// assert_eq!(table.cell_content(0, 0), Some("three".to_string()));
+    /// ```
+    pub fn sort_rows_by<F>(&mut self, mut compare: F) -> &mut Self
+    where
+        F: FnMut(&Row, &Row) -> Ordering,
+    {
+        let has_rowspans = self
+            .rows
+            .iter()
+            .flat_map(|row| row.cells.iter())
+            .any(|cell| cell.rowspan > 1);
+        if has_rowspans {
+            return self;
+        }
+
+        let mut boundaries: Vec<usize> = self
+            .row_groups
+            .iter()
+            .map(|(start, _)| *start)
+            .chain(self.raw_lines.iter().map(|(position, _)| *position))
+            .chain([self.rows.len()])
+            .map(|position| position.min(self.rows.len()))
+            .collect();
+        boundaries.sort_unstable();
+        boundaries.dedup();
+
+        let mut start = 0;
+        for end in boundaries {
+            self.rows[start..end].sort_by(&mut compare);
+            start = end;
+        }
+        self.grid_cache.reset();
+        for (index, row) in self.rows.iter_mut().enumerate() {
+            row.index = Some(index);
+        }
+
+        self
+    }
+
+    /// Return a copy of this table, to which the given [TableViewState] has been applied.
+    ///
+    /// The rows are sorted by the state's sort keys, its hidden columns are hidden and the rows
+    /// before its scroll offset are skipped. This table isn't changed, so the state can be
+    /// re-applied after its data has been refreshed.
+    ///
+    /// ```
+    /// use comfy_table::{Table, TableViewState};
+    ///
+    /// let mut table = Table::new();
+    /// table.add_row(vec!["one", "two"]);
+    ///
+    /// let mut state = TableViewState::new();
+    /// state.set_column_visible(0, false);
+    ///
+    /// let expected = "
+    /// +-----+
+    /// | two |
+    /// +-----+";
+    /// let view = table.with_view_state(&state);
+    /// assert_eq!(expected, "\n".to_string() + &view.to_string());
+    /// ```
+    pub fn with_view_state(&self, state: &TableViewState) -> Table {
+        let mut table = self.clone();
+        if !state.sort_keys().is_empty() {
+            let texts: Vec<Vec<String>> = state
+                .sort_keys()
+                .iter()
+                .map(|(column_index, _)| self.sort_texts(*column_index))
+                .collect();
+            table.sort_rows_by(|left, right| {
+                state.sort_keys().iter().zip(texts.iter()).fold(
+                    Ordering::Equal,
+                    |ordering, ((_, order), texts)| {
+                        ordering.then_with(|| {
+                            order
+                                .apply(natural_cmp(sort_text(texts, left), sort_text(texts, right)))
+                        })
+                    },
+                )
+            });
+        }
+
+        for index in state.hidden_columns() {
+            if let Some(column) = table.column_mut(*index) {
+                column.set_constraint(ColumnConstraint::Hidden);
+            }
+        }
+
+        #[cfg(feature = "tty")]
+        if let Some(row) = state
+            .selected_row()
+            .and_then(|index| table.rows.get_mut(index))
+        {
+            // Use the selection colors of the theme, if there are any.
+            let (fg, bg) = self
+                .theme
+                .as_ref()
+                .map(|theme| (theme.selection_fg(), theme.selection_bg()))
+                .unwrap_or_default();
+            for cell in row.cells.iter_mut() {
+                if fg.is_none() && bg.is_none() {
+                    cell.attributes.push(Attribute::Reverse);
+                }
+                cell.fg = fg.or(cell.fg);
+                cell.bg = bg.or(cell.bg);
+            }
+        }
+
+        // Skip the scrolled rows and move everything, that's positioned between rows, along.
+        let offset = state.scroll_offset().min(table.rows.len());
+        if offset > 0 {
+            table.rows.drain(..offset);
+            for (index, row) in table.rows.iter_mut().enumerate() {
+                row.index = Some(index);
+            }
+            table.raw_lines.retain(|(position, _)| *position >= offset);
+            for (position, _) in table.raw_lines.iter_mut() {
+                *position -= offset;
+            }
+            table.row_groups.retain(|(position, _)| *position >= offset);
+            for (position, _) in table.row_groups.iter_mut() {
+                *position -= offset;
+            }
+        }
+
+        table
+    }
+
     /// Return a vector representing the maximum amount of characters in any line of this column.\
     ///
     /// **Attention** This scans the whole current content of the table.
     pub fn column_max_content_widths(&self) -> Vec<u16> {
-        fn set_max_content_widths(max_widths: &mut [u16], row: &Row) {
+        if let Some(widths) = &self.measured_widths {
+            return widths.clone();
+        }
+
+        fn set_max_content_widths(max_widths: &mut [u16], row: &Row, table: &Table) {
             // Get the max width for each cell of the row
-            let row_max_widths = row.max_content_widths();
+            let row_max_widths = row.max_content_widths(&table.width_table, &table.columns);
             for (index, width) in row_max_widths.iter().enumerate() {
                 let mut width = (*width).try_into().unwrap_or(u16::MAX);
                 // A column's content is at least 1 char wide.
@@ -748,37 +2590,219 @@ impl Table {
         // The vector that'll contain the max widths per column.
         let mut max_widths = vec![0; self.columns.len()];
 
-        if let Some(header) = &self.header {
-            set_max_content_widths(&mut max_widths, header);
+        if grid::has_spans(self) {
+            self.set_spanned_max_content_widths(&mut max_widths);
+        } else {
+            if let Some(header) = &self.header {
+                set_max_content_widths(&mut max_widths, header, self);
+            }
+            // Iterate through all rows of the table.
+            for row in self.rows.iter() {
+                set_max_content_widths(&mut max_widths, row, self);
+            }
+            if let Some(footer) = &self.footer {
+                set_max_content_widths(&mut max_widths, footer, self);
+            }
         }
-        // Iterate through all rows of the table.
-        for row in self.rows.iter() {
-            set_max_content_widths(&mut max_widths, row);
+
+        // The labels of header groups span their columns just like any other cell.
+        for (cell, columns) in grid::header_groups(self) {
+            let padding = (
+                self.columns[columns.start].padding.0,
+                self.columns[columns.end - 1].padding.1,
+            );
+            let width: u16 = cell
+                .max_content_width_with_padding(&self.width_table, padding)
+                .try_into()
+                .unwrap_or(u16::MAX);
+            self.widen_spanned_columns(&mut max_widths, columns, width);
+        }
+
+        // Lined up anchors may need more space than the widest line.
+        for (max_width, anchor) in max_widths.iter_mut().zip(self.anchor_widths()) {
+            if let Some((before, after)) = anchor {
+                let width = (before + after).try_into().unwrap_or(u16::MAX);
+                *max_width = (*max_width).max(width);
+            }
         }
 
         max_widths
     }
 
+    /// Determine the widths of the content before and after the anchor in all columns, which
+    /// contain [anchored](CellAlignment::Anchor) cells.
+    ///
+    /// Only cells, which span a single column, are lined up.
+    /// Lines without the anchor don't influence the position of the anchor.
+    pub(crate) fn anchor_widths(&self) -> Vec<Option<(usize, usize)>> {
+        let mut anchors = vec![None; self.columns.len()];
+        let rows = grid::all_rows(self);
+        for (row_index, slots) in grid::place_cells(self).into_iter().enumerate() {
+            for slot in slots.iter() {
+                if slot.row != row_index || slot.columns.len() != 1 {
+                    continue;
+                }
+                let column = slot.columns.start;
+                let cell = &rows[slot.row].cells[slot.cell];
+                let alignment = cell.alignment.or(self.columns[column].cell_alignment);
+                let Some(CellAlignment::Anchor(anchor)) = alignment else {
+                    continue;
+                };
+
+                for line in cell.content.iter() {
+                    let Some(position) = line.find(anchor) else {
+                        continue;
+                    };
+                    let before = measure_text_width(&line[..position], &self.width_table);
+                    let after = measure_text_width(&line[position..], &self.width_table);
+                    let (max_before, max_after) = anchors[column].get_or_insert((0, 0));
+                    *max_before = before.max(*max_before);
+                    *max_after = after.max(*max_after);
+                }
+            }
+        }
+
+        anchors
+    }
+
+    /// Determine the max content widths of all columns, if cells span multiple columns or rows.
+    ///
+    /// Cells that span a single column are handled as usual.
+    /// If a cell, which spans multiple columns, doesn't fit into these columns, the spanned
+    /// columns are widened accordingly.
+    fn set_spanned_max_content_widths(&self, max_widths: &mut [u16]) {
+        let rows = grid::all_rows(self);
+        let slots: Vec<grid::Slot> = grid::place_cells(self)
+            .into_iter()
+            .enumerate()
+            .flat_map(|(row_index, slots)| {
+                slots.into_iter().filter(move |slot| slot.row == row_index)
+            })
+            .collect();
+        let width = |slot: &grid::Slot| {
+            let cell = &rows[slot.row].cells[slot.cell];
+            let padding = (
+                self.columns[slot.columns.start].padding.0,
+                self.columns[slot.columns.end - 1].padding.1,
+            );
+            let width: u16 = cell
+                .max_content_width_with_padding(&self.width_table, padding)
+                .try_into()
+                .unwrap_or(u16::MAX);
+            width.max(1)
+        };
+
+        for slot in slots.iter().filter(|slot| slot.columns.len() == 1) {
+            let index = slot.columns.start;
+            max_widths[index] = max_widths[index].max(width(slot));
+        }
+
+        for slot in slots.iter().filter(|slot| slot.columns.len() > 1) {
+            self.widen_spanned_columns(max_widths, slot.columns.clone(), width(slot));
+        }
+    }
+
+    /// Widen the given columns, if a cell with the given width that spans these columns doesn't
+    /// fit into them.
+    fn widen_spanned_columns(&self, max_widths: &mut [u16], columns: Range<usize>, width: u16) {
+        // The space between the spanned columns can be used by the cell as well.
+        let vertical_line = u16::from(should_draw_vertical_lines(self));
+        let mut available: u16 = 0;
+        for (position, index) in columns.clone().enumerate() {
+            available = available.saturating_add(max_widths[index]);
+            if position > 0 {
+                available = available
+                    .saturating_add(self.columns[index - 1].padding.1)
+                    .saturating_add(self.columns[index].padding.0)
+                    .saturating_add(vertical_line);
+            }
+        }
+
+        // Distribute the missing space equally, any excess goes to the leftmost columns.
+        let missing = width.saturating_sub(available);
+        let count = columns.len() as u16;
+        let mut excess = missing % count;
+        for index in columns {
+            let mut extra = missing / count;
+            if excess > 0 {
+                extra += 1;
+                excess -= 1;
+            }
+            max_widths[index] = max_widths[index].saturating_add(extra);
+        }
+    }
+
     pub(crate) fn style_or_default(&self, component: TableComponent) -> String {
-        match self.style.get(&component) {
+        match self.drawn_style(component) {
             None => " ".to_string(),
             Some(character) => character.to_string(),
         }
     }
 
+    /// All characters, which are used to draw the borders and lines of the table.
+    pub(crate) fn border_characters(&self) -> Vec<char> {
+        let mut characters: Vec<char> = self
+            .style
+            .values()
+            .copied()
+            .filter(|character| *character != ' ')
+            .collect();
+        characters.sort_unstable();
+        characters.dedup();
+
+        characters
+    }
+
     pub(crate) fn style_exists(&self, component: TableComponent) -> bool {
-        self.style.contains_key(&component)
+        self.drawn_style(component).is_some()
+    }
+
+    /// The character that's used to draw the box of a [boxed](Cell::set_boxed) cell.
+    /// Components, which aren't drawn, fall back to their ASCII equivalent.
+    pub(crate) fn box_style(&self, component: TableComponent) -> char {
+        self.drawn_style(component)
+            .copied()
+            .unwrap_or_else(|| component.ascii_fallback())
+    }
+
+    /// The character that's drawn for a component.
+    /// Footer components fall back to their header equivalent, if they aren't set.
+    fn drawn_style(&self, component: TableComponent) -> Option<&char> {
+        self.style.get(&component).or_else(|| {
+            component
+                .header_equivalent()
+                .and_then(|component| self.style.get(&component))
+        })
     }
 
     /// Autogenerate new columns, if a row is added with more cells than existing columns.
     fn autogenerate_columns(&mut self, row: &Row) {
-        if row.cell_count() > self.columns.len() {
-            for index in self.columns.len()..row.cell_count() {
-                self.columns.push(Column::new(index));
+        if row.spanned_columns() > self.columns.len() {
+            for index in self.columns.len()..row.spanned_columns() {
+                self.columns.push(self.new_column(index));
             }
         }
     }
 
+    /// Create a new column with the table's default settings.
+    fn new_column(&self, index: usize) -> Column {
+        let mut column = Column::new(index);
+        column.set_padding(self.default_padding);
+
+        column
+    }
+
+    /// The text of each row's cell in the given column, which is used for sorting.
+    /// Rows without a cell in this column are treated as empty.
+    fn sort_texts(&self, column_index: usize) -> Vec<String> {
+        (0..self.rows.len())
+            .map(|row_index| {
+                self.cell_content(row_index, column_index)
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+
     /// Calling this might be necessary if you add new cells to rows that're already added to the
     /// table.
     ///
@@ -789,16 +2813,23 @@ impl Table {
     /// To make sure everything works as expected, just call this function if you're adding cells
     /// to rows that're already added to the table.
     pub fn discover_columns(&mut self) {
+        self.grid_cache.reset();
         for row in self.rows.iter() {
-            if row.cell_count() > self.columns.len() {
-                for index in self.columns.len()..row.cell_count() {
-                    self.columns.push(Column::new(index));
+            if row.spanned_columns() > self.columns.len() {
+                for index in self.columns.len()..row.spanned_columns() {
+                    self.columns.push(self.new_column(index));
                 }
             }
         }
     }
 }
 
+/// The text of a row, which has been collected via [Table::sort_texts].
+fn sort_text<'a>(texts: &'a [String], row: &Row) -> &'a str {
+    let index = row.index.expect("Rows of a table always have an index");
+    &texts[index]
+}
+
 /// An iterator over cells of a specific column.
 /// A dedicated struct is necessary, as data is usually handled by rows and thereby stored in
 /// `Table::rows`. This type is returned by [Table::column_cells_iter].
@@ -40,7 +40,7 @@ pub fn evaluate(
         _ => {}
     }
 
-    if let Some(min_width) = min(table, &column.constraint, visible_columns) {
+    if let Some(min_width) = min(table, column, visible_columns) {
         // In case a min_width is specified, we may already fix the size of the column.
         // We do this, if we know that the content is smaller than the min size.
         let max_width = max_content_width + column.padding_width();
@@ -58,13 +58,12 @@ pub fn evaluate(
 /// This returns the value of absolute characters that are allowed to be in this column. \
 /// Lower boundaries with [Width::Fixed] just return their internal value. \
 /// Lower boundaries with [Width::Percentage] return the percental amount of the current table
-/// width.
-pub fn min(
-    table: &Table,
-    constraint: &Option<ColumnConstraint>,
-    visible_columns: usize,
-) -> Option<u16> {
-    let constraint = if let Some(constraint) = constraint {
+/// width. \
+/// [AtLeastHeaderWidth] returns the width of the column's header cell including its padding. \
+/// [MinReadable] returns its characters, the truncation indicator and the padding, but never more
+/// than the width of the column's content and its padding.
+pub fn min(table: &Table, column: &Column, visible_columns: usize) -> Option<u16> {
+    let constraint = if let Some(constraint) = &column.constraint {
         constraint
     } else {
         return None;
@@ -74,6 +73,17 @@ pub fn min(
         LowerBoundary(width) | Boundaries { lower: width, .. } => {
             absolute_value_from_width(table, width, visible_columns)
         }
+        AtLeastHeaderWidth => {
+            header_width(table, column).map(|width| width.saturating_add(column.padding_width()))
+        }
+        MinReadable(characters) => {
+            // Content that's narrower than that is displayed in full and doesn't need the space.
+            let content_width = table.column_max_content_widths()[column.index];
+            let width = characters
+                .saturating_add(indicator_width(table, column))
+                .min(content_width);
+            Some(width.saturating_add(column.padding_width()))
+        }
         _ => None,
     }
 }
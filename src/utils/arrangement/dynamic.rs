@@ -1,7 +1,9 @@
-use unicode_width::UnicodeWidthStr;
-
 use super::{ColumnDisplayInfo, DisplayInfos, constraint, helper::*};
-use crate::{Column, Table, style::*, utils::formatting::content_split::split_line};
+use crate::{
+    Column, Table,
+    style::*,
+    utils::{formatting::content_split::split_line, grid},
+};
 
 /// Try to find the best fit for a given content and table_width
 ///
@@ -132,7 +134,7 @@ pub fn arrange(
 ///
 /// This value is converted to a i32 to handle negative values in case we work with a very small
 /// terminal.
-fn available_content_width(
+pub(super) fn available_content_width(
     table: &Table,
     infos: &DisplayInfos,
     visible_columns: usize,
@@ -319,7 +321,20 @@ fn enforce_lower_boundary_constraints(
             }
 
             // Check whether the column has a LowerBoundary constraint.
-            let Some(min_width) = constraint::min(table, &column.constraint, visible_columns)
+            // Cells that must not be truncated and words that must not be split act like an
+            // additional lower boundary.
+            // That boundary may not exceed the space that's left, while leaving at least one
+            // character for each other remaining column.
+            let never_truncate = never_truncate_width(table, column)
+                .max(no_break_width(table, column))
+                .map(|width| {
+                    let available = remaining_width.saturating_sub(remaining_columns - 1);
+                    width
+                        .min(available.try_into().unwrap_or(u16::MAX))
+                        .saturating_add(column.padding_width())
+                });
+            let Some(min_width) =
+                constraint::min(table, column, visible_columns).max(never_truncate)
             else {
                 continue;
             };
@@ -446,35 +461,40 @@ fn longest_line_after_split(average_space: usize, column: &Column, table: &Table
     // Runtime variable that holds the longest found line length.
     let mut longest = 0;
 
-    for cell in table.column_cells_with_header_iter(column.index) {
-        // Only look at rows that actually contain this cell.
-        let Some(cell) = cell else { continue };
-
+    for cell in grid::column_cells(table, column.index) {
+        // Cells, which are ignored when measuring the column, don't need any space.
+        if cell.ignore_width {
+            continue;
+        }
         let delimiter = delimiter(table, column, cell);
 
+        // The box of a boxed cell takes up some of the space.
+        let box_width = if cell.boxed { 4 } else { 0 };
+        let space = average_space.saturating_sub(box_width);
+
         // Create a temporary ColumnDisplayInfo with the average space as width.
         // That way we can simulate how the split text will look like.
-        let info = ColumnDisplayInfo::new(column, average_space.try_into().unwrap_or(u16::MAX));
+        let info = ColumnDisplayInfo::new(column, space.try_into().unwrap_or(u16::MAX));
 
         // Iterate over each line and split it into multiple lines, if necessary.
         // Newlines added by the user will be preserved.
         for line in cell.content.iter() {
-            if line.width() > average_space {
-                let parts = split_line(line, &info, delimiter);
+            if table.width_table.str_width(line) > space {
+                let parts = split_line(line, &info, delimiter, &table.width_table);
 
                 #[cfg(feature = "_debug")]
                 println!(
                     "dynamic::longest_line_after_split: Splitting line with width {}. Original:\n    {}\nSplitted:\n    {:?}",
-                    line.width(),
+                    table.width_table.str_width(line),
                     line,
                     parts
                 );
 
                 parts
                     .iter()
-                    .for_each(|part| longest = longest.max(part.len()));
+                    .for_each(|part| longest = longest.max(part.len() + box_width));
             } else {
-                longest = longest.max(line.len())
+                longest = longest.max(line.len() + box_width)
             }
         }
     }
@@ -525,7 +545,7 @@ fn use_full_width(infos: &mut DisplayInfos, remaining_width: usize) {
 /// given width.
 ///
 /// This function now equally distributes the remaining width between the remaining columns.
-fn distribute_remaining_space(
+pub(super) fn distribute_remaining_space(
     columns: &[Column],
     infos: &mut DisplayInfos,
     remaining_width: usize,
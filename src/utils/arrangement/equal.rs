@@ -0,0 +1,23 @@
+use super::{
+    DisplayInfos,
+    dynamic::{available_content_width, distribute_remaining_space},
+    helper::*,
+};
+use crate::Table;
+
+/// Divide the available width equally between all columns, regardless of their content.
+///
+/// Columns, whose width has already been determined by constraints, keep their width.
+pub fn arrange(table: &Table, infos: &mut DisplayInfos, table_width: usize) {
+    let visible_columns = count_visible_columns(&table.columns);
+    let remaining_width = available_content_width(table, infos, visible_columns, table_width);
+    let remaining_columns = count_remaining_columns(visible_columns, infos);
+
+    if remaining_columns == 0 {
+        return;
+    }
+
+    // Each column gets at least one space, even if there's not enough space left.
+    let remaining_width = remaining_width.max(remaining_columns);
+    distribute_remaining_space(&table.columns, infos, remaining_width, remaining_columns);
+}
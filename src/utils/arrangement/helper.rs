@@ -1,8 +1,17 @@
 use super::DisplayInfos;
 use crate::{
     Cell, Column, Table,
-    utils::formatting::borders::{
-        should_draw_left_border, should_draw_right_border, should_draw_vertical_lines,
+    style::{TruncationStyle, WrapPolicy},
+    utils::{
+        ColumnDisplayInfo,
+        formatting::{
+            borders::{
+                should_draw_left_border, should_draw_right_border, should_draw_vertical_lines,
+            },
+            content_format::NO_WRAP_INDICATOR,
+            content_split::measure_text_width,
+        },
+        grid,
     },
 };
 
@@ -23,6 +32,55 @@ pub fn absolute_width_with_padding(column: &Column, width: u16) -> u16 {
     content_width
 }
 
+/// Return the content width of the header cell in the given column.
+///
+/// `None`, if there's no header or the header cell spans multiple columns.
+pub fn header_width(table: &Table, column: &Column) -> Option<u16> {
+    let header = table.header.as_ref()?;
+    let mut start = 0;
+    for cell in header.cells.iter() {
+        if start == column.index && cell.colspan == 1 {
+            let width = cell.max_content_width(&table.width_table);
+            return Some(width.try_into().unwrap_or(u16::MAX));
+        }
+        start += cell.colspan;
+        if start > column.index {
+            break;
+        }
+    }
+
+    None
+}
+
+/// Return the width of the indicator, which is displayed in place of truncated content of this
+/// column.
+pub fn indicator_width(table: &Table, column: &Column) -> u16 {
+    let indicator = match &column.truncation {
+        Some(
+            TruncationStyle::End(indicator)
+            | TruncationStyle::Start(indicator)
+            | TruncationStyle::Middle(indicator),
+        ) => indicator,
+        None if column.no_wrap => NO_WRAP_INDICATOR,
+        None => &table.truncation_indicator,
+    };
+
+    measure_text_width(indicator, &table.width_table)
+        .try_into()
+        .unwrap_or(u16::MAX)
+}
+
+/// Return the width of the table with the given arrangement.
+pub fn table_width(table: &Table, infos: &[ColumnDisplayInfo]) -> usize {
+    let visible_infos = infos.iter().filter(|info| !info.is_hidden);
+    let columns: usize = visible_infos
+        .clone()
+        .map(|info| usize::from(info.width()))
+        .sum();
+
+    count_border_columns(table, visible_infos.count()) + columns
+}
+
 /// Return the amount of visible columns
 pub fn count_visible_columns(columns: &[Column]) -> usize {
     columns.iter().filter(|column| !column.is_hidden()).count()
@@ -37,6 +95,23 @@ pub fn count_remaining_columns(column_count: usize, infos: &DisplayInfos) -> usi
     column_count - infos.iter().filter(|(_, info)| !info.is_hidden).count()
 }
 
+/// Return the smallest width the table can be displayed in.
+///
+/// That's the width of all borders, the padding of all visible columns and a single character
+/// of content per visible column.
+pub fn minimum_width(table: &Table, infos: &[ColumnDisplayInfo]) -> usize {
+    let visible_infos = infos.iter().filter(|info| !info.is_hidden);
+    if visible_infos.clone().next().is_none() {
+        return 0;
+    }
+    let columns: usize = visible_infos
+        .clone()
+        .map(|info| 1 + usize::from(info.padding.0) + usize::from(info.padding.1))
+        .sum();
+
+    count_border_columns(table, visible_infos.count()) + columns
+}
+
 /// Return the amount of border columns, that will be visible in the final table output.
 pub fn count_border_columns(table: &Table, visible_columns: usize) -> usize {
     let mut lines = 0;
@@ -68,3 +143,38 @@ pub fn delimiter(table: &Table, column: &Column, cell: &Cell) -> char {
         ' '
     }
 }
+
+/// Get the content width that's needed to fully display all cells of a column, which are marked
+/// via [Cell::set_never_truncate].
+pub fn never_truncate_width(table: &Table, column: &Column) -> Option<u16> {
+    grid::column_cells(table, column.index)
+        .into_iter()
+        .filter(|cell| cell.never_truncate)
+        .map(|cell| {
+            cell.max_content_width(&table.width_table)
+                .try_into()
+                .unwrap_or(u16::MAX)
+        })
+        .max()
+}
+
+/// Get the content width of the longest word of a column, whose words must not be split via
+/// [WrapPolicy::NoBreak].
+pub fn no_break_width(table: &Table, column: &Column) -> Option<u16> {
+    if column.wrap_policy != WrapPolicy::NoBreak {
+        return None;
+    }
+
+    grid::column_cells(table, column.index)
+        .into_iter()
+        .filter(|cell| !cell.ignore_width)
+        .flat_map(|cell| {
+            let delimiter = delimiter(table, column, cell);
+            cell.content
+                .iter()
+                .flat_map(move |line| line.split(delimiter))
+                .map(|word| measure_text_width(word, &table.width_table))
+        })
+        .max()
+        .map(|width| width.try_into().unwrap_or(u16::MAX))
+}
@@ -1,18 +1,139 @@
 use std::collections::BTreeMap;
 
-use super::ColumnDisplayInfo;
-use crate::{style::ContentArrangement, table::Table};
+use super::{ColumnDisplayInfo, formatting::content_format::format_content};
+use crate::{
+    style::{ColumnConstraint, ContentArrangement},
+    table::Table,
+};
 
 pub mod constraint;
 mod disabled;
 mod dynamic;
+mod equal;
 pub mod helper;
 
 type DisplayInfos = BTreeMap<usize, ColumnDisplayInfo>;
 
+/// Columns, whose content is squeezed below this width, are considered unreadable.
+const MIN_READABLE_WIDTH: u16 = 3;
+
 /// Determine the width of each column depending on the content of the given table.
 /// The results uses Option<usize>, since users can choose to hide columns.
+///
+/// Columns with a [MinReadable](ColumnConstraint::MinReadable) constraint are hidden from right
+/// to left, as long as a dynamic arrangement doesn't fit into the table's width.\
+/// Columns with a [hide priority](crate::Column::set_hide_priority) are hidden one after another,
+/// as long as a dynamic arrangement squeezes any column to an unreadable width.\
+/// If the table is still narrower than its [minimum width](helper::minimum_width), it's
+/// displayed with that minimum width.
 pub fn arrange_content(table: &Table) -> Vec<ColumnDisplayInfo> {
+    let mut infos = arrange_visible(table);
+
+    // Line up the anchors of anchored cells.
+    for (info, anchor) in infos.iter_mut().zip(table.anchor_widths()) {
+        info.anchor_offset = anchor.map(|(before, _)| before);
+    }
+
+    infos
+}
+
+/// Arrange the content and hide further columns, as long as the arrangement doesn't fit.
+fn arrange_visible(table: &Table) -> Vec<ColumnDisplayInfo> {
+    let mut infos = arrange(table);
+    if !is_overflowing(table, &infos) && !is_squeezed(table, &infos) {
+        return infos;
+    }
+
+    let mut table = table.clone();
+    loop {
+        let column = if is_overflowing(&table, &infos) {
+            table
+                .columns
+                .iter_mut()
+                .filter(|column| !column.is_hidden())
+                .rfind(|column| matches!(column.constraint, Some(ColumnConstraint::MinReadable(_))))
+        } else if is_squeezed(&table, &infos) {
+            table
+                .columns
+                .iter_mut()
+                .filter(|column| !column.is_hidden())
+                .filter(|column| column.hide_priority.is_some())
+                .min_by_key(|column| column.hide_priority)
+        } else {
+            None
+        };
+        let Some(column) = column else {
+            break;
+        };
+
+        column.constraint = Some(ColumnConstraint::Hidden);
+        infos = arrange(&table);
+    }
+
+    infos
+}
+
+/// Check whether a dynamic arrangement is wider than the table,
+/// while there are still [MinReadable](ColumnConstraint::MinReadable) columns that may be hidden.
+fn is_overflowing(table: &Table, infos: &[ColumnDisplayInfo]) -> bool {
+    if !is_dynamic(table) || table.equal_column_widths {
+        return false;
+    }
+
+    let can_hide = table.columns.iter().any(|column| {
+        !column.is_hidden() && matches!(column.constraint, Some(ColumnConstraint::MinReadable(_)))
+    });
+    let table_width = table.width().map(usize::from).unwrap_or_default();
+
+    can_hide && helper::table_width(table, infos) > table_width
+}
+
+/// Check whether the table has a width and an arrangement, which adjusts the columns to it.
+pub fn is_dynamic(table: &Table) -> bool {
+    let is_dynamic = matches!(
+        table.arrangement,
+        ContentArrangement::Dynamic
+            | ContentArrangement::DynamicFullWidth
+            | ContentArrangement::Auto { .. }
+    );
+
+    is_dynamic && table.width().is_some()
+}
+
+/// Check whether a dynamic arrangement squeezed a column with content to an unreadable width
+/// or the table doesn't even fit into its width with a single character per column,
+/// while there are still columns that may be hidden.
+fn is_squeezed(table: &Table, infos: &[ColumnDisplayInfo]) -> bool {
+    if !is_dynamic(table) || table.equal_column_widths {
+        return false;
+    }
+
+    let can_hide = table
+        .columns
+        .iter()
+        .any(|column| !column.is_hidden() && column.hide_priority.is_some());
+    if !can_hide {
+        return false;
+    }
+
+    let table_width = table.width().map(usize::from).unwrap_or_default();
+    if helper::minimum_width(table, infos) > table_width {
+        return true;
+    }
+
+    let max_content_widths = table.column_max_content_widths();
+    infos
+        .iter()
+        .zip(max_content_widths)
+        .any(|(info, max_width)| {
+            !info.is_hidden
+                && info.content_width < MIN_READABLE_WIDTH
+                && info.content_width < max_width
+        })
+}
+
+/// Determine the width of each column without hiding any further columns.
+fn arrange(table: &Table) -> Vec<ColumnDisplayInfo> {
     let table_width = table.width().map(usize::from);
     let mut infos = BTreeMap::new();
 
@@ -44,18 +165,53 @@ pub fn arrange_content(table: &Table) -> Vec<ColumnDisplayInfo> {
         return infos.into_values().collect();
     };
 
+    if table.equal_column_widths {
+        equal::arrange(table, &mut infos, table_width);
+        return infos.into_values().collect();
+    }
+
     match &table.arrangement {
         ContentArrangement::Disabled => {
             disabled::arrange(table, &mut infos, visible_columns, &max_content_widths)
         }
         ContentArrangement::Dynamic | ContentArrangement::DynamicFullWidth => {
+            let max_content_widths = with_expected_widths(table, max_content_widths);
             dynamic::arrange(table, &mut infos, table_width, &max_content_widths);
         }
+        ContentArrangement::Auto { max_height } => {
+            let max_content_widths = with_expected_widths(table, max_content_widths);
+            dynamic::arrange(table, &mut infos, table_width, &max_content_widths);
+            // Wrapping needs too much space, cut off the content of all cells instead.
+            if content_height(table, &infos) > *max_height {
+                for info in infos.values_mut() {
+                    info.no_wrap = true;
+                }
+            }
+        }
     }
 
     infos.into_values().collect()
 }
 
+/// Widen the max content widths of columns to their
+/// [expected width](crate::Column::set_expected_width), if their content is narrower.
+fn with_expected_widths(table: &Table, mut max_content_widths: Vec<u16>) -> Vec<u16> {
+    for column in table.columns.iter() {
+        if let Some(expected_width) = column.expected_width {
+            let max_width = &mut max_content_widths[column.index];
+            *max_width = (*max_width).max(expected_width);
+        }
+    }
+
+    max_content_widths
+}
+
+/// The amount of lines the content of the header and all rows needs with the given arrangement.
+fn content_height(table: &Table, infos: &DisplayInfos) -> usize {
+    let infos: Vec<ColumnDisplayInfo> = infos.values().cloned().collect();
+    format_content(table, &infos).iter().map(Vec::len).sum()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
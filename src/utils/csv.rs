@@ -0,0 +1,192 @@
+use std::io::{self, Write};
+
+use crate::{
+    Cell, Column, Table,
+    utils::grid::{all_rows, header_groups, place_cells},
+};
+
+/// Parse delimiter-separated values into records of fields.
+///
+/// Fields may be enclosed in double quotes, which allows them to contain delimiters and
+/// newlines. Double quotes inside of quoted fields are escaped by another double quote.\
+/// Records are separated by `\n` or `\r\n`. Empty lines are skipped.
+pub fn read_records(input: &str, delimiter: char) -> io::Result<Vec<Vec<String>>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    // Whether the current field is quoted.
+    // This distinguishes a record with a single empty field from an empty line.
+    let mut quoted = false;
+
+    let mut chars = input.chars().peekable();
+    while let Some(character) = chars.next() {
+        match character {
+            '"' if field.is_empty() && !quoted => {
+                quoted = true;
+                loop {
+                    match chars.next() {
+                        Some('"') if chars.peek() == Some(&'"') => {
+                            chars.next();
+                            field.push('"');
+                        }
+                        Some('"') => break,
+                        Some(character) => field.push(character),
+                        None => {
+                            return Err(io::Error::new(
+                                io::ErrorKind::InvalidData,
+                                format!("Unterminated quoted field in record {}", records.len()),
+                            ));
+                        }
+                    }
+                }
+            }
+            character if character == delimiter => {
+                record.push(std::mem::take(&mut field));
+                quoted = false;
+            }
+            '\r' if chars.peek() == Some(&'\n') => (),
+            '\n' => {
+                if !record.is_empty() || !field.is_empty() || quoted {
+                    record.push(std::mem::take(&mut field));
+                    records.push(std::mem::take(&mut record));
+                }
+                quoted = false;
+            }
+            character => field.push(character),
+        }
+    }
+
+    if !record.is_empty() || !field.is_empty() || quoted {
+        record.push(field);
+        records.push(record);
+    }
+
+    Ok(records)
+}
+
+/// Write the header and all rows of the table as delimiter-separated values.
+///
+/// Only visible columns are written. Cells that span multiple columns or rows are written into
+/// their first slot, all other slots of the cell stay empty.\
+/// Header cells are prefixed with the label of their [header group](Table::set_header_groups),
+/// e.g. `RX.bytes`. [Generated headers](Table::generate_headers) are skipped.
+pub fn write_records<W: Write>(table: &Table, mut writer: W, delimiter: char) -> io::Result<()> {
+    let visible: Vec<&Column> = table
+        .columns
+        .iter()
+        .filter(|column| !column.is_hidden())
+        .collect();
+
+    let rows = all_rows(table);
+    let body_end = usize::from(table.header.is_some()) + table.rows.len();
+    let groups = header_groups(table);
+    for (row_index, slots) in place_cells(table).iter().take(body_end).enumerate() {
+        let is_header = row_index == 0 && table.header.is_some();
+        // Generated headers aren't part of the data.
+        if is_header && table.header_generated {
+            continue;
+        }
+        let fields: Vec<String> = visible
+            .iter()
+            .map(|column| {
+                let Some(slot) = slots
+                    .iter()
+                    .find(|slot| slot.columns.contains(&column.index))
+                else {
+                    return String::new();
+                };
+
+                // Only the first visible slot of a cell holds its content.
+                let is_first = slot.row == row_index
+                    && visible
+                        .iter()
+                        .find(|column| slot.columns.contains(&column.index))
+                        .is_some_and(|first| first.index == column.index);
+                if !is_first {
+                    return String::new();
+                }
+
+                let mut field = content(rows[slot.row].cells.get(slot.cell));
+                // Header cells are prefixed with the label of their group.
+                if is_header
+                    && let Some((label, _)) = groups
+                        .iter()
+                        .find(|(_, columns)| columns.contains(&column.index))
+                {
+                    field = if field.is_empty() {
+                        content(Some(label))
+                    } else {
+                        format!("{}.{field}", content(Some(label)))
+                    };
+                }
+
+                quote(&field, delimiter)
+            })
+            .collect();
+
+        // A single empty field has to be quoted, otherwise it would be read as an empty line.
+        let line = match fields.as_slice() {
+            [field] if field.is_empty() => "\"\"".to_string(),
+            _ => fields.join(&delimiter.to_string()),
+        };
+        writeln!(writer, "{line}")?;
+    }
+
+    Ok(())
+}
+
+/// Get the raw content of a cell without any styling.
+fn content(cell: Option<&Cell>) -> String {
+    let Some(cell) = cell else {
+        return String::new();
+    };
+
+    #[cfg(feature = "custom_styling")]
+    let content = console::strip_ansi_codes(&cell.content()).to_string();
+    #[cfg(not(feature = "custom_styling"))]
+    let content = cell.content();
+
+    content
+}
+
+/// Enclose a field in double quotes, if it contains the delimiter, quotes or newlines.
+fn quote(field: &str, delimiter: char) -> String {
+    if field.contains([delimiter, '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_records() {
+        let input = "a,\"b,c\",\"d\"\"e\"\r\n\n\"multi\nline\",\nlast";
+        let records = read_records(input, ',').unwrap();
+
+        assert_eq!(
+            records,
+            vec![
+                vec!["a", "b,c", "d\"e"],
+                vec!["multi\nline", ""],
+                vec!["last"],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unterminated_quote() {
+        assert!(read_records("a,\"b", ',').is_err());
+    }
+
+    #[test]
+    fn test_quote() {
+        assert_eq!(quote("plain", ','), "plain");
+        assert_eq!(quote("a,b", ','), "\"a,b\"");
+        assert_eq!(quote("a,b", '\t'), "a,b");
+        assert_eq!(quote("say \"hi\"", ','), "\"say \"\"hi\"\"\"");
+    }
+}
@@ -0,0 +1,69 @@
+use crossterm::style::{Attribute, Attributes, Color};
+
+/// Style a line with the same escape sequences as crossterm.
+///
+/// Crossterm drops all colors, if the `NO_COLOR` environment variable is set. This writes the
+/// colors regardless, so the output of [deterministic](crate::Table::set_deterministic) tables
+/// doesn't depend on the environment.
+pub fn style(line: &str, fg: Option<Color>, bg: Option<Color>, attributes: &[Attribute]) -> String {
+    let mut styled = String::new();
+    if let Some(bg) = bg {
+        styled += &sgr(&color(48, bg));
+    }
+    if let Some(fg) = fg {
+        styled += &sgr(&color(38, fg));
+    }
+
+    // Each attribute is only set once, in the order crossterm sets them.
+    let attributes = Attributes::from(attributes);
+    for attribute in Attribute::iterator().filter(|attribute| attributes.has(*attribute)) {
+        styled += &sgr(&attribute.sgr());
+    }
+
+    styled += line;
+
+    if !attributes.is_empty() {
+        styled += &sgr("0");
+    } else {
+        if bg.is_some() {
+            styled += &sgr(&color(48, Color::Reset));
+        }
+        if fg.is_some() {
+            styled += &sgr(&color(38, Color::Reset));
+        }
+    }
+
+    styled
+}
+
+/// A "Select Graphic Rendition" escape sequence with the given parameters.
+fn sgr(parameters: &str) -> String {
+    format!("\u{1b}[{parameters}m")
+}
+
+/// The parameters, which set the foreground (38) or background (48) to the given color.
+fn color(base: u8, color: Color) -> String {
+    let code = match color {
+        Color::Reset => return (base + 1).to_string(),
+        Color::Black => "5;0".to_string(),
+        Color::DarkGrey => "5;8".to_string(),
+        Color::Red => "5;9".to_string(),
+        Color::DarkRed => "5;1".to_string(),
+        Color::Green => "5;10".to_string(),
+        Color::DarkGreen => "5;2".to_string(),
+        Color::Yellow => "5;11".to_string(),
+        Color::DarkYellow => "5;3".to_string(),
+        Color::Blue => "5;12".to_string(),
+        Color::DarkBlue => "5;4".to_string(),
+        Color::Magenta => "5;13".to_string(),
+        Color::DarkMagenta => "5;5".to_string(),
+        Color::Cyan => "5;14".to_string(),
+        Color::DarkCyan => "5;6".to_string(),
+        Color::White => "5;15".to_string(),
+        Color::Grey => "5;7".to_string(),
+        Color::Rgb { r, g, b } => format!("2;{r};{g};{b}"),
+        Color::AnsiValue(value) => format!("5;{value}"),
+    };
+
+    format!("{base};{code}")
+}
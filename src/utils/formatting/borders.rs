@@ -1,8 +1,177 @@
-use crate::{style::TableComponent, table::Table, utils::ColumnDisplayInfo};
+use super::content_format::{
+    format_annotations, format_banner, format_header_groups, format_row_group,
+};
+use crate::{
+    layout::LineOwner,
+    style::TableComponent,
+    table::Table,
+    utils::{
+        ColumnDisplayInfo,
+        grid::{self, place_cells},
+    },
+};
+
+/// The kind of horizontal line between two rows.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Separator {
+    /// The line between the header and the rows.
+    Header,
+    /// The line between two rows.
+    Rows,
+    /// The line between the rows and the footer.
+    Footer,
+}
+
+/// The cells of a row, which determine how the borders around the row are drawn.
+struct RowShape {
+    /// An identifier of the cell each visible column belongs to.
+    /// Neighboring columns with the same identifier are merged into a single cell.
+    cells: Vec<(usize, usize)>,
+    /// Whether the cell of each visible column spans into the next row.
+    continues: Vec<bool>,
+}
+
+impl RowShape {
+    /// Whether there's a vertical line in front of the visible column with the given index.
+    fn has_line_before(&self, index: usize) -> bool {
+        index > 0 && self.cells.get(index - 1) != self.cells.get(index)
+    }
+
+    /// Whether the cell of the visible column with the given index spans into the next row.
+    fn continues(&self, index: usize) -> bool {
+        self.continues.get(index).copied().unwrap_or(false)
+    }
+}
+
+/// Determine the shape of each row, starting with the header.
+fn row_shapes(table: &Table, display_info: &[ColumnDisplayInfo]) -> Vec<RowShape> {
+    place_cells(table)
+        .iter()
+        .enumerate()
+        .map(|(row_index, slots)| {
+            let mut shape = RowShape {
+                cells: Vec::new(),
+                continues: Vec::new(),
+            };
+            for (column, info) in display_info.iter().enumerate() {
+                if info.is_hidden {
+                    continue;
+                }
+
+                match slots.iter().find(|slot| slot.columns.contains(&column)) {
+                    Some(slot) => {
+                        shape.cells.push((slot.row, slot.cell));
+                        shape.continues.push(slot.rows.end > row_index + 1);
+                    }
+                    // Columns without a cell are treated as separate cells.
+                    None => {
+                        shape.cells.push((usize::MAX, column));
+                        shape.continues.push(false);
+                    }
+                }
+            }
+
+            shape
+        })
+        .collect()
+}
+
+/// The shape of the row of [header groups](Table::set_header_groups).
+fn header_group_shape(table: &Table, display_info: &[ColumnDisplayInfo]) -> RowShape {
+    let groups = grid::header_groups(table);
+    let cells = display_info
+        .iter()
+        .enumerate()
+        .filter(|(_, info)| !info.is_hidden)
+        .map(|(column, _)| {
+            match groups
+                .iter()
+                .position(|(_, columns)| columns.contains(&column))
+            {
+                Some(group) => (0, group),
+                // Columns without a group are treated as separate cells.
+                None => (usize::MAX, column),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    RowShape {
+        continues: vec![false; cells.len()],
+        cells,
+    }
+}
+
+/// The shape of a line, which spans all visible columns, such as the label of a row group.
+fn spanning_shape(display_info: &[ColumnDisplayInfo]) -> RowShape {
+    let columns = display_info.iter().filter(|info| !info.is_hidden).count();
+
+    RowShape {
+        cells: vec![(usize::MAX, usize::MAX); columns],
+        continues: vec![false; columns],
+    }
+}
 
 pub(crate) fn draw_borders(
     table: &Table,
     rows: &[Vec<Vec<String>>],
+    details: &[Vec<String>],
+    display_info: &[ColumnDisplayInfo],
+) -> Vec<String> {
+    let lines = draw_lines(table, rows, details, display_info);
+    if !table.collapse_separators {
+        return lines;
+    }
+
+    let owners = owners_of_lines(table, rows, details, display_info);
+    collapse_separators(lines, owners).0
+}
+
+/// Determine the part of the table each line of the rendered table belongs to.
+///
+/// This mirrors [draw_borders] and needs to be kept in sync with it.
+pub(crate) fn line_owners(
+    table: &Table,
+    rows: &[Vec<Vec<String>>],
+    details: &[Vec<String>],
+    display_info: &[ColumnDisplayInfo],
+) -> Vec<Option<LineOwner>> {
+    let owners = owners_of_lines(table, rows, details, display_info);
+    if !table.collapse_separators {
+        return owners;
+    }
+
+    let lines = draw_lines(table, rows, details, display_info);
+    collapse_separators(lines, owners).1
+}
+
+/// Remove all lines, which are identical to the line above them, if both of them don't belong to
+/// any cells. For instance, this is the case for [raw lines](Table::add_raw_line) that repeat the
+/// separator of the rows.
+fn collapse_separators(
+    lines: Vec<String>,
+    owners: Vec<Option<LineOwner>>,
+) -> (Vec<String>, Vec<Option<LineOwner>>) {
+    let is_separator = |owner: &Option<LineOwner>| matches!(owner, None | Some(LineOwner::Text));
+
+    let mut collapsed: (Vec<String>, Vec<Option<LineOwner>>) = (Vec::new(), Vec::new());
+    for (line, owner) in lines.into_iter().zip(owners) {
+        let is_repeated = collapsed.0.last() == Some(&line)
+            && collapsed.1.last().is_some_and(is_separator)
+            && is_separator(&owner);
+        if !is_repeated {
+            collapsed.0.push(line);
+            collapsed.1.push(owner);
+        }
+    }
+
+    collapsed
+}
+
+/// Draw all lines of the table, including the ones that might be collapsed.
+fn draw_lines(
+    table: &Table,
+    rows: &[Vec<Vec<String>>],
+    details: &[Vec<String>],
     display_info: &[ColumnDisplayInfo],
 ) -> Vec<String> {
     // We know how many lines there should be. Initialize the vector with the rough correct amount.
@@ -15,20 +184,59 @@ pub(crate) fn draw_borders(
         Vec::new()
     };
 
+    let shapes = row_shapes(table, display_info);
+
+    if let Some(title) = &table.title {
+        lines.extend(format_banner(table, title, display_info));
+    }
+
+    let group_lines = format_header_groups(table, display_info);
+    let group_shape = (!group_lines.is_empty()).then(|| header_group_shape(table, display_info));
+
     if should_draw_top_border(table) {
-        lines.push(draw_top_border(table, display_info));
+        let first_row = group_shape.as_ref().or(shapes.first());
+        lines.push(draw_top_border(table, display_info, first_row));
     }
 
-    draw_rows(&mut lines, rows, table, display_info);
+    // The header groups are drawn above the header and are separated from it like any other row.
+    if let Some(group_shape) = &group_shape {
+        for line_parts in group_lines.iter() {
+            lines.push(embed_line(line_parts, table));
+        }
+        if should_draw_horizontal_lines(table) {
+            lines.push(draw_horizontal_lines(
+                table,
+                display_info,
+                Separator::Rows,
+                Some(group_shape),
+                shapes.first(),
+            ));
+        }
+    }
+
+    draw_rows(&mut lines, rows, details, table, display_info, &shapes);
 
     if should_draw_bottom_border(table) {
-        lines.push(draw_bottom_border(table, display_info));
+        lines.push(draw_bottom_border(
+            table,
+            display_info,
+            shapes.last(),
+            false,
+        ));
+    }
+
+    if let Some(caption) = &table.caption {
+        lines.extend(format_banner(table, caption, display_info));
     }
 
     lines
 }
 
-fn draw_top_border(table: &Table, display_info: &[ColumnDisplayInfo]) -> String {
+fn draw_top_border(
+    table: &Table,
+    display_info: &[ColumnDisplayInfo],
+    first_row: Option<&RowShape>,
+) -> String {
     let left_corner = table.style_or_default(TableComponent::TopLeftCorner);
     let top_border = table.style_or_default(TableComponent::TopBorder);
     let intersection = table.style_or_default(TableComponent::TopBorderIntersections);
@@ -42,16 +250,17 @@ fn draw_top_border(table: &Table, display_info: &[ColumnDisplayInfo]) -> String
 
     // Build the top border line depending on the columns' width.
     // Also add the border intersections.
-    let mut first = true;
-    for info in display_info.iter() {
-        // Only add something, if the column isn't hidden
-        if !info.is_hidden {
-            if !first {
+    let visible = display_info.iter().filter(|info| !info.is_hidden);
+    for (index, info) in visible.enumerate() {
+        if index > 0 {
+            // Cells spanning multiple columns don't have any intersections.
+            if first_row.is_none_or(|shape| shape.has_line_before(index)) {
                 line += &intersection;
+            } else {
+                line += &top_border;
             }
-            line += &top_border.repeat(info.width().into());
-            first = false;
         }
+        line += &top_border.repeat(info.width().into());
     }
 
     // We only need the top right corner, if we need to draw a right border
@@ -65,30 +274,287 @@ fn draw_top_border(table: &Table, display_info: &[ColumnDisplayInfo]) -> String
 fn draw_rows(
     lines: &mut Vec<String>,
     rows: &[Vec<Vec<String>>],
+    details: &[Vec<String>],
     table: &Table,
     display_info: &[ColumnDisplayInfo],
+    shapes: &[RowShape],
 ) {
+    let header_offset = usize::from(table.header.is_some());
+    let footer_index = footer_index(table);
+    let label_shape = spanning_shape(display_info);
+
     // Iterate over all rows
     let mut row_iter = rows.iter().enumerate().peekable();
     while let Some((row_index, row)) = row_iter.next() {
+        // Raw lines are drawn verbatim above the row they've been inserted in front of.
+        // The label of a row group is drawn below them.
+        if let Some(index) = row_index
+            .checked_sub(header_offset)
+            .filter(|index| *index < table.rows.len())
+        {
+            lines.extend(raw_lines(table, index).cloned());
+            lines.extend(draw_row_group(
+                table,
+                display_info,
+                index,
+                row_index.checked_sub(1).and_then(|index| shapes.get(index)),
+                shapes.get(row_index),
+            ));
+        }
+
         // Concatenate the line parts and insert the vertical borders if needed
         for line_parts in row.iter() {
             lines.push(embed_line(line_parts, table));
         }
 
+        // Draw the content of the details column below its row.
+        // These lines span the whole table, which is why there are no vertical lines.
+        if let Some(detail_lines) = row_index
+            .checked_sub(header_offset)
+            .and_then(|index| details.get(index))
+        {
+            for detail_line in detail_lines {
+                lines.push(embed_line(std::slice::from_ref(detail_line), table));
+            }
+        }
+
+        // Annotations are drawn directly below the row without any borders.
+        let source_row = match row_index.checked_sub(header_offset) {
+            Some(index) => table.rows.get(index),
+            None => table.header.as_ref(),
+        };
+        if let Some(source_row) = source_row {
+            lines.append(&mut format_annotations(table, source_row));
+        }
+
+        // Raw lines, which have been inserted after the last row, are drawn below it.
+        if is_last_data_row(table, row_index) {
+            lines.extend(raw_lines(table, table.rows.len()).cloned());
+        }
+
+        // Draw the horizontal footer line if desired, otherwise continue to the next iteration
+        if Some(row_index + 1) == footer_index {
+            if should_draw_footer(table) {
+                lines.push(draw_horizontal_lines(
+                    table,
+                    display_info,
+                    Separator::Footer,
+                    shapes.get(row_index),
+                    shapes.get(row_index + 1),
+                ));
+            }
+            continue;
+        }
+
+        // The label of a row group is drawn below the line, if the next row starts a group.
+        let below = if starts_row_group(table, row_index + 1 - header_offset) {
+            Some(&label_shape)
+        } else {
+            shapes.get(row_index + 1)
+        };
+
         // Draw the horizontal header line if desired, otherwise continue to the next iteration
         if row_index == 0 && table.header.is_some() {
             if should_draw_header(table) {
-                lines.push(draw_horizontal_lines(table, display_info, true));
+                lines.push(draw_horizontal_lines(
+                    table,
+                    display_info,
+                    Separator::Header,
+                    shapes.get(row_index),
+                    below,
+                ));
             }
             continue;
         }
 
         // Draw a horizontal line, if we desired and if we aren't in the last row of the table.
         if row_iter.peek().is_some() && should_draw_horizontal_lines(table) {
-            lines.push(draw_horizontal_lines(table, display_info, false));
+            lines.push(draw_horizontal_lines(
+                table,
+                display_info,
+                Separator::Rows,
+                shapes.get(row_index),
+                below,
+            ));
+        }
+    }
+}
+
+/// The [raw lines](Table::add_raw_line), which are drawn above the row with the given index.
+fn raw_lines(table: &Table, index: usize) -> impl Iterator<Item = &String> {
+    table
+        .raw_lines
+        .iter()
+        .filter(move |(position, _)| *position == index)
+        .map(|(_, line)| line)
+}
+
+/// Whether the data row with the given index is the first row of a [row group](Table::add_row_group).
+fn starts_row_group(table: &Table, index: usize) -> bool {
+    table.row_groups.iter().any(|(start, _)| *start == index)
+}
+
+/// Draw the label of the [row group](Table::add_row_group), which starts with the data row with
+/// the given index, including the lines that separate it from the rows around it.
+fn draw_row_group(
+    table: &Table,
+    display_info: &[ColumnDisplayInfo],
+    index: usize,
+    above: Option<&RowShape>,
+    below: Option<&RowShape>,
+) -> Vec<String> {
+    let Some((_, label)) = table.row_groups.iter().find(|(start, _)| *start == index) else {
+        return Vec::new();
+    };
+    let label_shape = spanning_shape(display_info);
+
+    let mut lines = Vec::new();
+    // Groups are separated by the line below the header, if rows aren't separated at all.
+    if index > 0 && !should_draw_horizontal_lines(table) && should_draw_header(table) {
+        lines.push(draw_horizontal_lines(
+            table,
+            display_info,
+            Separator::Header,
+            above,
+            Some(&label_shape),
+        ));
+    }
+    for line in format_row_group(table, label, display_info) {
+        lines.push(embed_line(std::slice::from_ref(&line), table));
+    }
+    if should_draw_horizontal_lines(table) {
+        lines.push(draw_horizontal_lines(
+            table,
+            display_info,
+            Separator::Rows,
+            Some(&label_shape),
+            below,
+        ));
+    }
+
+    lines
+}
+
+/// Whether the row with the given index, including the header, is the last one before the
+/// footer. This is the header, if there aren't any rows.
+fn is_last_data_row(table: &Table, row_index: usize) -> bool {
+    match table.rows.len() {
+        0 => table.header.is_some() && row_index == 0,
+        rows => row_index + 1 == usize::from(table.header.is_some()) + rows,
+    }
+}
+
+/// The owner of each line, which is drawn by [draw_lines].
+///
+/// This mirrors [draw_lines] and needs to be kept in sync with it.
+fn owners_of_lines(
+    table: &Table,
+    rows: &[Vec<Vec<String>>],
+    details: &[Vec<String>],
+    display_info: &[ColumnDisplayInfo],
+) -> Vec<Option<LineOwner>> {
+    let mut lines = Vec::new();
+    if let Some(title) = &table.title {
+        lines.extend(
+            format_banner(table, title, display_info)
+                .iter()
+                .map(|_| Some(LineOwner::Text)),
+        );
+    }
+    if should_draw_top_border(table) {
+        lines.push(None);
+    }
+    let group_lines = format_header_groups(table, display_info).len();
+    if group_lines > 0 {
+        lines.extend(std::iter::repeat_n(None, group_lines));
+        if should_draw_horizontal_lines(table) {
+            lines.push(None);
+        }
+    }
+
+    let header_offset = usize::from(table.header.is_some());
+    let footer_index = footer_index(table);
+    let mut row_iter = rows.iter().enumerate().peekable();
+    while let Some((row_index, row)) = row_iter.next() {
+        let data_index = row_index.checked_sub(header_offset);
+        let owner = if Some(row_index) == footer_index {
+            LineOwner::Footer
+        } else {
+            data_index.map_or(LineOwner::Header, LineOwner::Row)
+        };
+        if let Some(index) = data_index.filter(|index| *index < table.rows.len()) {
+            lines.extend(raw_lines(table, index).map(|_| Some(LineOwner::Text)));
+            let group_lines = draw_row_group(table, display_info, index, None, None).len();
+            lines.extend(std::iter::repeat_n(None, group_lines));
+        }
+        lines.extend(std::iter::repeat_n(Some(owner), row.len()));
+
+        if let Some(index) = data_index {
+            let detail_lines = details.get(index).map_or(0, Vec::len);
+            lines.extend(std::iter::repeat_n(
+                Some(LineOwner::Details(index)),
+                detail_lines,
+            ));
+        }
+
+        let source_row = match data_index {
+            Some(index) => table.rows.get(index),
+            None => table.header.as_ref(),
+        };
+        for (index, annotation) in source_row
+            .iter()
+            .flat_map(|row| row.annotations.iter().enumerate())
+        {
+            let owner = LineOwner::Annotation {
+                row: data_index,
+                index,
+            };
+            lines.extend(std::iter::repeat_n(Some(owner), annotation.content.len()));
+        }
+
+        if is_last_data_row(table, row_index) {
+            lines.extend(raw_lines(table, table.rows.len()).map(|_| Some(LineOwner::Text)));
+        }
+
+        if Some(row_index + 1) == footer_index {
+            if should_draw_footer(table) {
+                lines.push(None);
+            }
+            continue;
+        }
+
+        if row_index == 0 && table.header.is_some() {
+            if should_draw_header(table) {
+                lines.push(None);
+            }
+            continue;
+        }
+
+        if row_iter.peek().is_some() && should_draw_horizontal_lines(table) {
+            lines.push(None);
         }
     }
+
+    if should_draw_bottom_border(table) {
+        lines.push(None);
+    }
+    if let Some(caption) = &table.caption {
+        lines.extend(
+            format_banner(table, caption, display_info)
+                .iter()
+                .map(|_| Some(LineOwner::Text)),
+        );
+    }
+
+    lines
+}
+
+/// The index of the footer in the formatted rows, if the table has a footer.
+fn footer_index(table: &Table) -> Option<usize> {
+    table
+        .footer
+        .as_ref()
+        .map(|_| usize::from(table.header.is_some()) + table.rows.len())
 }
 
 // Takes the parts of a single line, surrounds them with borders and adds vertical lines.
@@ -119,59 +585,157 @@ fn embed_line(line_parts: &[String], table: &Table) -> String {
 fn draw_horizontal_lines(
     table: &Table,
     display_info: &[ColumnDisplayInfo],
-    header: bool,
+    separator: Separator,
+    above: Option<&RowShape>,
+    below: Option<&RowShape>,
 ) -> String {
-    // Styling depends on whether we're currently on the header line or not.
-    let (left_intersection, horizontal_lines, middle_intersection, right_intersection) = if header {
-        (
+    // Styling depends on whether we're currently on the header or footer line or not.
+    // The middle intersections are determined separately, see [intersection].
+    let (left_intersection, horizontal_lines, right_intersection) = match separator {
+        Separator::Header => (
             table.style_or_default(TableComponent::LeftHeaderIntersection),
             table.style_or_default(TableComponent::HeaderLines),
-            table.style_or_default(TableComponent::MiddleHeaderIntersections),
             table.style_or_default(TableComponent::RightHeaderIntersection),
-        )
-    } else {
-        (
+        ),
+        Separator::Rows => (
             table.style_or_default(TableComponent::LeftBorderIntersections),
             table.style_or_default(TableComponent::HorizontalLines),
-            table.style_or_default(TableComponent::MiddleIntersections),
             table.style_or_default(TableComponent::RightBorderIntersections),
-        )
+        ),
+        Separator::Footer => (
+            table.style_or_default(TableComponent::LeftFooterIntersection),
+            table.style_or_default(TableComponent::FooterLines),
+            table.style_or_default(TableComponent::RightFooterIntersection),
+        ),
     };
 
+    // Cells that span into the next row aren't separated by a horizontal line.
+    let continues = |index: usize| above.is_some_and(|shape| shape.continues(index));
+    let visible: Vec<&ColumnDisplayInfo> =
+        display_info.iter().filter(|info| !info.is_hidden).collect();
+
     let mut line = String::new();
     // We only need the bottom left corner, if we need to draw a left border
     if should_draw_left_border(table) {
-        line += &left_intersection;
+        if continues(0) {
+            line += &table.style_or_default(TableComponent::LeftBorder);
+        } else {
+            line += &left_intersection;
+        }
     }
 
     let draw_vertical_lines = should_draw_vertical_lines(table);
 
     // Append the middle lines depending on the columns' widths.
     // Also add the middle intersections.
-    let mut first = true;
-    for info in display_info.iter() {
-        // Only add something, if the column isn't hidden
-        if !info.is_hidden {
-            if !first && draw_vertical_lines {
-                line += &middle_intersection;
-            }
+    for (index, info) in visible.iter().enumerate() {
+        if index > 0 && draw_vertical_lines {
+            line += &intersection(table, separator, index, above, below);
+        }
+
+        if continues(index) {
+            line += &" ".repeat(info.width().into());
+        } else {
             line += &horizontal_lines.repeat(info.width().into());
-            first = false;
         }
     }
 
     // We only need the bottom right corner, if we need to draw a right border
     if should_draw_right_border(table) {
-        line += &right_intersection;
+        if continues(visible.len().saturating_sub(1)) {
+            line += &table.style_or_default(TableComponent::RightBorder);
+        } else {
+            line += &right_intersection;
+        }
     }
 
     line
 }
 
-fn draw_bottom_border(table: &Table, display_info: &[ColumnDisplayInfo]) -> String {
+/// Determine the character in front of the visible column with the given index in a horizontal
+/// line, depending on the cells above and below the line.
+///
+/// Without any cells that span multiple columns or rows, this is always the middle intersection.
+fn intersection(
+    table: &Table,
+    separator: Separator,
+    index: usize,
+    above: Option<&RowShape>,
+    below: Option<&RowShape>,
+) -> String {
+    let (middle_intersection, horizontal_lines) = match separator {
+        Separator::Header => (
+            TableComponent::MiddleHeaderIntersections,
+            TableComponent::HeaderLines,
+        ),
+        Separator::Rows => (
+            TableComponent::MiddleIntersections,
+            TableComponent::HorizontalLines,
+        ),
+        Separator::Footer => (
+            TableComponent::MiddleFooterIntersections,
+            TableComponent::FooterLines,
+        ),
+    };
+    // Use the given component, if the preset has one. Fall back to the middle intersection.
+    let style_or_middle = |component: TableComponent| {
+        if separator == Separator::Rows && table.style_exists(component) {
+            table.style_or_default(component)
+        } else {
+            table.style_or_default(middle_intersection)
+        }
+    };
+
+    let continues = |index: usize| above.is_some_and(|shape| shape.continues(index));
+    let line_above = above.is_none_or(|shape| shape.has_line_before(index));
+    let line_below = below.is_none_or(|shape| shape.has_line_before(index));
+
+    match (continues(index - 1), continues(index)) {
+        (true, true) if line_above => table.style_or_default(TableComponent::VerticalLines),
+        (true, true) => " ".to_string(),
+        (true, false) => style_or_middle(TableComponent::LeftBorderIntersections),
+        (false, true) => style_or_middle(TableComponent::RightBorderIntersections),
+        (false, false) => match (line_above, line_below) {
+            (true, true) => table.style_or_default(middle_intersection),
+            (true, false) => style_or_middle(TableComponent::BottomBorderIntersections),
+            (false, true) => style_or_middle(TableComponent::TopBorderIntersections),
+            (false, false) => table.style_or_default(horizontal_lines),
+        },
+    }
+}
+
+/// Draw the line in place of the bottom border, which signals that the table is cut off below
+/// its last row.
+///
+/// Returns `None`, if no [TableComponent::ContinuationBorder] is set.
+pub(crate) fn draw_continuation_border(
+    table: &Table,
+    display_info: &[ColumnDisplayInfo],
+) -> Option<String> {
+    if !table.style_exists(TableComponent::ContinuationBorder) {
+        return None;
+    }
+
+    let shapes = row_shapes(table, display_info);
+    Some(draw_bottom_border(table, display_info, shapes.last(), true))
+}
+
+fn draw_bottom_border(
+    table: &Table,
+    display_info: &[ColumnDisplayInfo],
+    last_row: Option<&RowShape>,
+    continued: bool,
+) -> String {
     let left_corner = table.style_or_default(TableComponent::BottomLeftCorner);
-    let bottom_border = table.style_or_default(TableComponent::BottomBorder);
-    let middle_intersection = table.style_or_default(TableComponent::BottomBorderIntersections);
+    let mut bottom_border = table.style_or_default(TableComponent::BottomBorder);
+    let mut middle_intersection = table.style_or_default(TableComponent::BottomBorderIntersections);
+    if continued {
+        bottom_border = table.style_or_default(TableComponent::ContinuationBorder);
+        if table.style_exists(TableComponent::ContinuationBorderIntersections) {
+            middle_intersection =
+                table.style_or_default(TableComponent::ContinuationBorderIntersections);
+        }
+    }
     let right_corner = table.style_or_default(TableComponent::BottomRightCorner);
 
     let mut line = String::new();
@@ -182,16 +746,17 @@ fn draw_bottom_border(table: &Table, display_info: &[ColumnDisplayInfo]) -> Stri
 
     // Add the bottom border lines depending on column width
     // Also add the border intersections.
-    let mut first = true;
-    for info in display_info.iter() {
-        // Only add something, if the column isn't hidden
-        if !info.is_hidden {
-            if !first {
+    let visible = display_info.iter().filter(|info| !info.is_hidden);
+    for (index, info) in visible.enumerate() {
+        if index > 0 {
+            // Cells spanning multiple columns don't have any intersections.
+            if last_row.is_none_or(|shape| shape.has_line_before(index)) {
                 line += &middle_intersection;
+            } else {
+                line += &bottom_border;
             }
-            line += &bottom_border.repeat(info.width().into());
-            first = false;
         }
+        line += &bottom_border.repeat(info.width().into());
     }
 
     // We only need the bottom right corner, if we need to draw a right border
@@ -202,7 +767,7 @@ fn draw_bottom_border(table: &Table, display_info: &[ColumnDisplayInfo]) -> Stri
     line
 }
 
-fn should_draw_top_border(table: &Table) -> bool {
+pub fn should_draw_top_border(table: &Table) -> bool {
     if table.style_exists(TableComponent::TopLeftCorner)
         || table.style_exists(TableComponent::TopBorder)
         || table.style_exists(TableComponent::TopBorderIntersections)
@@ -214,7 +779,7 @@ fn should_draw_top_border(table: &Table) -> bool {
     false
 }
 
-fn should_draw_bottom_border(table: &Table) -> bool {
+pub fn should_draw_bottom_border(table: &Table) -> bool {
     if table.style_exists(TableComponent::BottomLeftCorner)
         || table.style_exists(TableComponent::BottomBorder)
         || table.style_exists(TableComponent::BottomBorderIntersections)
@@ -252,7 +817,7 @@ pub fn should_draw_right_border(table: &Table) -> bool {
     false
 }
 
-fn should_draw_horizontal_lines(table: &Table) -> bool {
+pub fn should_draw_horizontal_lines(table: &Table) -> bool {
     if table.style_exists(TableComponent::LeftBorderIntersections)
         || table.style_exists(TableComponent::HorizontalLines)
         || table.style_exists(TableComponent::MiddleIntersections)
@@ -277,7 +842,7 @@ pub fn should_draw_vertical_lines(table: &Table) -> bool {
     false
 }
 
-fn should_draw_header(table: &Table) -> bool {
+pub fn should_draw_header(table: &Table) -> bool {
     if table.style_exists(TableComponent::LeftHeaderIntersection)
         || table.style_exists(TableComponent::HeaderLines)
         || table.style_exists(TableComponent::MiddleHeaderIntersections)
@@ -288,3 +853,15 @@ fn should_draw_header(table: &Table) -> bool {
 
     false
 }
+
+pub fn should_draw_footer(table: &Table) -> bool {
+    if table.style_exists(TableComponent::LeftFooterIntersection)
+        || table.style_exists(TableComponent::FooterLines)
+        || table.style_exists(TableComponent::MiddleFooterIntersections)
+        || table.style_exists(TableComponent::RightFooterIntersection)
+    {
+        return true;
+    }
+
+    false
+}
@@ -1,12 +1,23 @@
 #[cfg(feature = "tty")]
 use crossterm::style::{Stylize, style};
 use unicode_segmentation::UnicodeSegmentation;
-use unicode_width::UnicodeWidthStr;
 
-use super::content_split::{measure_text_width, split_line};
+#[cfg(feature = "tty")]
+use super::ansi;
+use super::{
+    borders::should_draw_vertical_lines,
+    content_split::{SOFT_HYPHEN, measure_text_width, split_line},
+};
 #[cfg(feature = "tty")]
 use crate::style::{map_attribute, map_color};
-use crate::{cell::Cell, row::Row, style::CellAlignment, table::Table, utils::ColumnDisplayInfo};
+use crate::{
+    cell::Cell,
+    column::Column,
+    row::Row,
+    style::{CellAlignment, TableComponent, TruncationStyle, WidthTable},
+    table::Table,
+    utils::{ColumnDisplayInfo, arrangement::helper::count_border_columns, grid},
+};
 
 pub fn delimiter(cell: &Cell, info: &ColumnDisplayInfo, table: &Table) -> char {
     // Determine, which delimiter should be used
@@ -41,6 +52,11 @@ pub fn format_content(table: &Table, display_info: &[ColumnDisplayInfo]) -> Vec<
     // The content of the whole table
     let mut table_content = Vec::with_capacity(table.rows.len() + 1);
 
+    // Cells that span multiple columns or rows need to be placed in the table's grid first.
+    if grid::has_spans(table) {
+        return format_spanned_content(table, display_info);
+    }
+
     // Format table header if it exists
     if let Some(header) = table.header() {
         table_content.push(format_row(header, display_info, table));
@@ -49,171 +65,328 @@ pub fn format_content(table: &Table, display_info: &[ColumnDisplayInfo]) -> Vec<
     for row in table.rows.iter() {
         table_content.push(format_row(row, display_info, table));
     }
+
+    if let Some(footer) = &table.footer {
+        table_content.push(format_row(footer, display_info, table));
+    }
     table_content
 }
 
-pub fn format_row(
-    row: &Row,
-    display_infos: &[ColumnDisplayInfo],
-    table: &Table,
-) -> Vec<Vec<String>> {
-    // The content of this specific row
-    let mut temp_row_content = Vec::with_capacity(display_infos.len());
-
-    let mut cell_iter = row.cells.iter();
-    // Now iterate over all cells and handle them according to their alignment
-    for info in display_infos.iter() {
-        if info.is_hidden {
-            cell_iter.next();
+/// Format the labels of the [header groups](Table::set_header_groups).
+///
+/// Each group is a single part of a line, which covers all of its visible columns.
+/// Labels are centered, unless the cell has its own alignment.
+pub fn format_header_groups(table: &Table, display_info: &[ColumnDisplayInfo]) -> Vec<Vec<String>> {
+    let groups = grid::header_groups(table);
+    let mut group_iter = groups.iter().peekable();
+    let mut parts: Vec<(Vec<String>, usize)> = Vec::new();
+    let mut column = 0;
+    while column < display_info.len() {
+        if let Some((cell, columns)) = group_iter.next_if(|(_, columns)| columns.start == column) {
+            if let Some(mut info) = grid::merged_info(table, display_info, columns.clone()) {
+                info.cell_alignment = Some(CellAlignment::Center);
+                parts.push((format_cell(cell, &info, None, table), info.width().into()));
+            }
+            column = columns.end;
             continue;
         }
 
-        // Each cell is divided into several lines divided by newline
-        // Every line that's too long will be split into multiple lines
-        let mut cell_lines = Vec::new();
+        // Columns without a group are filled with spaces.
+        let info = &display_info[column];
+        if !info.is_hidden {
+            parts.push((Vec::new(), info.width().into()));
+        }
+        column += 1;
+    }
 
-        // Check if the row has as many cells as the table has columns.
-        // If that's not the case, create a new cell with empty spaces.
-        let Some(cell) = cell_iter.next() else {
-            cell_lines.push(" ".repeat(info.width().into()));
-            temp_row_content.push(cell_lines);
-            continue;
-        };
+    let height = parts
+        .iter()
+        .map(|(lines, _)| lines.len())
+        .max()
+        .unwrap_or(0);
+    (0..height)
+        .map(|index| {
+            parts
+                .iter()
+                .map(|(lines, width)| match lines.get(index) {
+                    Some(line) => line.clone(),
+                    None => " ".repeat(*width),
+                })
+                .collect()
+        })
+        .collect()
+}
 
-        // The delimiter is configurable, determine which one should be used for this cell.
-        let delimiter = delimiter(cell, info, table);
+/// The display info of a cell, whose columns may have been merged, and its formatted lines.
+type FormattedCell = (ColumnDisplayInfo, Vec<String>);
 
-        // Iterate over each line and split it into multiple lines if necessary.
-        // Newlines added by the user will be preserved.
-        for line in cell.content.iter() {
-            if measure_text_width(line) > info.content_width.into() {
-                let mut parts = split_line(line, info, delimiter);
-                cell_lines.append(&mut parts);
-            } else {
-                cell_lines.push(line.into());
-            }
+/// Format the content of a table, whose cells span multiple columns or rows.
+///
+/// Each cell is formatted according to its merged columns first. Afterwards, the height of each
+/// row is determined and the lines of cells, which span multiple rows, are distributed over
+/// these rows. The resulting structure is the same as the one of [format_content], except that
+/// each merged cell is a single part of a line.
+fn format_spanned_content(
+    table: &Table,
+    display_info: &[ColumnDisplayInfo],
+) -> Vec<Vec<Vec<String>>> {
+    let rows = grid::all_rows(table);
+    let grid = grid::place_cells(table);
+
+    // The merged display info and the formatted lines of each cell, indexed by row and cell.
+    let mut cells: Vec<Vec<Option<FormattedCell>>> =
+        rows.iter().map(|row| vec![None; row.cells.len()]).collect();
+    for (row_index, slots) in grid.iter().enumerate() {
+        let row = rows[row_index];
+        for slot in slots.iter().filter(|slot| slot.row == row_index) {
+            let Some(info) = grid::merged_info(table, display_info, slot.columns.clone()) else {
+                continue;
+            };
+            let lines = format_cell(&row.cells[slot.cell], &info, row.max_height, table);
+            cells[row_index][slot.cell] = Some((info, lines));
         }
+    }
 
-        // Remove all unneeded lines of this cell, if the row's height is capped to a certain
-        // amount of lines and there're too many lines in this cell.
-        // This then truncates and inserts a '...' string at the end of the last line to indicate
-        // that the cell has been truncated.
-        if let Some(lines) = row.max_height
-            && cell_lines.len() > lines
+    // Determine the height of each row, ignoring cells that span multiple rows.
+    let has_visible_columns = display_info.iter().any(|info| !info.is_hidden);
+    let mut heights: Vec<usize> = grid
+        .iter()
+        .enumerate()
+        .map(|(row_index, slots)| {
+            let min_height = if has_visible_columns {
+                rows[row_index].min_height.unwrap_or(1).max(1)
+            } else {
+                0
+            };
+            slots
+                .iter()
+                .filter(|slot| slot.row == row_index && slot.rows.len() == 1)
+                .filter_map(|slot| cells[row_index][slot.cell].as_ref())
+                .map(|(_, lines)| lines.len())
+                .fold(min_height, usize::max)
+        })
+        .collect();
+
+    // Cells that span multiple rows get the combined height of those rows.
+    // If that's not enough, the last spanned row grows accordingly.
+    for (row_index, slots) in grid.iter().enumerate() {
+        for slot in slots
+            .iter()
+            .filter(|slot| slot.row == row_index && slot.rows.len() > 1)
         {
-            // We already have to many lines. Cut off the surplus lines.
-            let _ = cell_lines.split_off(lines);
-
-            // Directly access the last line.
-            let last_line = cell_lines
-                .get_mut(lines - 1)
-                .expect("We know it's this long.");
-
-            // Truncate any ansi codes, as the following cutoff might break ansi code
-            // otherwise anyway. This could be handled smarter, but it's simple and just works.
-            #[cfg(feature = "custom_styling")]
-            {
-                let stripped = console::strip_ansi_codes(last_line).to_string();
-                *last_line = stripped;
-            }
+            let Some((_, lines)) = &cells[row_index][slot.cell] else {
+                continue;
+            };
+            let available: usize = heights[slot.rows.clone()].iter().sum();
+            heights[slot.rows.end - 1] += lines.len().saturating_sub(available);
+        }
+    }
 
-            let max_width: usize = info.content_width.into();
-            let indicator_width = table.truncation_indicator.width();
+    grid.iter()
+        .enumerate()
+        .map(|(row_index, slots)| {
+            // The lines of each part of this row with the offset of this row inside the cell and
+            // the line, which fills the part below its content.
+            let mut parts: Vec<(&[String], usize, String)> = Vec::new();
+            let mut slot_iter = slots.iter().peekable();
+            let mut column = 0;
+            while column < display_info.len() {
+                if let Some(slot) = slot_iter.next_if(|slot| slot.columns.start == column) {
+                    if let Some((info, lines)) = &cells[slot.row][slot.cell] {
+                        let offset = heights[slot.rows.start..row_index].iter().sum();
+                        let cell = &rows[slot.row].cells[slot.cell];
+                        parts.push((lines, offset, blank_line(table, info, Some(cell))));
+                    }
+                    column = slot.columns.end;
+                    continue;
+                }
 
-            let mut truncate_at = 0;
-            // Start the accumulated_width with the indicator_width, which is the minimum width
-            // we may show anyway.
-            let mut accumulated_width = indicator_width;
-            let mut full_string_fits = false;
+                // Columns without a cell are filled with spaces.
+                let info = &display_info[column];
+                if !info.is_hidden {
+                    parts.push((&[], 0, blank_line(table, info, None)));
+                }
+                column += 1;
+            }
 
-            // Leave these print statements in here in case we ever have to debug this annoying
-            // stuff again.
-            //println!("\nSTART:");
-            //println!("\nMax width: {max_width}, Indicator width: {indicator_width}");
-            //println!("Full line hex: {last_line}");
-            //println!(
-            //    "Full line hex: {}",
-            //    last_line
-            //        .as_bytes()
-            //        .iter()
-            //        .map(|byte| format!("{byte:02x}"))
-            //        .collect::<Vec<String>>()
-            //        .join(", ")
-            //);
+            (0..heights[row_index])
+                .map(|index| {
+                    parts
+                        .iter()
+                        .map(|(lines, offset, blank)| match lines.get(offset + index) {
+                            Some(line) => line.clone(),
+                            None => blank.clone(),
+                        })
+                        .collect()
+                })
+                .collect()
+        })
+        .collect()
+}
 
-            // Iterate through the UTF-8 graphemes.
-            // Check the `split_long_word` inline function docs to see why we're using
-            // graphemes.
-            // **Note:** The `index` here is the **byte** index. So we cannot just
-            //    String::truncate afterwards. We have to convert to a byte vector to perform
-            //    the truncation first.
-            let mut grapheme_iter = last_line.grapheme_indices(true).peekable();
-            while let Some((index, grapheme)) = grapheme_iter.next() {
-                // Leave these print statements in here in case we ever have to debug this
-                // annoying stuff again
-                //println!(
-                //    "Current index: {index}, Next grapheme: {grapheme} (width: {})",
-                //    grapheme.width()
-                //);
-                //println!(
-                //    "Next grapheme hex: {}",
-                //    grapheme
-                //        .as_bytes()
-                //        .iter()
-                //        .map(|byte| format!("{byte:02x}"))
-                //        .collect::<Vec<String>>()
-                //        .join(", ")
-                //);
+/// Format the label of a [row group](Table::add_row_group), which spans all visible columns.
+///
+/// Labels are left aligned, unless the cell has its own alignment.
+pub fn format_row_group(
+    table: &Table,
+    cell: &Cell,
+    display_info: &[ColumnDisplayInfo],
+) -> Vec<String> {
+    let Some(mut info) = grid::merged_info(table, display_info, 0..display_info.len()) else {
+        return Vec::new();
+    };
+    info.cell_alignment = Some(CellAlignment::Left);
 
-                // Immediately save where to truncate in case this grapheme doesn't fit.
-                // The index is just before the current grapheme actually starts.
-                truncate_at = index;
-                // Check if the next grapheme would break the boundary of the allowed line
-                // length.
-                let new_width = accumulated_width + grapheme.width();
-                //println!(
-                //    "Next width: {new_width}/{max_width} ({accumulated_width} + {})",
-                //    grapheme.width()
-                //);
-                if new_width > max_width {
-                    //println!(
-                    //    "Breaking: {:?}",
-                    //    accumulated_width + grapheme.width() > max_width
-                    //);
-                    break;
-                }
+    format_cell(cell, &info, None, table)
+}
+
+/// Format the content of the details column below each row.
+///
+/// The content spans the whole inner width of the table and is indented by two spaces.
+/// Returns the lines for each row of the table, which are then drawn below the respective row.
+pub fn format_details(
+    table: &Table,
+    column_index: usize,
+    display_infos: &[ColumnDisplayInfo],
+) -> Vec<Vec<String>> {
+    let visible: Vec<&ColumnDisplayInfo> = display_infos
+        .iter()
+        .filter(|info| !info.is_hidden)
+        .collect();
+    let mut inner_width: usize = visible.iter().map(|info| usize::from(info.width())).sum();
+    if should_draw_vertical_lines(table) {
+        inner_width += visible.len().saturating_sub(1);
+    }
 
-                // The grapheme seems to fit. Save the index and check the next one.
-                accumulated_width += grapheme.width();
+    let column = &table.columns[column_index];
+    let mut info = ColumnDisplayInfo::new(
+        column,
+        inner_width
+            .saturating_sub(DETAILS_INDENT + 2)
+            .try_into()
+            .unwrap_or(u16::MAX),
+    );
+    info.padding = (DETAILS_INDENT as u16 + 1, 1);
+    info.cell_alignment = Some(CellAlignment::Left);
+
+    table
+        .rows
+        .iter()
+        .map(|row| {
+            let Some(cell) = row.cells.get(column_index) else {
+                return Vec::new();
+            };
+            if cell.content.iter().all(String::is_empty) {
+                return Vec::new();
+            }
 
-                // This is a special case.
-                // We reached the last char, meaning that full last line + the indicator fit.
-                if grapheme_iter.peek().is_none() {
-                    full_string_fits = true
+            let delimiter = delimiter(cell, &info, table);
+            let mut lines = Vec::new();
+            for line in cell.content.iter() {
+                if measure_text_width(line, &table.width_table) > info.content_width.into() {
+                    lines.append(&mut split_line(line, &info, delimiter, &table.width_table));
+                } else {
+                    lines.push(line.clone());
                 }
             }
 
-            // Only do any truncation logic if the line doesn't fit.
-            if !full_string_fits {
-                // Truncate the string at the byte index just behind the last valid grapheme
-                // and overwrite the last line with the new truncated string.
-                let mut last_line_bytes = last_line.clone().into_bytes();
-                last_line_bytes.truncate(truncate_at);
-                let new_last_line =
-                    String::from_utf8(last_line_bytes).expect("We cut at an exact char boundary");
-                *last_line = new_last_line;
-            }
+            lines
+                .into_iter()
+                .map(|line| align_line(table, &info, cell, line))
+                .collect()
+        })
+        .collect()
+}
 
-            // Push the truncation indicator.
-            last_line.push_str(&table.truncation_indicator);
+/// Format the annotations of a row.
+///
+/// Annotations are indented by two spaces and aren't aligned or padded.
+#[allow(unused_variables)]
+pub fn format_annotations(table: &Table, row: &Row) -> Vec<String> {
+    let mut lines = Vec::new();
+    for annotation in row.annotations.iter() {
+        for line in annotation.content.iter() {
+            #[cfg(feature = "tty")]
+            let line = if table.should_style() {
+                style_table_line(table, line.clone(), annotation)
+            } else {
+                line.clone()
+            };
+
+            lines.push(" ".repeat(DETAILS_INDENT) + line.as_str());
         }
+    }
+
+    lines
+}
 
-        // Iterate over all generated lines of this cell and align them
-        let cell_lines = cell_lines
+/// Format the title or caption of a table.
+///
+/// The content spans the full width of the table including its borders and is centered, unless
+/// the cell has its own alignment. Trailing whitespace is removed from each line.
+pub fn format_banner(
+    table: &Table,
+    cell: &Cell,
+    display_infos: &[ColumnDisplayInfo],
+) -> Vec<String> {
+    let visible: Vec<&ColumnDisplayInfo> = display_infos
+        .iter()
+        .filter(|info| !info.is_hidden)
+        .collect();
+    let width = count_border_columns(table, visible.len())
+        + visible
             .iter()
-            .map(|line| align_line(table, info, cell, line.to_string()));
+            .map(|info| usize::from(info.width()))
+            .sum::<usize>();
+
+    let mut info = ColumnDisplayInfo::new(&Column::new(0), width.try_into().unwrap_or(u16::MAX));
+    info.padding = (0, 0);
+    info.cell_alignment = Some(CellAlignment::Center);
+
+    let delimiter = delimiter(cell, &info, table);
+    let mut lines = Vec::new();
+    for line in cell.content.iter() {
+        if measure_text_width(line, &table.width_table) > info.content_width.into() {
+            lines.append(&mut split_line(line, &info, delimiter, &table.width_table));
+        } else {
+            lines.push(line.clone());
+        }
+    }
+
+    lines
+        .into_iter()
+        .map(|line| align_line(table, &info, cell, line).trim_end().to_string())
+        .collect()
+}
+
+/// The indentation of the content of the details column and of annotations.
+pub(crate) const DETAILS_INDENT: usize = 2;
+
+pub fn format_row(
+    row: &Row,
+    display_infos: &[ColumnDisplayInfo],
+    table: &Table,
+) -> Vec<Vec<String>> {
+    // The content of this specific row
+    let mut temp_row_content = Vec::with_capacity(display_infos.len());
+
+    let mut cell_iter = row.cells.iter();
+    // Now iterate over all cells and handle them according to their alignment
+    for info in display_infos.iter() {
+        if info.is_hidden {
+            cell_iter.next();
+            continue;
+        }
+
+        // Check if the row has as many cells as the table has columns.
+        // If that's not the case, create a new cell with empty spaces.
+        let Some(cell) = cell_iter.next() else {
+            temp_row_content.push((vec![], blank_line(table, info, None)));
+            continue;
+        };
 
-        temp_row_content.push(cell_lines.collect());
+        let lines = format_cell(cell, info, row.max_height, table);
+        temp_row_content.push((lines, blank_line(table, info, Some(cell))));
     }
 
     // Right now, we have a different structure than desired.
@@ -231,7 +404,11 @@ pub fn format_row(
     //  tc[0][0][0]     tc[0][0][1]
     //  tc[0][1][0]     tc[0][1][1]
     //  tc[0][2][0]     tc[0][2][1] <- Now filled with placeholder (spaces)
-    let max_lines = temp_row_content.iter().map(Vec::len).max().unwrap_or(0);
+    let max_lines = temp_row_content
+        .iter()
+        .map(|(lines, _)| lines.len().max(1))
+        .max()
+        .map_or(0, |lines| lines.max(row.min_height.unwrap_or(0)));
     let mut row_content = Vec::with_capacity(max_lines * display_infos.len());
 
     // Each column should have `max_lines` for this row.
@@ -245,13 +422,13 @@ pub fn format_row(
                 continue;
             }
 
-            let cell = cell_iter.next().unwrap();
+            let (cell, blank) = cell_iter.next().unwrap();
             match cell.get(index) {
                 // The current cell has content for this line. Append it
                 Some(content) => line.push(content.clone()),
                 // The current cell doesn't have content for this line.
                 // Fill with a placeholder (empty spaces)
-                None => line.push(" ".repeat(info.width().into())),
+                None => line.push(blank.clone()),
             }
         }
         row_content.push(line);
@@ -260,22 +437,382 @@ pub fn format_row(
     row_content
 }
 
-/// Apply the alignment for a column. Alignment can be either Left/Right/Center.
+/// A line without any content, which fills the space of a cell below its content.
+///
+/// In columns with a [background](crate::Column::set_bg), the line is painted with the background
+/// of the cell or, if there's no cell, with the background of the column.
+#[allow(unused_variables)]
+fn blank_line(table: &Table, info: &ColumnDisplayInfo, cell: Option<&Cell>) -> String {
+    let line = " ".repeat(info.width().into());
+
+    #[cfg(feature = "tty")]
+    if info.background.is_some()
+        && table.should_style()
+        && let Some(bg) = cell.map_or(info.background, |cell| cell.bg)
+    {
+        if table.is_deterministic() {
+            return ansi::style(&line, None, Some(map_color(bg)), &[]);
+        }
+        return style(line).on(map_color(bg)).to_string();
+    }
+
+    line
+}
+
+/// Split the content of a single cell into lines, which fit into its column, and align them.
+///
+/// If the row's height is capped via `max_height`, surplus lines are cut off.
+fn format_cell(
+    cell: &Cell,
+    info: &ColumnDisplayInfo,
+    max_height: Option<usize>,
+    table: &Table,
+) -> Vec<String> {
+    // The padding of the cell overrides the padding of its column.
+    let cell_info;
+    let info = match cell.padding {
+        Some(padding) => {
+            cell_info = ColumnDisplayInfo {
+                padding,
+                content_width: info
+                    .width()
+                    .saturating_sub(padding.0.saturating_add(padding.1)),
+                ..info.clone()
+            };
+            &cell_info
+        }
+        None => info,
+    };
+
+    // Nested tables are rendered with the width that's available for the content of the cell.
+    let nested_cell;
+    let cell = match &cell.nested {
+        Some(nested) => {
+            nested_cell = Cell {
+                content: nested.render(Some(info.content_width)),
+                ..cell.clone()
+            };
+            &nested_cell
+        }
+        None => cell,
+    };
+
+    if cell.boxed && info.content_width > 4 {
+        return format_boxed_cell(cell, info, max_height, table);
+    }
+
+    // Cells in truncated columns are displayed on a single line.
+    if let Some(style) = &info.truncation {
+        let line = truncate_line(table, info, cell, style);
+        return vec![align_line(table, info, cell, line)];
+    }
+
+    // Cells in columns without wrapping only display their first line.
+    // Cut it off, if there's any more content.
+    if info.no_wrap {
+        let line = cut_off_line(table, info, cell);
+        return vec![align_line(table, info, cell, line)];
+    }
+
+    // Each cell is divided into several lines divided by newline
+    // Every line that's too long will be split into multiple lines
+    let mut cell_lines = Vec::new();
+
+    // The delimiter is configurable, determine which one should be used for this cell.
+    let delimiter = delimiter(cell, info, table);
+
+    // Iterate over each line and split it into multiple lines if necessary.
+    // Newlines added by the user will be preserved.
+    for line in cell.content.iter() {
+        if measure_text_width(line, &table.width_table) > info.content_width.into() {
+            let mut parts = split_line(line, info, delimiter, &table.width_table);
+            cell_lines.append(&mut parts);
+        } else {
+            cell_lines.push(line.into());
+        }
+    }
+
+    // Remove all unneeded lines of this cell, if the row's height is capped to a certain
+    // amount of lines and there're too many lines in this cell.
+    // This then truncates and inserts a '...' string at the end of the last line to indicate
+    // that the cell has been truncated.
+    if let Some(lines) = max_height
+        && cell_lines.len() > lines
+        && !cell.never_truncate
+    {
+        // We already have to many lines. Cut off the surplus lines.
+        let _ = cell_lines.split_off(lines);
+
+        // Directly access the last line.
+        let last_line = cell_lines
+            .get_mut(lines - 1)
+            .expect("We know it's this long.");
+
+        // Truncate any ansi codes, as the following cutoff might break ansi code
+        // otherwise anyway. This could be handled smarter, but it's simple and just works.
+        #[cfg(feature = "custom_styling")]
+        {
+            let stripped = console::strip_ansi_codes(last_line).to_string();
+            *last_line = stripped;
+        }
+
+        let max_width: usize = info.content_width.into();
+        let indicator_width = table.width_table.str_width(&table.truncation_indicator);
+
+        let mut truncate_at = 0;
+        // Start the accumulated_width with the indicator_width, which is the minimum width
+        // we may show anyway.
+        let mut accumulated_width = indicator_width;
+        let mut full_string_fits = false;
+
+        // Leave these print statements in here in case we ever have to debug this annoying
+        // stuff again.
+        //println!("\nSTART:");
+        //println!("\nMax width: {max_width}, Indicator width: {indicator_width}");
+        //println!("Full line hex: {last_line}");
+        //println!(
+        //    "Full line hex: {}",
+        //    last_line
+        //        .as_bytes()
+        //        .iter()
+        //        .map(|byte| format!("{byte:02x}"))
+        //        .collect::<Vec<String>>()
+        //        .join(", ")
+        //);
+
+        // Iterate through the UTF-8 graphemes.
+        // Check the `split_long_word` inline function docs to see why we're using
+        // graphemes.
+        // **Note:** The `index` here is the **byte** index. So we cannot just
+        //    String::truncate afterwards. We have to convert to a byte vector to perform
+        //    the truncation first.
+        let mut grapheme_iter = last_line.grapheme_indices(true).peekable();
+        while let Some((index, grapheme)) = grapheme_iter.next() {
+            // Leave these print statements in here in case we ever have to debug this
+            // annoying stuff again
+            //println!(
+            //    "Current index: {index}, Next grapheme: {grapheme} (width: {})",
+            //    grapheme.width()
+            //);
+            //println!(
+            //    "Next grapheme hex: {}",
+            //    grapheme
+            //        .as_bytes()
+            //        .iter()
+            //        .map(|byte| format!("{byte:02x}"))
+            //        .collect::<Vec<String>>()
+            //        .join(", ")
+            //);
+
+            // Immediately save where to truncate in case this grapheme doesn't fit.
+            // The index is just before the current grapheme actually starts.
+            truncate_at = index;
+            // Check if the next grapheme would break the boundary of the allowed line
+            // length.
+            let grapheme_width = table.width_table.grapheme_width(grapheme);
+            let new_width = accumulated_width + grapheme_width;
+            //println!(
+            //    "Next width: {new_width}/{max_width} ({accumulated_width} + {})",
+            //    grapheme.width()
+            //);
+            if new_width > max_width {
+                //println!(
+                //    "Breaking: {:?}",
+                //    accumulated_width + grapheme.width() > max_width
+                //);
+                break;
+            }
+
+            // The grapheme seems to fit. Save the index and check the next one.
+            accumulated_width += grapheme_width;
+
+            // This is a special case.
+            // We reached the last char, meaning that full last line + the indicator fit.
+            if grapheme_iter.peek().is_none() {
+                full_string_fits = true
+            }
+        }
+
+        // Only do any truncation logic if the line doesn't fit.
+        if !full_string_fits {
+            // Truncate the string at the byte index just behind the last valid grapheme
+            // and overwrite the last line with the new truncated string.
+            let mut last_line_bytes = last_line.clone().into_bytes();
+            last_line_bytes.truncate(truncate_at);
+            let new_last_line =
+                String::from_utf8(last_line_bytes).expect("We cut at an exact char boundary");
+            *last_line = new_last_line;
+        }
+
+        // Push the truncation indicator.
+        last_line.push_str(&table.truncation_indicator);
+    }
+
+    // Iterate over all generated lines of this cell and align them
+    cell_lines
+        .iter()
+        .map(|line| align_line(table, info, cell, line.to_string()))
+        .collect()
+}
+
+/// Format the content of a [boxed](Cell::set_boxed) cell and draw the box around it.
+/// The box is placed inside of the padding of the cell and has an inner padding of one space.
+fn format_boxed_cell(
+    cell: &Cell,
+    info: &ColumnDisplayInfo,
+    max_height: Option<usize>,
+    table: &Table,
+) -> Vec<String> {
+    let inner_info = ColumnDisplayInfo {
+        padding: (1, 1),
+        content_width: info.content_width - 4,
+        ..info.clone()
+    };
+    let inner_cell = Cell {
+        boxed: false,
+        padding: None,
+        ..cell.clone()
+    };
+    // The box takes up two lines of the row's height.
+    let max_height = max_height.map(|lines| lines.saturating_sub(2).max(1));
+    let lines = format_cell(&inner_cell, &inner_info, max_height, table);
+
+    let style = |component| table.box_style(component).to_string();
+    let horizontal = |component| style(component).repeat(inner_info.width().into());
+
+    let mut boxed = vec![
+        style(TableComponent::TopLeftCorner)
+            + &horizontal(TableComponent::TopBorder)
+            + &style(TableComponent::TopRightCorner),
+    ];
+    boxed.extend(lines.into_iter().map(|line| {
+        style(TableComponent::LeftBorder) + &line + &style(TableComponent::RightBorder)
+    }));
+    boxed.push(
+        style(TableComponent::BottomLeftCorner)
+            + &horizontal(TableComponent::BottomBorder)
+            + &style(TableComponent::BottomRightCorner),
+    );
+
+    boxed.iter().map(|line| pad_line(line, info)).collect()
+}
+
+/// Marks the point at which content of a cell in a [no-wrap](crate::Column::set_no_wrap) column
+/// has been cut off.
+pub const NO_WRAP_INDICATOR: &str = "▶";
+
+/// Get the first line of a cell and cut it off with the [NO_WRAP_INDICATOR], if the cell contains
+/// more content than fits into a single line of the column.
+fn cut_off_line(table: &Table, info: &ColumnDisplayInfo, cell: &Cell) -> String {
+    let Some(first_line) = cell.content.first() else {
+        return String::new();
+    };
+
+    let max_width = usize::from(info.content_width);
+    let fits = measure_text_width(first_line, &table.width_table) <= max_width;
+    if fits && cell.content.len() == 1 {
+        return first_line.clone();
+    }
+
+    // Any ansi codes are removed, as the cutoff might break them otherwise.
+    #[cfg(feature = "custom_styling")]
+    let first_line = &console::strip_ansi_codes(first_line).to_string();
+
+    let allowed_width = max_width.saturating_sub(NO_WRAP_INDICATOR.chars().count());
+    let mut line = String::new();
+    let mut width = 0;
+    for grapheme in first_line.graphemes(true) {
+        let grapheme_width = table.width_table.grapheme_width(grapheme);
+        if width + grapheme_width > allowed_width {
+            break;
+        }
+        width += grapheme_width;
+        line.push_str(grapheme);
+    }
+    line.push_str(NO_WRAP_INDICATOR);
+
+    line
+}
+
+/// Join all lines of a cell and cut off the part that doesn't fit into the column, as specified
+/// by the column's [TruncationStyle].
+fn truncate_line(
+    table: &Table,
+    info: &ColumnDisplayInfo,
+    cell: &Cell,
+    style: &TruncationStyle,
+) -> String {
+    let line = cell.content.join(" ");
+    let max_width = usize::from(info.content_width);
+    if measure_text_width(&line, &table.width_table) <= max_width {
+        return line;
+    }
+
+    // Any ansi codes are removed, as the cutoff might break them otherwise.
+    #[cfg(feature = "custom_styling")]
+    let line = console::strip_ansi_codes(&line).to_string();
+
+    let (TruncationStyle::End(indicator)
+    | TruncationStyle::Start(indicator)
+    | TruncationStyle::Middle(indicator)) = style;
+    let allowed_width = max_width.saturating_sub(table.width_table.str_width(indicator));
+    let graphemes: Vec<&str> = line.graphemes(true).collect();
+
+    let widths = &table.width_table;
+    match style {
+        TruncationStyle::End(_) => {
+            take_graphemes(graphemes.iter().copied(), allowed_width, widths).concat() + indicator
+        }
+        TruncationStyle::Start(_) => {
+            let mut end = take_graphemes(graphemes.iter().copied().rev(), allowed_width, widths);
+            end.reverse();
+            indicator.clone() + &end.concat()
+        }
+        TruncationStyle::Middle(_) => {
+            let start =
+                take_graphemes(graphemes.iter().copied(), allowed_width.div_ceil(2), widths);
+            let mut end =
+                take_graphemes(graphemes.iter().copied().rev(), allowed_width / 2, widths);
+            end.reverse();
+            start.concat() + indicator + &end.concat()
+        }
+    }
+}
+
+/// Take graphemes from the iterator as long as they fit into the given width.
+fn take_graphemes<'a>(
+    graphemes: impl Iterator<Item = &'a str>,
+    width: usize,
+    widths: &WidthTable,
+) -> Vec<&'a str> {
+    let mut taken = Vec::new();
+    let mut taken_width = 0;
+    for grapheme in graphemes {
+        taken_width += widths.grapheme_width(grapheme);
+        if taken_width > width {
+            break;
+        }
+        taken.push(grapheme);
+    }
+
+    taken
+}
+
+/// Apply the alignment for a column. Alignment can be either Left/Right/Center/Anchor.
 /// In every case all lines will be exactly the same character length `info.width - padding long`
 /// This is needed, so we can simply insert it into the border frame later on.
 /// Padding is applied in this function as well.
 #[allow(unused_variables)]
 fn align_line(table: &Table, info: &ColumnDisplayInfo, cell: &Cell, mut line: String) -> String {
-    let content_width = info.content_width;
-    let remaining: usize = usize::from(content_width).saturating_sub(measure_text_width(&line));
-
-    // Apply the styling before aligning the line, if the user requests it.
-    // That way non-delimiter whitespaces won't have stuff like underlines.
-    #[cfg(feature = "tty")]
-    if table.should_style() && table.style_text_only {
-        line = style_line(line, cell);
+    // Soft hyphens are only displayed, if a word is broken at their position.
+    if line.contains(SOFT_HYPHEN) {
+        line = line.replace(SOFT_HYPHEN, "");
     }
 
+    let content_width = info.content_width;
+    let remaining: usize =
+        usize::from(content_width).saturating_sub(measure_text_width(&line, &table.width_table));
+
     // Determine the alignment of the column cells.
     // Cell settings overwrite the columns Alignment settings.
     // Default is Left
@@ -287,6 +824,29 @@ fn align_line(table: &Table, info: &ColumnDisplayInfo, cell: &Cell, mut line: St
         CellAlignment::Left
     };
 
+    // Anchored lines are shifted, until their anchor is in line with the anchors of the column.
+    // Lines without an anchor end at the position of the anchor.
+    let anchor_shift = match alignment {
+        CellAlignment::Anchor(anchor) => {
+            let before = match line.find(anchor) {
+                Some(position) => &line[..position],
+                None => &line,
+            };
+            let before = measure_text_width(before, &table.width_table);
+            info.anchor_offset
+                .map_or(0, |offset| offset.saturating_sub(before))
+                .min(remaining)
+        }
+        _ => 0,
+    };
+
+    // Apply the styling before aligning the line, if the user requests it.
+    // That way non-delimiter whitespaces won't have stuff like underlines.
+    #[cfg(feature = "tty")]
+    if table.should_style() && table.style_text_only {
+        line = style_table_line(table, line, cell);
+    }
+
     // Apply left/right/both side padding depending on the alignment of the column
     match alignment {
         CellAlignment::Left => {
@@ -300,13 +860,16 @@ fn align_line(table: &Table, info: &ColumnDisplayInfo, cell: &Cell, mut line: St
             let right_padding = (remaining as f32 / 2f32).floor() as usize;
             line = " ".repeat(left_padding) + &line + &" ".repeat(right_padding);
         }
+        CellAlignment::Anchor(_) => {
+            line = " ".repeat(anchor_shift) + &line + &" ".repeat(remaining - anchor_shift);
+        }
     }
 
     line = pad_line(&line, info);
 
     #[cfg(feature = "tty")]
     if table.should_style() && !table.style_text_only {
-        return style_line(line, cell);
+        return style_table_line(table, line, cell);
     }
 
     line
@@ -323,8 +886,28 @@ fn pad_line(line: &str, info: &ColumnDisplayInfo) -> String {
     padded_line
 }
 
+/// Style a line of the given table with the colors and attributes of a cell.
+///
+/// The colors of [deterministic](Table::set_deterministic) tables don't depend on the
+/// environment, so they're written even if the `NO_COLOR` environment variable is set.
+#[cfg(feature = "tty")]
+fn style_table_line(table: &Table, line: String, cell: &Cell) -> String {
+    if !table.is_deterministic() {
+        return style_line(line, cell);
+    }
+
+    let attributes: Vec<_> = cell.attributes.iter().copied().map(map_attribute).collect();
+    ansi::style(
+        &line,
+        cell.fg.map(map_color),
+        cell.bg.map(map_color),
+        &attributes,
+    )
+}
+
+/// Style a line with the colors and attributes of a cell.
 #[cfg(feature = "tty")]
-fn style_line(line: String, cell: &Cell) -> String {
+pub(crate) fn style_line(line: String, cell: &Cell) -> String {
     // Just return the line, if there's no need to style.
     if cell.fg.is_none() && cell.bg.is_none() && cell.attributes.is_empty() {
         return line;
@@ -1,13 +1,14 @@
 use ansi_str::AnsiStr;
 use unicode_segmentation::UnicodeSegmentation;
-use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::style::WidthTable;
 
 const ANSI_RESET: &str = "\u{1b}[0m";
 
 /// Returns printed length of string, takes into account escape codes
 #[inline(always)]
-pub fn measure_text_width(s: &str) -> usize {
-    s.ansi_strip().width()
+pub fn measure_text_width(s: &str, widths: &WidthTable) -> usize {
+    widths.str_width(&s.ansi_strip())
 }
 
 /// Split the line by the given deliminator without breaking ansi codes that contain the delimiter
@@ -43,7 +44,7 @@ pub fn split_line_by_delimiter(line: &str, delimiter: char) -> Vec<String> {
 
 /// Splits a long word at a given character width. Inserting the needed ansi codes to preserve
 /// style.
-pub fn split_long_word(allowed_width: usize, word: &str) -> (String, String) {
+pub fn split_long_word(allowed_width: usize, word: &str, widths: &WidthTable) -> (String, String) {
     // A buffer for the first half of the split str, which will take up at most `allowed_len`
     // characters when printed to the terminal.
     let mut head = String::with_capacity(word.len());
@@ -75,7 +76,7 @@ pub fn split_long_word(allowed_width: usize, word: &str) -> (String, String) {
 
         let slice_len = match is_esc {
             true => 0,
-            false => str_slice.width(),
+            false => widths.str_width(str_slice),
         };
 
         if head_len + slice_len <= allowed_width {
@@ -91,7 +92,7 @@ pub fn split_long_word(allowed_width: usize, word: &str) -> (String, String) {
             assert!(!is_esc);
             let mut graphmes = str_slice.graphemes(true).peekable();
             while let Some(c) = graphmes.peek() {
-                let character_width = c.width();
+                let character_width = widths.grapheme_width(c);
                 if allowed_width < head_len + character_width {
                     break;
                 }
@@ -190,9 +191,10 @@ mod test {
         use unicode_width::UnicodeWidthStr;
 
         use super::measure_text_width;
+        use crate::style::WidthTable;
 
         let text = "\x1b]8;;https://github.com\x1b\\This is a link\x1b]8;;\x1b";
-        let width = measure_text_width(text);
+        let width = measure_text_width(text, &WidthTable::default());
 
         assert_eq!(text.width(), 41);
         assert_eq!(width, 14);
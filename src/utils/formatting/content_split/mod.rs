@@ -1,4 +1,7 @@
-use crate::utils::ColumnDisplayInfo;
+use crate::{
+    style::{WidthTable, WrapPolicy},
+    utils::ColumnDisplayInfo,
+};
 
 #[cfg(feature = "custom_styling")]
 mod custom_styling;
@@ -10,6 +13,12 @@ pub use custom_styling::*;
 #[cfg(not(feature = "custom_styling"))]
 pub use normal::*;
 
+/// The soft hyphen (U+00AD) marks a position, at which a word may be broken.
+///
+/// It isn't displayed, unless the word is broken at this position. In that case, a `-` is
+/// displayed at the end of the line instead.
+pub const SOFT_HYPHEN: char = '\u{AD}';
+
 /// Split a line if it's longer than the allowed columns (width - padding).
 ///
 /// This function tries to do this in a smart way, by splitting the content
@@ -20,9 +29,20 @@ pub use normal::*;
 /// This is repeated until there are no more "elements".
 ///
 /// Mid-element splits only occurs if an element doesn't fit in a single line by itself.
-pub fn split_line(line: &str, info: &ColumnDisplayInfo, delimiter: char) -> Vec<String> {
-    let mut lines = Vec::new();
+/// Where exactly such elements are split is controlled by the column's [WrapPolicy].
+/// Elements are preferably split at [soft hyphens](SOFT_HYPHEN).
+pub fn split_line(
+    line: &str,
+    info: &ColumnDisplayInfo,
+    delimiter: char,
+    widths: &WidthTable,
+) -> Vec<String> {
     let content_width = usize::from(info.content_width);
+    if info.wrap_policy == WrapPolicy::BreakAnywhere {
+        return split_anywhere(line, content_width, widths);
+    }
+
+    let mut lines = Vec::new();
 
     // Split the line by the given deliminator and turn the content into a stack.
     // Also clone it and convert it into a Vec<String>. Otherwise, we get some burrowing problems
@@ -34,8 +54,8 @@ pub fn split_line(line: &str, info: &ColumnDisplayInfo, delimiter: char) -> Vec<
 
     let mut current_line = String::new();
     while let Some(next) = elements.pop() {
-        let current_length = measure_text_width(&current_line);
-        let next_length = measure_text_width(&next);
+        let current_length = measure_text_width(&current_line, widths);
+        let next_length = measure_text_width(&next, widths);
 
         // Some helper variables
         // The length of the current line when combining it with the next element
@@ -60,7 +80,7 @@ pub fn split_line(line: &str, info: &ColumnDisplayInfo, delimiter: char) -> Vec<
             current_line += &next;
 
             // Already complete the current line, if there isn't space for more than two chars
-            current_line = check_if_full(&mut lines, content_width, current_line);
+            current_line = check_if_full(&mut lines, content_width, current_line, widths);
             continue;
         }
 
@@ -78,6 +98,20 @@ pub fn split_line(line: &str, info: &ColumnDisplayInfo, delimiter: char) -> Vec<
             continue;
         }
 
+        // Words are preferably broken at soft hyphens, if a part of them fits into the line.
+        if let Some((head, tail)) = split_at_soft_hyphen(remaining_width, &next, widths) {
+            if !current_line.is_empty() {
+                current_line.push(delimiter);
+            }
+            current_line += &head;
+            elements.push(tail);
+
+            lines.push(current_line);
+            current_line = String::new();
+
+            continue;
+        }
+
         // Ok. There's still enough space to fit something in (more than MIN_FREE_CHARS characters)
         // There are two scenarios:
         //
@@ -91,6 +125,15 @@ pub fn split_line(line: &str, info: &ColumnDisplayInfo, delimiter: char) -> Vec<
         // The element is longer than the specified content_width
         // Split the word, push the remaining string back on the stack
         if next_length > content_width {
+            // Words are only split on a fresh line, if they must not be broken.
+            if info.wrap_policy == WrapPolicy::NoBreak && !current_line.is_empty() {
+                elements.push(next);
+                lines.push(current_line);
+                current_line = String::new();
+
+                continue;
+            }
+
             let new_line = current_line.is_empty();
 
             // Only add delimiter, if we're not on a fresh line
@@ -98,7 +141,12 @@ pub fn split_line(line: &str, info: &ColumnDisplayInfo, delimiter: char) -> Vec<
                 current_line.push(delimiter);
             }
 
-            let (mut next, mut remaining) = split_long_word(remaining_width, &next);
+            let (mut next, mut remaining) = match info.wrap_policy {
+                WrapPolicy::BreakOn(characters) => {
+                    split_after_break(remaining_width, &next, characters, widths)
+                }
+                _ => split_long_word(remaining_width, &next, widths),
+            };
 
             // This is an ugly hack, but it's needed for now.
             //
@@ -130,7 +178,7 @@ pub fn split_line(line: &str, info: &ColumnDisplayInfo, delimiter: char) -> Vec<
         // Push the current line and initialize the next line with the element.
         lines.push(current_line);
         current_line = next.to_string();
-        current_line = check_if_full(&mut lines, content_width, current_line);
+        current_line = check_if_full(&mut lines, content_width, current_line, widths);
     }
 
     if !current_line.is_empty() {
@@ -140,6 +188,76 @@ pub fn split_line(line: &str, info: &ColumnDisplayInfo, delimiter: char) -> Vec<
     lines
 }
 
+/// Fill each line completely without paying attention to any delimiters.
+fn split_anywhere(line: &str, content_width: usize, widths: &WidthTable) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut remaining = line.to_string();
+    while measure_text_width(&remaining, widths) > content_width {
+        let (mut head, mut tail) = split_long_word(content_width, &remaining, widths);
+
+        // A multi-character wide symbol doesn't fit into the line.
+        // Put it into the line anyway, as there's no legitimate way to split it.
+        // See [split_line] for more details.
+        if head.is_empty() {
+            let mut chars = tail.chars();
+            head.extend(chars.next());
+            tail = chars.collect();
+        }
+
+        lines.push(head);
+        remaining = tail;
+    }
+
+    if !remaining.is_empty() || lines.is_empty() {
+        lines.push(remaining);
+    }
+
+    lines
+}
+
+/// Split a word at the last soft hyphen, at which the first part and a hyphen fit into the
+/// allowed width. The soft hyphen is replaced by a visible `-`.
+///
+/// Returns `None`, if there's no such soft hyphen.
+fn split_at_soft_hyphen(
+    allowed_width: usize,
+    word: &str,
+    widths: &WidthTable,
+) -> Option<(String, String)> {
+    word.char_indices()
+        .rev()
+        .filter(|(_, character)| *character == SOFT_HYPHEN)
+        .find_map(|(index, _)| {
+            let head = &word[..index];
+            let width = measure_text_width(head, widths);
+            (width > 0 && width < allowed_width).then(|| {
+                let tail = &word[index + SOFT_HYPHEN.len_utf8()..];
+                (format!("{head}-"), tail.to_string())
+            })
+        })
+}
+
+/// Split a long word after the last of the given characters that fits into the allowed width.
+/// The word is split at the allowed width, if none of the characters fits.
+fn split_after_break(
+    allowed_width: usize,
+    word: &str,
+    characters: &[char],
+    widths: &WidthTable,
+) -> (String, String) {
+    let (head, tail) = split_long_word(allowed_width, word, widths);
+    let Some((index, character)) = head
+        .char_indices()
+        .rev()
+        .find(|(_, character)| characters.contains(character))
+    else {
+        return (head, tail);
+    };
+
+    let (head, rest) = head.split_at(index + character.len_utf8());
+    (head.to_string(), rest.to_string() + &tail)
+}
+
 /// This is the minimum of available characters per line.
 /// It's used to check, whether another element can be added to the current line.
 /// Otherwise, the line will simply be left as it is, and we start with a new one.
@@ -150,9 +268,14 @@ const MIN_FREE_CHARS: usize = 2;
 /// Check if the current line is too long and whether we should start a new one
 /// If it's too long, we add the current line to the list of lines and return a new [String].
 /// Otherwise, we simply return the current line and basically don't do anything.
-fn check_if_full(lines: &mut Vec<String>, content_width: usize, current_line: String) -> String {
+fn check_if_full(
+    lines: &mut Vec<String>,
+    content_width: usize,
+    current_line: String,
+    widths: &WidthTable,
+) -> String {
     // Already complete the current line, if there isn't space for more than two chars
-    if measure_text_width(&current_line) > content_width.saturating_sub(MIN_FREE_CHARS) {
+    if measure_text_width(&current_line, widths) > content_width.saturating_sub(MIN_FREE_CHARS) {
         lines.push(current_line);
         return String::new();
     }
@@ -173,7 +296,7 @@ mod tests {
         assert_eq!(emoji.chars().count(), 4);
         assert_eq!(emoji.width(), 2);
 
-        let (word, remaining) = split_long_word(emoji.width(), emoji);
+        let (word, remaining) = split_long_word(emoji.width(), emoji, &WidthTable::default());
 
         assert_eq!(word, "\u{1F642}\u{200D}\u{2195}\u{FE0F}");
         assert_eq!(word.len(), 13);
@@ -0,0 +1,310 @@
+#[cfg(feature = "tty")]
+use crate::style::{Attribute, Color};
+use crate::{
+    Cell, Column, Table,
+    style::{CellAlignment, ColumnConstraint, Width},
+    utils::grid::{all_rows, header_groups, place_cells},
+};
+
+/// Render the table as a HTML `<table>`.
+///
+/// The header is placed in a `<thead>`, all rows in a `<tbody>` and the footer in a `<tfoot>`.
+/// Colors, attributes and alignments of cells are converted into inline styles.
+/// Columns with an [absolute](ColumnConstraint::Absolute) width get a `width` hint in a
+/// `<colgroup>`.\
+/// [Header groups](Table::set_header_groups) are placed in an additional row above the header,
+/// each of them gets its own `<colgroup>`.
+pub fn render_html(table: &Table) -> String {
+    let visible: Vec<&Column> = table
+        .columns
+        .iter()
+        .filter(|column| !column.is_hidden())
+        .collect();
+
+    let mut lines = vec!["<table>".to_string()];
+
+    // Each header group gets its own `<colgroup>`, columns after the last group share one.
+    let groups = column_groups(table, &visible);
+    let widths: Vec<Option<String>> = visible.iter().map(|column| width_hint(column)).collect();
+    if !groups.is_empty() || widths.iter().any(Option::is_some) {
+        let mut start = 0;
+        let mut ends: Vec<usize> = groups.iter().map(|(_, end)| *end).collect();
+        if ends.last() != Some(&visible.len()) {
+            ends.push(visible.len());
+        }
+        for end in ends {
+            lines.push("  <colgroup>".to_string());
+            for width in &widths[start..end] {
+                match width {
+                    Some(width) => lines.push(format!("    <col style=\"width: {width}\">")),
+                    None => lines.push("    <col>".to_string()),
+                }
+            }
+            lines.push("  </colgroup>".to_string());
+            start = end;
+        }
+    }
+
+    let rows = all_rows(table);
+    let header_rows = usize::from(table.header.is_some());
+    let body_end = header_rows + table.rows.len();
+    let section = |row_index: usize| {
+        if row_index < header_rows {
+            "thead"
+        } else if row_index < body_end {
+            "tbody"
+        } else {
+            "tfoot"
+        }
+    };
+
+    // The header groups are an additional row at the top of the `<thead>`.
+    if !groups.is_empty() {
+        lines.push("  <thead>".to_string());
+        lines.push("    <tr>".to_string());
+        let mut start = 0;
+        for (label, end) in groups.iter() {
+            lines.push(group_cell(label.map(escape), end - start));
+            start = *end;
+        }
+        if start < visible.len() {
+            lines.push(group_cell(None, visible.len() - start));
+        }
+        lines.push("    </tr>".to_string());
+        if header_rows == 0 {
+            lines.push("  </thead>".to_string());
+        }
+    }
+
+    for (row_index, slots) in place_cells(table).iter().enumerate() {
+        let is_header = row_index < header_rows;
+        let is_open = row_index == 0 && is_header && !groups.is_empty();
+        if (row_index == 0 || section(row_index - 1) != section(row_index)) && !is_open {
+            if row_index > 0 {
+                lines.push(format!("  </{}>", section(row_index - 1)));
+            }
+            lines.push(format!("  <{}>", section(row_index)));
+        }
+
+        lines.push("    <tr>".to_string());
+        // Cells that span multiple rows are only written in their first row.
+        for slot in slots.iter().filter(|slot| slot.row == row_index) {
+            let columns: Vec<&&Column> = visible
+                .iter()
+                .filter(|column| slot.columns.contains(&column.index))
+                .collect();
+            let Some(column) = columns.first() else {
+                continue;
+            };
+
+            let cell = &rows[slot.row].cells[slot.cell];
+            let tag = if is_header { "th" } else { "td" };
+            let mut attributes = String::new();
+            if columns.len() > 1 {
+                attributes += &format!(" colspan=\"{}\"", columns.len());
+            }
+            if slot.rows.len() > 1 {
+                attributes += &format!(" rowspan=\"{}\"", slot.rows.len());
+            }
+            let style = cell_style(cell, column);
+            if !style.is_empty() {
+                attributes += &format!(" style=\"{}\"", style.join("; "));
+            }
+
+            lines.push(format!("      <{tag}{attributes}>{}</{tag}>", escape(cell)));
+        }
+        lines.push("    </tr>".to_string());
+    }
+
+    if !rows.is_empty() {
+        lines.push(format!("  </{}>", section(rows.len() - 1)));
+    }
+    lines.push("</table>".to_string());
+
+    lines.join("\n")
+}
+
+/// The labels of all [header groups](Table::set_header_groups) and the index of the first visible
+/// column after each of them.
+///
+/// Groups without any visible columns are skipped.
+fn column_groups<'a>(table: &'a Table, visible: &[&Column]) -> Vec<(Option<&'a Cell>, usize)> {
+    let mut groups = Vec::new();
+    for (label, columns) in header_groups(table) {
+        let end = visible
+            .iter()
+            .take_while(|column| column.index < columns.end)
+            .count();
+        if groups.last().map_or(0, |(_, end)| *end) < end {
+            groups.push((Some(label), end));
+        }
+    }
+
+    groups
+}
+
+/// A cell of the header group row, which spans the given amount of columns.
+/// Columns after the last group get a cell without a label.
+fn group_cell(label: Option<String>, columns: usize) -> String {
+    let mut attributes = String::new();
+    if columns > 1 {
+        attributes += &format!(" colspan=\"{columns}\"");
+    }
+    if label.is_some() {
+        attributes += " scope=\"colgroup\"";
+    }
+
+    format!("      <th{attributes}>{}</th>", label.unwrap_or_default())
+}
+
+/// Get the CSS width of a column with an absolute width.
+fn width_hint(column: &Column) -> Option<String> {
+    match column.constraint {
+        Some(ColumnConstraint::Absolute(Width::Fixed(width))) => Some(format!("{width}ch")),
+        Some(ColumnConstraint::Absolute(Width::Percentage(percent))) => {
+            Some(format!("{}%", percent.min(100)))
+        }
+        _ => None,
+    }
+}
+
+/// Get the inline CSS properties of a cell.
+fn cell_style(cell: &Cell, column: &Column) -> Vec<String> {
+    let mut style = Vec::new();
+
+    let alignment = cell.alignment.or(column.cell_alignment);
+    match alignment {
+        Some(CellAlignment::Left) => style.push("text-align: left".to_string()),
+        Some(CellAlignment::Center) => style.push("text-align: center".to_string()),
+        Some(CellAlignment::Right | CellAlignment::Anchor(_)) => {
+            style.push("text-align: right".to_string())
+        }
+        None => (),
+    }
+
+    #[cfg(feature = "tty")]
+    {
+        if let Some(color) = cell.fg.and_then(css_color) {
+            style.push(format!("color: {color}"));
+        }
+        if let Some(color) = cell.bg.and_then(css_color) {
+            style.push(format!("background-color: {color}"));
+        }
+        style.extend(
+            cell.attributes
+                .iter()
+                .filter_map(|attribute| css_attribute(*attribute)),
+        );
+    }
+
+    style
+}
+
+/// Convert a color into a CSS color.
+/// [Color::Reset] has no equivalent and returns `None`.
+#[cfg(feature = "tty")]
+fn css_color(color: Color) -> Option<String> {
+    let name = match color {
+        Color::Reset => return None,
+        Color::Rgb { r, g, b } => return Some(format!("#{r:02x}{g:02x}{b:02x}")),
+        Color::AnsiValue(value) => return Some(ansi_value_to_css(value)),
+        Color::Black => "black",
+        Color::DarkGrey => "dimgray",
+        Color::Red => "red",
+        Color::DarkRed => "darkred",
+        Color::Green => "lime",
+        Color::DarkGreen => "green",
+        Color::Yellow => "yellow",
+        Color::DarkYellow => "olive",
+        Color::Blue => "blue",
+        Color::DarkBlue => "navy",
+        Color::Magenta => "fuchsia",
+        Color::DarkMagenta => "purple",
+        Color::Cyan => "aqua",
+        Color::DarkCyan => "teal",
+        Color::White => "white",
+        Color::Grey => "silver",
+    };
+
+    Some(name.to_string())
+}
+
+/// Convert a color of the 256 color ANSI palette into a CSS color.
+#[cfg(feature = "tty")]
+fn ansi_value_to_css(value: u8) -> String {
+    const BASE_COLORS: [Color; 16] = [
+        Color::Black,
+        Color::DarkRed,
+        Color::DarkGreen,
+        Color::DarkYellow,
+        Color::DarkBlue,
+        Color::DarkMagenta,
+        Color::DarkCyan,
+        Color::Grey,
+        Color::DarkGrey,
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+        Color::White,
+    ];
+
+    let (r, g, b) = match value {
+        0..=15 => return css_color(BASE_COLORS[usize::from(value)]).unwrap_or_default(),
+        // The 6x6x6 color cube.
+        16..=231 => {
+            let level = |index: u8| if index == 0 { 0 } else { 55 + index * 40 };
+            let index = value - 16;
+            (level(index / 36), level(index / 6 % 6), level(index % 6))
+        }
+        // The grayscale ramp.
+        232..=255 => {
+            let level = 8 + (value - 232) * 10;
+            (level, level, level)
+        }
+    };
+
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+/// Convert an attribute into a CSS property.
+/// Attributes without an equivalent return `None`.
+#[cfg(feature = "tty")]
+fn css_attribute(attribute: Attribute) -> Option<String> {
+    let property = match attribute {
+        Attribute::Bold => "font-weight: bold",
+        Attribute::Dim => "opacity: 0.5",
+        Attribute::Italic => "font-style: italic",
+        Attribute::Underlined => "text-decoration: underline",
+        Attribute::DoubleUnderlined => "text-decoration: underline double",
+        Attribute::Undercurled => "text-decoration: underline wavy",
+        Attribute::Underdotted => "text-decoration: underline dotted",
+        Attribute::Underdashed => "text-decoration: underline dashed",
+        Attribute::CrossedOut => "text-decoration: line-through",
+        Attribute::OverLined => "text-decoration: overline",
+        Attribute::Hidden => "visibility: hidden",
+        _ => return None,
+    };
+
+    Some(property.to_string())
+}
+
+/// Escape the content of a cell, so it can be placed inside of a HTML element.
+/// Lines are separated by `<br>`.
+fn escape(cell: &Cell) -> String {
+    cell.content
+        .iter()
+        .map(|line| {
+            #[cfg(feature = "custom_styling")]
+            let line = console::strip_ansi_codes(line);
+
+            line.replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;")
+                .replace('"', "&quot;")
+        })
+        .collect::<Vec<String>>()
+        .join("<br>")
+}
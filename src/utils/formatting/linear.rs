@@ -0,0 +1,66 @@
+use crate::{
+    Cell, Table,
+    utils::grid::{Slot, all_rows, place_cells},
+};
+
+/// Render each row of the table as a single line of labeled values.
+///
+/// Each value is labeled with the content of the header cell above it.
+/// Cells that span multiple rows are repeated on each of these rows, empty cells are skipped.
+/// The title is displayed above the rows, the footer and the caption below them.
+pub fn render_linear(table: &Table) -> String {
+    let rows = all_rows(table);
+    let grid = place_cells(table);
+    let header_rows = usize::from(table.header.is_some());
+
+    let label = |slot: &Slot| {
+        let header = grid.first().filter(|_| header_rows > 0)?;
+        let header_slot = header
+            .iter()
+            .find(|header_slot| header_slot.columns.contains(&slot.columns.start))?;
+        let label = plain_text(&rows[header_slot.row].cells[header_slot.cell]);
+        (!label.is_empty()).then_some(label)
+    };
+
+    let mut lines = Vec::new();
+    if let Some(title) = &table.title {
+        lines.push(plain_text(title));
+    }
+    for slots in grid.iter().skip(header_rows) {
+        let values: Vec<String> = slots
+            .iter()
+            .filter(|slot| !table.columns[slot.columns.start].is_hidden())
+            .filter_map(|slot| {
+                let value = plain_text(&rows[slot.row].cells[slot.cell]);
+                if value.is_empty() {
+                    return None;
+                }
+                Some(match label(slot) {
+                    Some(label) => format!("{label}: {value}"),
+                    None => value,
+                })
+            })
+            .collect();
+        lines.push(values.join("; "));
+    }
+    if let Some(caption) = &table.caption {
+        lines.push(plain_text(caption));
+    }
+
+    lines.join("\n")
+}
+
+/// The content of a cell on a single line and without any styling.
+fn plain_text(cell: &Cell) -> String {
+    let content = cell
+        .content
+        .iter()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<&str>>()
+        .join(" ");
+    #[cfg(feature = "custom_styling")]
+    let content = console::strip_ansi_codes(&content).to_string();
+
+    content
+}
@@ -0,0 +1,83 @@
+use crate::{
+    Cell, Table,
+    style::CellAlignment,
+    utils::grid::{all_rows, place_cells},
+};
+
+/// Render the table as a GitHub-flavored Markdown table.
+///
+/// Markdown tables always have a header, so an empty one is used, if the table has none.
+/// Cells that span multiple columns are followed by empty cells.
+pub fn render_markdown(table: &Table) -> String {
+    let visible: Vec<usize> = table
+        .columns
+        .iter()
+        .filter(|column| !column.is_hidden())
+        .map(|column| column.index)
+        .collect();
+
+    let rows = all_rows(table);
+    let mut lines: Vec<Vec<String>> = place_cells(table)
+        .iter()
+        .enumerate()
+        .map(|(row_index, slots)| {
+            visible
+                .iter()
+                .map(
+                    |column| match slots.iter().find(|slot| slot.columns.start == *column) {
+                        Some(slot) if slot.row == row_index => {
+                            escape(&rows[slot.row].cells[slot.cell])
+                        }
+                        _ => String::new(),
+                    },
+                )
+                .collect()
+        })
+        .collect();
+
+    if table.header.is_none() {
+        lines.insert(0, vec![String::new(); visible.len()]);
+    }
+
+    let alignments = visible
+        .iter()
+        .map(|index| match table.columns[*index].cell_alignment {
+            None => "---",
+            Some(CellAlignment::Left) => ":---",
+            Some(CellAlignment::Center) => ":---:",
+            Some(CellAlignment::Right | CellAlignment::Anchor(_)) => "---:",
+        })
+        .map(ToString::to_string)
+        .collect();
+    lines.insert(1, alignments);
+
+    lines
+        .iter()
+        .map(|line| {
+            let cells: Vec<String> = line
+                .iter()
+                .map(|cell| {
+                    if cell.is_empty() {
+                        " ".to_string()
+                    } else {
+                        format!(" {cell} ")
+                    }
+                })
+                .collect();
+            format!("|{}|", cells.join("|"))
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Escape the content of a cell, so it can be placed inside of a Markdown table.
+///
+/// Pipes are escaped and newlines are replaced by `<br>`, as Markdown table cells only span a
+/// single line.
+fn escape(cell: &Cell) -> String {
+    let content = cell.content.join("<br>");
+    #[cfg(feature = "custom_styling")]
+    let content = console::strip_ansi_codes(&content).to_string();
+
+    content.replace('|', "\\|")
+}
@@ -1,3 +1,10 @@
+#[cfg(feature = "tty")]
+pub mod ansi;
 pub mod borders;
 pub mod content_format;
 pub mod content_split;
+pub mod html;
+pub mod linear;
+pub mod markdown;
+pub mod numeric;
+pub mod sanitize;
@@ -0,0 +1,87 @@
+use crate::style::NumericFormat;
+
+/// A number, which has been formatted, split at its decimal point.
+pub struct FormattedNumber {
+    /// The sign and all digits before the decimal point.
+    pub integer: String,
+    /// The decimal point and all digits after it. Empty for integers.
+    pub fraction: String,
+}
+
+/// Format the given content with the given format, if it's a plain decimal number.
+///
+/// Numbers in scientific notation and any other content are ignored.
+pub fn format_number(content: &str, format: &NumericFormat) -> Option<FormattedNumber> {
+    let content = content.trim();
+    let unsigned = content.strip_prefix(['-', '+']).unwrap_or(content);
+    let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let is_digits = |part: &str| part.chars().all(|char| char.is_ascii_digit());
+    if integer.is_empty() || !is_digits(integer) || !is_digits(fraction) {
+        return None;
+    }
+
+    let sign = &content[..content.len() - unsigned.len()];
+    let rounded;
+    let (integer, fraction) = match format.precision() {
+        Some(precision) => {
+            let value: f64 = content.parse().ok()?;
+            rounded = format!("{:.*}", precision, value.abs());
+            rounded.split_once('.').unwrap_or((&rounded, ""))
+        }
+        None => (integer, fraction),
+    };
+
+    let integer = match format.thousands_separator() {
+        Some(separator) => group_thousands(integer, separator),
+        None => integer.to_string(),
+    };
+    let fraction = if fraction.is_empty() {
+        String::new()
+    } else {
+        format!(".{fraction}")
+    };
+
+    Some(FormattedNumber {
+        integer: format!("{sign}{integer}"),
+        fraction,
+    })
+}
+
+/// Insert the separator between each group of three digits, starting from the right.
+fn group_thousands(digits: &str, separator: char) -> String {
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            grouped.push(separator);
+        }
+        grouped.push(digit);
+    }
+
+    grouped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(content: &str, format: &NumericFormat) -> Option<String> {
+        format_number(content, format).map(|number| number.integer + &number.fraction)
+    }
+
+    #[test]
+    fn test_format_number() {
+        let mut rounded = NumericFormat::new();
+        rounded.set_precision(1).set_thousands_separator('_');
+
+        assert_eq!(
+            format("1234567.25", &NumericFormat::new()),
+            Some("1234567.25".into())
+        );
+        assert_eq!(format("1234567.25", &rounded), Some("1_234_567.2".into()));
+        assert_eq!(format("-999.96", &rounded), Some("-1_000.0".into()));
+        assert_eq!(format("+12", &rounded), Some("+12.0".into()));
+        assert_eq!(format("1e5", &rounded), None);
+        assert_eq!(format(".5", &rounded), None);
+        assert_eq!(format("n/a", &rounded), None);
+    }
+}
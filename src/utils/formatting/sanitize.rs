@@ -0,0 +1,58 @@
+/// Remove all terminal escape sequences and control characters from the given text.
+///
+/// This covers CSI sequences (e.g. `ESC[1m`), OSC sequences (e.g. hyperlinks or window titles),
+/// which are terminated by `BEL` or `ESC\`, and all other two-character escape sequences.
+/// Newlines and tabs are kept.
+pub fn strip_escape_sequences(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '\u{1b}' => match chars.next() {
+                // Control Sequence Introducer, terminated by a byte in the range `@` to `~`.
+                Some('[') => {
+                    for char in chars.by_ref() {
+                        if ('@'..='~').contains(&char) {
+                            break;
+                        }
+                    }
+                }
+                // Operating System Command, terminated by `BEL` or `ESC\`.
+                Some(']') => {
+                    while let Some(char) = chars.next() {
+                        if char == '\u{7}' {
+                            break;
+                        }
+                        if char == '\u{1b}' && chars.peek() == Some(&'\\') {
+                            chars.next();
+                            break;
+                        }
+                    }
+                }
+                _ => {}
+            },
+            '\n' | '\t' => stripped.push(char),
+            char if char.is_control() => {}
+            char => stripped.push(char),
+        }
+    }
+
+    stripped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_escape_sequences() {
+        assert_eq!(strip_escape_sequences("\u{1b}[1;31mred\u{1b}[0m"), "red");
+        assert_eq!(
+            strip_escape_sequences("\u{1b}]8;;https://example.com\u{1b}\\link\u{1b}]8;;\u{7}"),
+            "link"
+        );
+        assert_eq!(strip_escape_sequences("a\rb\u{8}c\u{9b}d"), "abcd");
+        assert_eq!(strip_escape_sequences("one\ttwo\nthree"), "one\ttwo\nthree");
+    }
+}
@@ -0,0 +1,284 @@
+use std::{ops::Range, sync::OnceLock};
+
+use super::{ColumnDisplayInfo, formatting::borders::should_draw_vertical_lines};
+use crate::{Cell, Row, Table};
+
+/// The position of a cell in the grid of a table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Slot {
+    /// The index of the row the cell belongs to.
+    /// The header has the index `0`, if it exists.
+    pub row: usize,
+    /// The index of the cell in its row.
+    pub cell: usize,
+    /// The columns the cell spans.
+    pub columns: Range<usize>,
+    /// The rows the cell spans.
+    pub rows: Range<usize>,
+}
+
+/// The placed cells of a table, which are computed once to look up cells by their position.
+///
+/// The cache has to be [reset](GridCache::reset), whenever the cells of the table may change.
+/// Clones start out empty, as copies of a table are usually changed right away.
+#[derive(Debug, Default)]
+pub struct GridCache(OnceLock<Option<Vec<Vec<Slot>>>>);
+
+impl Clone for GridCache {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl GridCache {
+    /// The [placed cells](place_cells) of the table or `None`, if the table doesn't have any spans.
+    pub fn get(&self, table: &Table) -> Option<&Vec<Vec<Slot>>> {
+        self.0
+            .get_or_init(|| has_spans(table).then(|| place_cells(table)))
+            .as_ref()
+    }
+
+    /// Drop the placed cells, so they're placed again on the next lookup.
+    pub fn reset(&mut self) {
+        self.0.take();
+    }
+}
+
+/// All rows of the table, starting with the header and ending with the footer, if they exist.
+pub fn all_rows(table: &Table) -> Vec<&Row> {
+    table
+        .header
+        .iter()
+        .chain(table.rows.iter())
+        .chain(table.footer.iter())
+        .collect()
+}
+
+/// Returns whether any cell of the table spans multiple columns or rows.
+pub fn has_spans(table: &Table) -> bool {
+    all_rows(table)
+        .iter()
+        .flat_map(|row| row.cells.iter())
+        .any(|cell| cell.colspan > 1 || cell.rowspan > 1)
+}
+
+/// Place all cells of the table in a grid of rows and columns.
+///
+/// Cells can [span multiple columns](Cell::set_colspan) or [rows](Cell::set_rowspan).
+/// Columns that are occupied by a cell of a previous row are skipped when placing the cells of
+/// a row. Spans are cut off at the end of the table and at cells of previous rows.
+///
+/// Returns the slots of each row ordered by column, starting with the header.
+/// A cell, which spans multiple rows, is part of each of these rows.
+pub fn place_cells(table: &Table) -> Vec<Vec<Slot>> {
+    let column_count = table.columns.len();
+    let rows = all_rows(table);
+    let header_rows = usize::from(table.header.is_some());
+    let body_end = header_rows + table.rows.len();
+
+    let mut grid: Vec<Vec<Slot>> = vec![Vec::new(); rows.len()];
+    // The index of the first row, which is no longer occupied by a previous cell in each column.
+    let mut occupied_until = vec![0; column_count];
+    for (row_index, row) in rows.iter().enumerate() {
+        let is_occupied =
+            |occupied_until: &[usize], column: usize| occupied_until[column] > row_index;
+
+        let mut column = 0;
+        for (cell_index, cell) in row.cells.iter().enumerate() {
+            while column < column_count && is_occupied(&occupied_until, column) {
+                column += 1;
+            }
+            if column >= column_count {
+                break;
+            }
+
+            let mut end_column = column + 1;
+            while end_column < (column + cell.colspan).min(column_count)
+                && !is_occupied(&occupied_until, end_column)
+            {
+                end_column += 1;
+            }
+
+            // Cells of the header cannot span into the rows of the table and cells of the rows
+            // cannot span into the footer.
+            let last_row = if row_index < header_rows {
+                header_rows
+            } else if row_index < body_end {
+                body_end
+            } else {
+                rows.len()
+            };
+            let end_row = (row_index + cell.rowspan).min(last_row);
+
+            let slot = Slot {
+                row: row_index,
+                cell: cell_index,
+                columns: column..end_column,
+                rows: row_index..end_row,
+            };
+            for occupied in &mut occupied_until[slot.columns.clone()] {
+                *occupied = end_row;
+            }
+            for spanned_row in slot.rows.clone() {
+                grid[spanned_row].push(slot.clone());
+            }
+
+            column = end_column;
+        }
+    }
+
+    for row in grid.iter_mut() {
+        row.sort_by_key(|slot| slot.columns.start);
+    }
+
+    grid
+}
+
+/// The [header groups](Table::set_header_groups) of the table and the columns they span.
+///
+/// Groups are cut off at the end of the table. Groups that don't span any column are skipped.
+pub fn header_groups(table: &Table) -> Vec<(&Cell, Range<usize>)> {
+    let column_count = table.columns.len();
+    let mut start = 0;
+    let mut groups = Vec::new();
+    for (cell, columns) in table.header_groups.iter() {
+        let end = (start + columns).min(column_count);
+        if end > start {
+            groups.push((cell, start..end));
+        }
+        start = end;
+    }
+
+    groups
+}
+
+/// Get all cells of the header, the rows and the footer, which are placed in exactly this column.
+///
+/// Cells that span multiple columns aren't included.
+pub fn column_cells(table: &Table, column: usize) -> Vec<&Cell> {
+    if !has_spans(table) {
+        return table
+            .column_cells_with_header_iter(column)
+            .chain(table.footer.iter().map(|footer| footer.cells.get(column)))
+            .flatten()
+            .collect();
+    }
+
+    placed_column_cells(table, column, 0..all_rows(table).len())
+}
+
+/// Same as [column_cells], but only the cells of the rows are included.
+/// The header and the footer are skipped.
+pub fn body_column_cells(table: &Table, column: usize) -> Vec<&Cell> {
+    if !has_spans(table) {
+        return table.column_cells_iter(column).flatten().collect();
+    }
+
+    let header_rows = usize::from(table.header.is_some());
+    placed_column_cells(table, column, header_rows..header_rows + table.rows.len())
+}
+
+/// Get the cells, which start in the given rows and are placed in exactly this column.
+fn placed_column_cells(table: &Table, column: usize, rows: Range<usize>) -> Vec<&Cell> {
+    let all_rows = all_rows(table);
+    place_cells(table)
+        .iter()
+        .enumerate()
+        .filter(|(row_index, _)| rows.contains(row_index))
+        .flat_map(|(row_index, slots)| {
+            slots
+                .iter()
+                .filter(move |slot| slot.row == row_index && slot.columns == (column..column + 1))
+        })
+        .map(|slot| &all_rows[slot.row].cells[slot.cell])
+        .collect()
+}
+
+/// Get the display info of a cell, that spans the given columns.
+///
+/// The visible columns are merged into a single column, which also covers the vertical lines
+/// between them. The settings of the first visible column are used.
+/// Returns `None`, if all columns are hidden.
+pub fn merged_info(
+    table: &Table,
+    display_info: &[ColumnDisplayInfo],
+    columns: Range<usize>,
+) -> Option<ColumnDisplayInfo> {
+    let visible: Vec<&ColumnDisplayInfo> = display_info
+        .get(columns)?
+        .iter()
+        .filter(|info| !info.is_hidden)
+        .collect();
+    let first = visible.first()?;
+    let last = visible.last()?;
+    if visible.len() == 1 {
+        return Some((*first).clone());
+    }
+
+    let vertical_lines = if should_draw_vertical_lines(table) {
+        visible.len() - 1
+    } else {
+        0
+    };
+    let width = visible
+        .iter()
+        .map(|info| usize::from(info.width()))
+        .sum::<usize>()
+        + vertical_lines;
+    let padding = (first.padding.0, last.padding.1);
+    let content_width = width
+        .saturating_sub(usize::from(padding.0) + usize::from(padding.1))
+        .try_into()
+        .unwrap_or(u16::MAX);
+
+    Some(ColumnDisplayInfo {
+        padding,
+        delimiter: first.delimiter,
+        content_width,
+        cell_alignment: first.cell_alignment,
+        no_wrap: first.no_wrap,
+        wrap_policy: first.wrap_policy,
+        truncation: first.truncation.clone(),
+        #[cfg(feature = "tty")]
+        background: first.background,
+        anchor_offset: None,
+        is_hidden: false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn place_spanning_cells() {
+        let mut table = Table::new();
+        table
+            .add_row(vec![
+                Cell::new("a").set_rowspan(2),
+                Cell::new("b").set_colspan(2),
+            ])
+            .add_row(vec!["c", "d", "e"]);
+
+        let grid = place_cells(&table);
+        let positions: Vec<Vec<(usize, usize, Range<usize>)>> = grid
+            .iter()
+            .map(|slots| {
+                slots
+                    .iter()
+                    .map(|slot| (slot.row, slot.cell, slot.columns.clone()))
+                    .collect()
+            })
+            .collect();
+
+        // The cells of the second row skip the first column, which is occupied by `a`.
+        // `e` doesn't fit into the table anymore.
+        assert_eq!(
+            positions,
+            vec![
+                vec![(0, 0, 0..1), (0, 1, 1..3)],
+                vec![(0, 0, 0..1), (1, 0, 1..2), (1, 1, 2..3)],
+            ]
+        );
+    }
+}
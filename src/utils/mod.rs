@@ -1,12 +1,48 @@
 pub mod arrangement;
+#[cfg(feature = "csv")]
+pub mod csv;
 pub mod formatting;
+pub mod grid;
+
+use std::{
+    borrow::Cow,
+    ops::Range,
+    time::{Duration, Instant},
+};
 
 use arrangement::arrange_content;
-use formatting::{borders::draw_borders, content_format::format_content};
+#[cfg(feature = "tty")]
+use formatting::content_format::DETAILS_INDENT;
+use formatting::{
+    borders::{
+        draw_borders, draw_continuation_border, line_owners, should_draw_bottom_border,
+        should_draw_left_border, should_draw_top_border, should_draw_vertical_lines,
+    },
+    content_format::{format_banner, format_content, format_details},
+    content_split::measure_text_width,
+    html::render_html,
+    linear::render_linear,
+    markdown::render_markdown,
+    numeric::{FormattedNumber, format_number},
+    sanitize::strip_escape_sequences,
+};
+#[cfg(feature = "tty")]
+use unicode_segmentation::UnicodeSegmentation;
 
 use crate::{
-    Column, Table,
-    style::{CellAlignment, ColumnConstraint},
+    ArrangedColumn, Cell, Column, Layout, RenderedTable, Row, Table,
+    error::{CellOverflow, ColumnOverflow, LayoutError, StyleWarning, WidthMismatch},
+    layout::LineOwner,
+    partial::{PartialRender, RenderContinuation},
+    style::{
+        BorderCollision, CellAlignment, ColumnConstraint, NumericFormat, TruncationStyle,
+        WrapPolicy,
+    },
+};
+#[cfg(feature = "tty")]
+use crate::{
+    Color,
+    style::{StyleSpan, WidthTable},
 };
 
 /// This struct is ONLY used when table.to_string() is called.
@@ -15,7 +51,7 @@ use crate::{
 ///
 /// The idea is to have a place for all this intermediate stuff, without
 /// actually touching the Column struct.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ColumnDisplayInfo {
     pub padding: (u16, u16),
     pub delimiter: Option<char>,
@@ -23,6 +59,17 @@ pub struct ColumnDisplayInfo {
     pub content_width: u16,
     /// The content alignment of cells in this column
     pub cell_alignment: Option<CellAlignment>,
+    /// Whether content should be kept on a single line instead of being wrapped.
+    pub no_wrap: bool,
+    /// Where content may be split, if it doesn't fit into a line.
+    pub wrap_policy: WrapPolicy,
+    /// How content is cut off instead of being wrapped.
+    pub truncation: Option<TruncationStyle>,
+    /// The background color, which is painted through all rows of this column.
+    #[cfg(feature = "tty")]
+    pub background: Option<Color>,
+    /// The width of the content in front of the anchor of [anchored](CellAlignment::Anchor) cells.
+    pub anchor_offset: Option<usize>,
     is_hidden: bool,
 }
 
@@ -37,6 +84,12 @@ impl ColumnDisplayInfo {
             delimiter: column.delimiter,
             content_width,
             cell_alignment: column.cell_alignment,
+            no_wrap: column.no_wrap,
+            wrap_policy: column.wrap_policy,
+            truncation: column.truncation.clone(),
+            #[cfg(feature = "tty")]
+            background: column.bg,
+            anchor_offset: None,
             is_hidden: matches!(column.constraint, Some(ColumnConstraint::Hidden)),
         }
     }
@@ -49,7 +102,1229 @@ impl ColumnDisplayInfo {
 }
 
 pub fn build_table(table: &Table) -> impl Iterator<Item = String> {
+    render(table, false)
+        .expect("Layout is only validated in strict mode")
+        .into_iter()
+}
+
+/// Same as [build_table], but the arranged layout is validated first, if the table is in strict
+/// mode.
+pub fn try_build_table(table: &Table) -> Result<impl Iterator<Item = String>, LayoutError> {
+    Ok(render(table, table.is_strict())?.into_iter())
+}
+
+/// The arranged and formatted content of a table, which is ready to be drawn.
+struct Formatted<'a> {
+    /// The table that's actually drawn.
+    /// This differs from the original table, if the details column has been hidden.
+    grid: Cow<'a, Table>,
+    content: Vec<Vec<Vec<String>>>,
+    details: Vec<Vec<String>>,
+    display_info: Vec<ColumnDisplayInfo>,
+}
+
+fn render(table: &Table, strict: bool) -> Result<Vec<String>, LayoutError> {
+    let formatted = format(table, strict)?;
+
+    Ok(draw_borders(
+        &formatted.grid,
+        &formatted.content,
+        &formatted.details,
+        &formatted.display_info,
+    ))
+}
+
+fn format(table: &Table, strict: bool) -> Result<Formatted<'_>, LayoutError> {
+    let table = with_decorations(table);
+    let mut display_info = arrange_content(&table);
+
+    // The details column doesn't fit into the table.
+    // Hide it and arrange the remaining columns again, its content is then displayed below each
+    // row instead.
+    if let Some(index) = overflowing_details_column(&table, &display_info) {
+        let mut grid = table.clone().into_owned();
+        grid.columns[index].constraint = Some(ColumnConstraint::Hidden);
+        display_info = arrange_content(&grid);
+        if strict {
+            check_minimum_width(&grid, &display_info)?;
+            check_never_truncate(&grid, &display_info)?;
+        }
+
+        let content = format_content(&grid, &display_info);
+        let details = format_details(&table, index, &display_info);
+        return Ok(Formatted {
+            grid: Cow::Owned(grid),
+            content,
+            details,
+            display_info,
+        });
+    }
+
+    if strict {
+        check_minimum_width(&table, &display_info)?;
+        check_never_truncate(&table, &display_info)?;
+    }
+    let content = format_content(&table, &display_info);
+    Ok(Formatted {
+        grid: table,
+        content,
+        details: Vec::new(),
+        display_info,
+    })
+}
+
+/// Determine the max content width of each column just like it's done when rendering the table.
+pub fn measure_content(table: &Table) -> Vec<u16> {
+    with_decorations(table).column_max_content_widths()
+}
+
+/// Apply all settings, which are stored outside of the cells but change their content or styling,
+/// to the cells themselves.
+///
+/// The table is only cloned, if there's anything to apply.
+fn with_decorations(table: &Table) -> Cow<'_, Table> {
+    let table = with_translated_header(table);
+    let table = with_header_icons(table);
+    let table = with_numeric_formats(table);
+    let table = with_border_collisions(table);
+    #[cfg(feature = "tty")]
+    let table = with_validation_styles(table);
+    #[cfg(feature = "tty")]
+    let table = with_row_styles(table);
+    #[cfg(feature = "tty")]
+    let table = with_column_backgrounds(table);
+
+    table
+}
+
+/// Display all cells in red, whose content is rejected by the
+/// [validator of their column](Column::set_validator).
+///
+/// Cells with their own foreground color keep it.
+#[cfg(feature = "tty")]
+fn with_validation_styles(table: Cow<'_, Table>) -> Cow<'_, Table> {
+    let invalid = table.invalid_cells();
+    if invalid.is_empty() {
+        return table;
+    }
+
+    let mut table = table;
+    let decorated = table.to_mut();
+    for (row, cell, _) in invalid {
+        let cell = &mut decorated.rows[row].cells[cell];
+        cell.fg = cell.fg.or(Some(Color::Red));
+    }
+
+    table
+}
+
+/// Apply the [styling of rows](Row::set_fg) and the
+/// [zebra striping](Table::set_zebra_striping) to all cells of the affected rows.
+///
+/// Styling of the cells themselves takes precedence.
+#[cfg(feature = "tty")]
+fn with_row_styles(table: Cow<'_, Table>) -> Cow<'_, Table> {
+    let rows_styled = grid::all_rows(&table).into_iter().any(Row::is_styled);
+    let theme = table.theme.as_ref().filter(|theme| {
+        theme.header_fg().is_some()
+            || theme.header_bg().is_some()
+            || !theme.header_attributes().is_empty()
+    });
+    if !rows_styled && table.zebra_striping.is_none() && theme.is_none() {
+        return table;
+    }
+
+    // The header is styled by the theme, unless the header row has its own styling.
+    let theme = theme.cloned();
+    let mut table = table;
+    let decorated = table.to_mut();
+    if let (Some(theme), Some(header)) = (theme, decorated.header.as_mut()) {
+        header.fg = header.fg.or(theme.header_fg());
+        header.bg = header.bg.or(theme.header_bg());
+        header
+            .attributes
+            .splice(0..0, theme.header_attributes().iter().copied());
+    }
+    for row in decorated
+        .header
+        .iter_mut()
+        .chain(decorated.footer.iter_mut())
+    {
+        apply_row_style(row, None);
+    }
+    let zebra_striping = decorated.zebra_striping;
+    for (index, row) in decorated.rows.iter_mut().enumerate() {
+        apply_row_style(row, zebra_stripe(zebra_striping, index));
+    }
+
+    table
+}
+
+/// Paint the background of all cells with the [background of their column](Column::set_bg).
+///
+/// Cells that span multiple columns get the background of their first column.
+/// All other background colors take precedence.
+#[cfg(feature = "tty")]
+fn with_column_backgrounds(table: Cow<'_, Table>) -> Cow<'_, Table> {
+    if table.columns.iter().all(|column| column.bg.is_none()) {
+        return table;
+    }
+
+    let grid = grid::place_cells(&table);
+    let mut table = table;
+    let decorated = table.to_mut();
+    let rows = decorated
+        .header
+        .iter_mut()
+        .chain(decorated.rows.iter_mut())
+        .chain(decorated.footer.iter_mut());
+    for (row_index, (row, slots)) in rows.zip(grid).enumerate() {
+        for slot in slots.iter().filter(|slot| slot.row == row_index) {
+            let cell = &mut row.cells[slot.cell];
+            cell.bg = cell.bg.or(decorated.columns[slot.columns.start].bg);
+        }
+    }
+
+    table
+}
+
+/// The background color of the row with the given index, if the table is striped.
+#[cfg(feature = "tty")]
+fn zebra_stripe(zebra_striping: Option<(Color, Color)>, index: usize) -> Option<Color> {
+    zebra_striping.map(|(even, odd)| if index.is_multiple_of(2) { even } else { odd })
+}
+
+/// Apply the styling of a row and its stripe color to all of its cells.
+#[cfg(feature = "tty")]
+fn apply_row_style(row: &mut Row, stripe: Option<Color>) {
+    let bg = row.bg.or(stripe);
+    for cell in row.cells.iter_mut() {
+        cell.fg = cell.fg.or(row.fg);
+        cell.bg = cell.bg.or(bg);
+        cell.attributes = row
+            .attributes
+            .iter()
+            .chain(cell.attributes.iter())
+            .copied()
+            .collect();
+    }
+}
+
+/// Replace the content of all header cells by its
+/// [translation](Table::set_header_translator).
+fn with_translated_header(table: &Table) -> Cow<'_, Table> {
+    let (Some(translator), Some(_)) = (&table.header_translator, &table.header) else {
+        return Cow::Borrowed(table);
+    };
+
+    let translator = translator.clone();
+    let mut decorated = table.clone();
+    if let Some(header) = decorated.header.as_mut() {
+        for cell in header.cells.iter_mut() {
+            let translated = strip_escape_sequences(&translator.translate(&cell.content()));
+            cell.content = translated.split('\n').map(ToString::to_string).collect();
+        }
+    }
+
+    Cow::Owned(decorated)
+}
+
+/// Prepend the [header icons](Column::set_header_icon) of all columns to their header.
+///
+/// The table is only cloned, if there are any icons.
+fn with_header_icons(table: Cow<'_, Table>) -> Cow<'_, Table> {
+    let icons: Vec<(usize, String)> = table
+        .columns
+        .iter()
+        .filter_map(|column| {
+            let (icon, fallback) = column.header_icon.as_ref()?;
+            let icon = if table.ascii_icons { fallback } else { icon };
+            Some((column.index, icon.clone()))
+        })
+        .collect();
+    if icons.is_empty() || table.header.is_none() {
+        return table;
+    }
+
+    // Header cells that span multiple columns don't belong to any single column.
+    let slots = grid::place_cells(&table).swap_remove(0);
+    let mut table = table;
+    if let Some(header) = table.to_mut().header.as_mut() {
+        for (index, icon) in icons {
+            let Some(slot) = slots.iter().find(|slot| slot.columns == (index..index + 1)) else {
+                continue;
+            };
+
+            let cell = &mut header.cells[slot.cell];
+            match cell.content.first_mut() {
+                Some(line) => *line = format!("{icon} {line}"),
+                None => cell.content.push(icon),
+            }
+        }
+    }
+
+    table
+}
+
+/// Escape or substitute all characters in the header, the rows and the footer, which are also
+/// used to draw the borders of the table, see [Table::set_border_collision].
+fn with_border_collisions(table: Cow<'_, Table>) -> Cow<'_, Table> {
+    let collision = table.border_collision;
+    let characters = table.border_characters();
+    let collides = |character: char| match collision {
+        BorderCollision::Keep => false,
+        BorderCollision::Escape => character == '\\' || characters.contains(&character),
+        BorderCollision::Substitute(_) => characters.contains(&character),
+    };
+    let has_collisions = grid::all_rows(&table)
+        .iter()
+        .flat_map(|row| row.cells.iter())
+        .flat_map(|cell| cell.content.iter())
+        .any(|line| line.chars().any(collides));
+    if !has_collisions {
+        return table;
+    }
+
+    let mut table = table.into_owned();
+    let rows = table
+        .header
+        .iter_mut()
+        .chain(table.rows.iter_mut())
+        .chain(table.footer.iter_mut());
+    for line in rows
+        .flat_map(|row| row.cells.iter_mut())
+        .flat_map(|cell| cell.content.iter_mut())
+    {
+        let mut resolved = String::with_capacity(line.len());
+        for character in line.chars() {
+            match collision {
+                BorderCollision::Escape if collides(character) => {
+                    resolved.push('\\');
+                    resolved.push(character);
+                }
+                BorderCollision::Substitute(substitute) if collides(character) => {
+                    resolved.push(substitute);
+                }
+                _ => resolved.push(character),
+            }
+        }
+        *line = resolved;
+    }
+
+    Cow::Owned(table)
+}
+
+/// Apply the [numeric format](Column::set_numeric_format) of all columns to the numbers in
+/// their cells, including the footer.
+///
+/// Numbers are [anchored](CellAlignment::Anchor) at their decimal point, which lines them up
+/// regardless of their precision.
+fn with_numeric_formats(table: Cow<'_, Table>) -> Cow<'_, Table> {
+    let formats: Vec<(usize, NumericFormat)> = table
+        .columns
+        .iter()
+        .filter_map(|column| Some((column.index, column.numeric_format.clone()?)))
+        .collect();
+    if formats.is_empty() {
+        return table;
+    }
+
+    // Cells that span multiple columns don't belong to any single column.
+    let grid = grid::place_cells(&table);
+    let header_rows = usize::from(table.header.is_some());
+    let mut table = table;
+    let decorated = table.to_mut();
+    let rows = decorated
+        .header
+        .iter_mut()
+        .chain(decorated.rows.iter_mut())
+        .chain(decorated.footer.iter_mut());
+    for (row_index, (row, slots)) in rows.zip(grid).enumerate().skip(header_rows) {
+        for slot in slots.iter().filter(|slot| slot.row == row_index) {
+            let Some((_, format)) = formats
+                .iter()
+                .find(|(index, _)| slot.columns == (*index..*index + 1))
+            else {
+                continue;
+            };
+
+            let cell = &mut row.cells[slot.cell];
+            if cell.content.len() != 1 {
+                continue;
+            }
+            let Some(FormattedNumber { integer, fraction }) =
+                format_number(&cell.content[0], format)
+            else {
+                continue;
+            };
+            cell.content[0] = integer + &fraction;
+            cell.alignment = cell.alignment.or(Some(CellAlignment::Anchor('.')));
+        }
+    }
+
+    table
+}
+
+/// Render the table as a GitHub-flavored Markdown table.
+pub fn build_markdown(table: &Table) -> String {
+    render_markdown(&with_decorations(table))
+}
+
+/// Render each row of the table as a single line of labeled values.
+pub fn build_linear(table: &Table) -> String {
+    render_linear(&with_decorations(table))
+}
+
+/// Render the table as a HTML table.
+pub fn build_html(table: &Table) -> String {
+    render_html(&with_decorations(table))
+}
+
+/// Determine the positions of all cells in the rendered table.
+pub fn build_layout(table: &Table) -> Layout {
+    let formatted = format(table, false).expect("Layout is only validated in strict mode");
+    let grid = &formatted.grid;
+
+    Layout {
+        lines: line_owners(
+            grid,
+            &formatted.content,
+            &formatted.details,
+            &formatted.display_info,
+        ),
+        columns: column_ranges(grid, &formatted.display_info),
+    }
+}
+
+/// Arrange the columns of the table the same way they're arranged when rendering it.
+pub fn build_arranged_columns(table: &Table) -> Vec<ArrangedColumn> {
+    let table = &*with_decorations(table);
+    let mut display_info = arrange_content(table);
+
+    // The details column is hidden, if it's displayed below the rows.
+    if let Some(index) = overflowing_details_column(table, &display_info) {
+        let mut grid = table.clone();
+        grid.columns[index].constraint = Some(ColumnConstraint::Hidden);
+        display_info = arrange_content(&grid);
+    }
+
+    display_info
+        .iter()
+        .enumerate()
+        .map(|(index, info)| ArrangedColumn {
+            index,
+            padding: info.padding,
+            content_width: info.content_width,
+            is_hidden: info.is_hidden,
+        })
+        .collect()
+}
+
+/// Render the table and collect the final column widths and the positions of all cells.
+pub fn build_rendered(table: &Table) -> RenderedTable {
+    let formatted = format(table, false).expect("Layout is only validated in strict mode");
+    let grid = &formatted.grid;
+    let display_info = &formatted.display_info;
+
+    RenderedTable {
+        lines: draw_borders(grid, &formatted.content, &formatted.details, display_info),
+        column_widths: display_info
+            .iter()
+            .map(|info| if info.is_hidden { 0 } else { info.width() })
+            .collect(),
+        layout: Layout {
+            lines: line_owners(grid, &formatted.content, &formatted.details, display_info),
+            columns: column_ranges(grid, display_info),
+        },
+    }
+}
+
+/// The index and the horizontal range of each visible column in the rendered table.
+fn column_ranges(table: &Table, display_info: &[ColumnDisplayInfo]) -> Vec<(usize, Range<usize>)> {
+    // Each border and vertical line is exactly one character wide.
+    let vertical_lines = usize::from(should_draw_vertical_lines(table));
+    let mut position = usize::from(should_draw_left_border(table));
+    let mut columns = Vec::new();
+    for (index, info) in display_info.iter().enumerate() {
+        if info.is_hidden {
+            continue;
+        }
+
+        let width = usize::from(info.width());
+        columns.push((index, position..position + width));
+        position += width + vertical_lines;
+    }
+
+    columns
+}
+
+/// Render the table without any styling and collect the styling of each cell as spans of the
+/// plain text.
+///
+/// The table must not be styled itself, i.e. [Table::should_style] must be `false`.
+#[cfg(feature = "tty")]
+pub fn build_style_spans(table: &Table) -> (String, Vec<StyleSpan>) {
+    let formatted = format(table, false).expect("Layout is only validated in strict mode");
+    let drawn = &formatted.grid;
+
+    let lines = draw_borders(
+        drawn,
+        &formatted.content,
+        &formatted.details,
+        &formatted.display_info,
+    );
+    let owners = line_owners(
+        drawn,
+        &formatted.content,
+        &formatted.details,
+        &formatted.display_info,
+    );
+    let columns = column_ranges(drawn, &formatted.display_info);
+    let rows = grid::all_rows(drawn);
+    let slots = grid::place_cells(drawn);
+    let header_offset = usize::from(drawn.header.is_some());
+    let inner = columns
+        .first()
+        .map(|(_, range)| range.start)
+        .unwrap_or_default()
+        ..columns
+            .last()
+            .map(|(_, range)| range.end)
+            .unwrap_or_default();
+
+    let mut spans = Vec::new();
+    let mut offset = 0;
+    for (line, owner) in lines
+        .iter()
+        .zip(owners.into_iter().chain(std::iter::repeat(None)))
+    {
+        // Collect the cells on this line with the part of the line they occupy.
+        let cells: Vec<(&Cell, Range<usize>)> = match owner {
+            None | Some(LineOwner::Text) => Vec::new(),
+            Some(LineOwner::Header | LineOwner::Row(_) | LineOwner::Footer) => {
+                let row_index = match owner {
+                    Some(LineOwner::Row(index)) => index + header_offset,
+                    Some(LineOwner::Footer) => drawn.rows.len() + header_offset,
+                    _ => 0,
+                };
+                // A cell covers the range from its first to its last visible column.
+                let range = |slot: &grid::Slot| {
+                    let mut ranges = columns
+                        .iter()
+                        .filter(|(index, _)| slot.columns.contains(index))
+                        .map(|(_, range)| range);
+                    let first = ranges.next()?;
+                    let last = ranges.next_back().unwrap_or(first);
+                    Some(first.start..last.end)
+                };
+                slots[row_index]
+                    .iter()
+                    .filter_map(|slot| Some((&rows[slot.row].cells[slot.cell], range(slot)?)))
+                    .collect()
+            }
+            Some(LineOwner::Details(index)) => drawn
+                .details_column
+                .and_then(|column| drawn.rows.get(index)?.cells.get(column))
+                .map(|cell| (cell, inner.clone()))
+                .into_iter()
+                .collect(),
+            Some(LineOwner::Annotation { row, index }) => {
+                let row = match row {
+                    Some(row) => drawn.rows.get(row),
+                    None => drawn.header.as_ref(),
+                };
+                row.and_then(|row| row.annotations.get(index))
+                    .map(|cell| (cell, DETAILS_INDENT..usize::MAX))
+                    .into_iter()
+                    .collect()
+            }
+        };
+
+        for (cell, range) in cells {
+            if cell.fg.is_none() && cell.bg.is_none() && cell.attributes.is_empty() {
+                continue;
+            }
+
+            let mut bytes = byte_range(line, range, &drawn.width_table);
+            // Only the text itself is styled, the whitespace around it isn't.
+            if drawn.style_text_only {
+                let text = &line[bytes.clone()];
+                let start = bytes.start + (text.len() - text.trim_start_matches(' ').len());
+                let end = bytes.end - (text.len() - text.trim_end_matches(' ').len());
+                bytes = start..end.max(start);
+            }
+            if bytes.is_empty() {
+                continue;
+            }
+
+            spans.push(StyleSpan {
+                range: offset + bytes.start..offset + bytes.end,
+                fg: cell.fg,
+                bg: cell.bg,
+                attributes: cell.attributes.clone(),
+            });
+        }
+
+        // Account for the newline between lines.
+        offset += line.len() + 1;
+    }
+
+    (lines.join("\n"), spans)
+}
+
+/// Convert a range of terminal columns in a line to a range of bytes.
+#[cfg(feature = "tty")]
+fn byte_range(line: &str, columns: Range<usize>, widths: &WidthTable) -> Range<usize> {
+    let mut start = line.len();
+    let mut end = line.len();
+    let mut position = 0;
+    for (byte, grapheme) in line.grapheme_indices(true) {
+        if position >= columns.start && start == line.len() {
+            start = byte;
+        }
+        if position >= columns.end {
+            end = byte;
+            break;
+        }
+        position += widths.grapheme_width(grapheme);
+    }
+
+    start..end.max(start)
+}
+
+/// Render the structure of the table with the given amount of blank rows.
+///
+/// The columns are arranged based on the actual content of the table, so the skeleton has the
+/// same dimensions as the final table.
+pub fn build_skeleton(table: &Table, rows: usize) -> impl Iterator<Item = String> {
+    let table = &*with_decorations(table);
+    let mut skeleton = table.clone();
+    let mut display_info = arrange_content(table);
+
+    // Mirror the layout of the final table, if the details column is moved below the rows.
+    if let Some(index) = overflowing_details_column(table, &display_info) {
+        skeleton.columns[index].constraint = Some(ColumnConstraint::Hidden);
+        display_info = arrange_content(&skeleton);
+    }
+
+    skeleton.rows = (0..rows).map(|_| Row::new()).collect();
+    let content = format_content(&skeleton, &display_info);
+    draw_borders(&skeleton, &content, &[], &display_info).into_iter()
+}
+
+/// Render the header of the table, followed by the given rows.
+///
+/// The columns are arranged once based on the current content of the table.
+/// The rows are then formatted and drawn one at a time with these column widths, so only the
+/// current and the previous row are kept in memory.
+pub fn build_rows_stream<I>(table: &Table, rows: I) -> impl Iterator<Item = String>
+where
+    I: Iterator<Item = Row>,
+{
+    let mut stream = RowStream::new(table);
+    let mut rows = rows;
+    std::iter::from_fn(move || {
+        if stream.is_finished() {
+            return None;
+        }
+
+        Some(match rows.next() {
+            Some(row) => stream.render_row(row),
+            None => stream.finish(),
+        })
+    })
+    .flatten()
+}
+
+/// Rows, which are rendered one after another with the same column widths.
+///
+/// The columns are arranged only once, based on the content of the table the stream has been
+/// created for. Each row is then drawn below a blank copy of the previous row, which draws the
+/// line between both rows.
+#[derive(Clone, Debug)]
+pub struct RowStream {
+    template: Table,
+    display_info: Vec<ColumnDisplayInfo>,
+    details_column: Option<usize>,
+    /// The footer and the caption are only drawn below the very last row.
+    footer: Option<Row>,
+    caption: Option<Cell>,
+    /// The title and the header groups are only drawn above the very first row.
+    title: Option<Cell>,
+    header_groups: Vec<(Cell, usize)>,
+    /// The raw lines and row groups of the rows of the table itself.
+    raw_lines: Vec<(usize, String)>,
+    row_groups: Vec<(usize, Cell)>,
+    top_border: usize,
+    has_bottom_border: bool,
+    /// Whether rows are decorated, when they're rendered.
+    /// The rows of the table itself have already been decorated along with the table.
+    decorate_rows: bool,
+    rendered_rows: usize,
+    previous: Option<Row>,
+    bottom_border: Option<String>,
+    finished: bool,
+}
+
+impl RowStream {
+    /// Arrange the columns of the given table. Its rows aren't rendered.
+    pub fn new(table: &Table) -> Self {
+        let (mut stream, _) = Self::with_rows(table);
+        // Streamed rows don't belong to any row group and aren't preceded by any raw lines.
+        stream.raw_lines = Vec::new();
+        stream.row_groups = Vec::new();
+        stream.decorate_rows = true;
+
+        stream
+    }
+
+    /// Same as [RowStream::new], but also returns the decorated rows of the table, which can then
+    /// be rendered one by one.
+    pub fn with_rows(table: &Table) -> (Self, Vec<Row>) {
+        let mut template = with_decorations(table).into_owned();
+        let mut display_info = arrange_content(&template);
+
+        // Mirror the layout of the final table, if the details column is moved below the rows.
+        let details_column = overflowing_details_column(&template, &display_info);
+        if let Some(index) = details_column {
+            template.columns[index].constraint = Some(ColumnConstraint::Hidden);
+            display_info = arrange_content(&template);
+        }
+        let rows = std::mem::take(&mut template.rows);
+        let raw_lines = std::mem::take(&mut template.raw_lines);
+        let row_groups = std::mem::take(&mut template.row_groups);
+        let footer = template.footer.take();
+        let title = template.title.take();
+        let header_groups = std::mem::take(&mut template.header_groups);
+        let caption = template.caption.take();
+
+        let top_border = usize::from(should_draw_top_border(&template));
+        let has_bottom_border = should_draw_bottom_border(&template);
+
+        let stream = Self {
+            template,
+            display_info,
+            details_column,
+            footer,
+            caption,
+            title,
+            header_groups,
+            raw_lines,
+            row_groups,
+            top_border,
+            has_bottom_border,
+            decorate_rows: false,
+            rendered_rows: 0,
+            previous: None,
+            bottom_border: None,
+            finished: false,
+        };
+        (stream, rows)
+    }
+
+    /// Whether the end of the table has already been rendered.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// The amount of rows that have been rendered so far.
+    pub fn rendered_rows(&self) -> usize {
+        self.rendered_rows
+    }
+
+    /// Render the next row. The first row is preceded by the title and the header.
+    pub fn render_row(&mut self, mut row: Row) -> Vec<String> {
+        let template = &mut self.template;
+        let index = self.rendered_rows;
+        self.rendered_rows += 1;
+        row.index = Some(index);
+        if self.decorate_rows {
+            row = decorate_streamed_row(template, row, index);
+        }
+
+        // All rows after the first one are drawn below a blank copy of the previous row.
+        // That way, the line between both rows is drawn, which is then the first line of the
+        // chunk.
+        let mut skip = 0;
+        template.title = self.title.take();
+        template.header_groups = std::mem::take(&mut self.header_groups);
+        template.rows = match self.previous.take() {
+            Some(previous) => {
+                template.header = None;
+                skip = self.top_border + 1;
+                vec![blank_row(&previous), row]
+            }
+            None => vec![row],
+        };
+        let position = template.rows.len() - 1;
+        template.raw_lines = positioned(&self.raw_lines, index, position);
+        template.row_groups = positioned(&self.row_groups, index, position);
+
+        let mut lines = render_chunk(template, &self.display_info, self.details_column);
+        if self.has_bottom_border {
+            self.bottom_border = lines.pop();
+        }
+        self.previous = template.rows.pop();
+
+        lines.split_off(skip.min(lines.len()))
+    }
+
+    /// Render the end of the table below the last row, i.e. the bottom border, the footer and the
+    /// caption.
+    pub fn finish(&mut self) -> Vec<String> {
+        self.finished = true;
+        let template = &mut self.template;
+        template.footer = self.footer.take();
+        template.caption = self.caption.take();
+        // Without any rows, the table only consists of its title, header, footer and caption.
+        let Some(previous) = self.previous.take() else {
+            template.title = self.title.take();
+            template.header_groups = std::mem::take(&mut self.header_groups);
+            return render_chunk(template, &self.display_info, self.details_column);
+        };
+        if template.footer.is_none() {
+            let mut lines: Vec<String> = positioned(&self.raw_lines, self.rendered_rows, 0)
+                .into_iter()
+                .map(|(_, line)| line)
+                .collect();
+            lines.extend(self.bottom_border.take());
+            if let Some(caption) = &template.caption {
+                lines.extend(format_banner(template, caption, &self.display_info));
+            }
+            return lines;
+        }
+
+        // Draw the footer below a blank copy of the last row, just like any other row.
+        template.header = None;
+        template.title = None;
+        template.header_groups = Vec::new();
+        template.rows = vec![blank_row(&previous)];
+        template.raw_lines = positioned(&self.raw_lines, self.rendered_rows, 1);
+        template.row_groups = Vec::new();
+        let mut lines = render_chunk(template, &self.display_info, self.details_column);
+        lines.split_off((self.top_border + 1).min(lines.len()))
+    }
+}
+
+/// Decorate a row, which is streamed below the given template, the same way [with_decorations]
+/// decorates the rows of a table.
+#[allow(unused_variables)]
+fn decorate_streamed_row(template: &mut Table, row: Row, index: usize) -> Row {
+    // Only the row itself is decorated, the header has been decorated along with the template.
+    let header = template.header.take();
+    template.rows = vec![row];
+
+    let decorated: Cow<'_, Table> = Cow::Owned(std::mem::take(template));
+    let decorated = with_numeric_formats(decorated);
+    let decorated = with_border_collisions(decorated);
+    #[cfg(feature = "tty")]
+    let decorated = {
+        let mut decorated = with_validation_styles(decorated);
+        let stripe = zebra_stripe(decorated.zebra_striping, index);
+        apply_row_style(&mut decorated.to_mut().rows[0], stripe);
+        with_column_backgrounds(decorated)
+    };
+
+    *template = decorated.into_owned();
+    template.header = header;
+    template.rows.pop().expect("The row has been added above")
+}
+
+/// The items, which belong in front of the row with the given index, moved to the given position.
+fn positioned<T: Clone>(items: &[(usize, T)], index: usize, position: usize) -> Vec<(usize, T)> {
+    items
+        .iter()
+        .filter(|(item_index, _)| *item_index == index)
+        .map(|(_, item)| (position, item.clone()))
+        .collect()
+}
+
+/// Render the rows of the table one at a time, until the time budget is used up.
+pub fn build_partial(table: &Table, budget: Duration) -> PartialRender {
+    // Rows are rendered one at a time, which would cut off cells that span multiple rows.
+    // Such tables are rendered in one go instead.
+    let has_rowspans = table
+        .rows
+        .iter()
+        .flat_map(|row| row.cells.iter())
+        .any(|cell| cell.rowspan > 1);
+    if has_rowspans {
+        return PartialRender::complete(build_table(table).collect());
+    }
+
+    let deadline = Instant::now().checked_add(budget);
+    let (stream, rows) = RowStream::with_rows(table);
+
+    RenderContinuation::new(stream, rows).render_until(deadline)
+}
+
+/// Render the table in pages of the given amount of rows.
+///
+/// The columns are arranged once for the whole table. Each page is then drawn with these column
+/// widths and contains the header and all borders. The title is only drawn on the first page,
+/// the caption only on the last one. The footer is drawn on the last page, or on every page if
+/// it's [repeated](Table::set_repeat_footer). All pages but the last one end with the
+/// [continuation border](crate::TableComponent::ContinuationBorder), if it's set.
+pub fn build_pages(table: &Table, rows_per_page: usize) -> impl Iterator<Item = String> {
+    let mut template = with_decorations(table).into_owned();
+    let mut display_info = arrange_content(&template);
+
+    // Mirror the layout of the final table, if the details column is moved below the rows.
+    let details_column = overflowing_details_column(&template, &display_info);
+    if let Some(index) = details_column {
+        template.columns[index].constraint = Some(ColumnConstraint::Hidden);
+        display_info = arrange_content(&template);
+    }
+
+    let rows_per_page = rows_per_page.max(1);
+    let rows = std::mem::take(&mut template.rows);
+    let raw_lines = std::mem::take(&mut template.raw_lines);
+    let row_groups = std::mem::take(&mut template.row_groups);
+    let mut title = template.title.take();
+    let mut footer = template.footer.take();
+    let mut caption = template.caption.take();
+
+    let pages = rows.len().div_ceil(rows_per_page).max(1);
+    let mut rows = rows.into_iter();
+    (0..pages).map(move |page| {
+        let start = page * rows_per_page;
+        let mut page_table = template.clone();
+        page_table.rows = rows.by_ref().take(rows_per_page).collect();
+        let end = start + page_table.rows.len();
+        let is_last = page + 1 == pages;
+
+        // Raw lines after the very last row are only drawn on the last page.
+        page_table.raw_lines = raw_lines
+            .iter()
+            .filter(|(position, _)| {
+                (start..end).contains(position) || (is_last && *position == end)
+            })
+            .map(|(position, line)| (position - start, line.clone()))
+            .collect();
+        page_table.row_groups = row_groups
+            .iter()
+            .filter(|(position, _)| (start..end).contains(position))
+            .map(|(position, label)| (position - start, label.clone()))
+            .collect();
+        page_table.title = title.take();
+        if template.repeat_footer {
+            page_table.footer = footer.clone();
+        }
+        if is_last {
+            page_table.footer = footer.take();
+            page_table.caption = caption.take();
+        }
+
+        let mut lines = render_chunk(&page_table, &display_info, details_column);
+        // The bottom border of all pages but the last one shows that the table continues.
+        if !is_last
+            && should_draw_bottom_border(&page_table)
+            && let Some(border) = draw_continuation_border(&page_table, &display_info)
+        {
+            lines.pop();
+            lines.push(border);
+        }
+
+        lines.join("\n")
+    })
+}
+
+/// A row without any content, whose cells span the same columns as the cells of the given row.
+fn blank_row(row: &Row) -> Row {
+    let cells: Vec<Cell> = row
+        .cells
+        .iter()
+        .map(|cell| Cell::new("").set_colspan(cell.colspan))
+        .collect();
+
+    Row::from(cells)
+}
+
+/// Format and draw the rows of the table with an already arranged layout.
+fn render_chunk(
+    table: &Table,
+    display_info: &[ColumnDisplayInfo],
+    details_column: Option<usize>,
+) -> Vec<String> {
+    let content = format_content(table, display_info);
+    let details = details_column
+        .map(|index| format_details(table, index, display_info))
+        .unwrap_or_default();
+
+    draw_borders(table, &content, &details, display_info)
+}
+
+/// Return the index of the [details column](Table::set_details_column), if its content doesn't
+/// fit into its arranged width.
+fn overflowing_details_column(table: &Table, display_info: &[ColumnDisplayInfo]) -> Option<usize> {
+    let index = table.details_column?;
+    let info = display_info.get(index)?;
+    if info.is_hidden {
+        return None;
+    }
+
+    let overflows = table
+        .column_cells_iter(index)
+        .flatten()
+        .any(|cell| cell.max_content_width(&table.width_table) > usize::from(info.content_width));
+
+    overflows.then_some(index)
+}
+
+/// Find all cells of the header and the rows, whose content doesn't fit into their arranged
+/// column.
+pub fn build_overflow_report(table: &Table) -> Vec<CellOverflow> {
+    let table = &*with_decorations(table);
+    let mut display_info = arrange_content(table);
+
+    // The details column is displayed below the rows, if it doesn't fit.
+    let mut grid = Cow::Borrowed(table);
+    if let Some(index) = overflowing_details_column(table, &display_info) {
+        grid.to_mut().columns[index].constraint = Some(ColumnConstraint::Hidden);
+        display_info = arrange_content(&grid);
+    }
+
+    let rows = grid::all_rows(table);
+    let header_offset = usize::from(table.header.is_some());
+    let mut overflows = Vec::new();
+    for (row_index, slots) in grid::place_cells(table).iter().enumerate() {
+        let row = row_index.checked_sub(header_offset);
+        if row.is_some_and(|row| row >= table.rows.len()) {
+            continue;
+        }
+
+        for slot in slots.iter().filter(|slot| slot.row == row_index) {
+            let Some(info) = grid::merged_info(&grid, &display_info, slot.columns.clone()) else {
+                continue;
+            };
+
+            let cell = &rows[row_index].cells[slot.cell];
+            let width = cell.max_content_width(&table.width_table);
+            let available = usize::from(info.content_width);
+            if width > available {
+                overflows.push(CellOverflow {
+                    row,
+                    column: slot.columns.start,
+                    width,
+                    available,
+                    truncated: info.no_wrap || info.truncation.is_some(),
+                });
+            }
+        }
+    }
+
+    overflows
+}
+
+/// Find combinations of styles, which likely make the table unreadable.
+///
+/// Cells are checked with the styling of their row and the theme applied. The footer is skipped.
+pub fn build_style_lint(table: &Table) -> Vec<StyleWarning> {
+    let table = &*with_decorations(table);
+    let mut warnings = Vec::new();
+
+    #[cfg(feature = "tty")]
+    {
+        let rows = grid::all_rows(table);
+        let header_offset = usize::from(table.header.is_some());
+        for (row_index, slots) in grid::place_cells(table).iter().enumerate() {
+            let row = row_index.checked_sub(header_offset);
+            if row.is_some_and(|row| row >= table.rows.len()) {
+                continue;
+            }
+
+            for slot in slots.iter().filter(|slot| slot.row == row_index) {
+                let cell = &rows[row_index].cells[slot.cell];
+                if let (Some(fg), Some(bg)) = (cell.fg, cell.bg)
+                    && fg == bg
+                {
+                    warnings.push(StyleWarning::SameColors {
+                        row,
+                        column: slot.columns.start,
+                        color: fg,
+                    });
+                }
+            }
+        }
+
+        // Deterministic tables write their colors, even if `NO_COLOR` is set.
+        let colored = table.zebra_striping.is_some()
+            || rows
+                .iter()
+                .flat_map(|row| row.cells.iter())
+                .any(|cell| cell.fg.is_some() || cell.bg.is_some());
+        let no_color = std::env::var("NO_COLOR").is_ok_and(|value| !value.is_empty());
+        if colored && no_color && !table.is_deterministic() {
+            warnings.push(StyleWarning::ColorsDisabled);
+        }
+    }
+
+    if let Some(header) = &table.header {
+        let display_info = arrange_content(table);
+        let slots = grid::place_cells(table)
+            .into_iter()
+            .next()
+            .unwrap_or_default();
+        for slot in slots {
+            let Some(info) = grid::merged_info(table, &display_info, slot.columns.clone()) else {
+                continue;
+            };
+            // Truncated headers only display the indicator, if the column is too narrow.
+            let cell = &header.cells[slot.cell];
+            let is_cut =
+                cell.max_content_width(&table.width_table) > usize::from(info.content_width);
+            let mut space = info.content_width;
+            if info.no_wrap || info.truncation.is_some() {
+                let column = &table.columns[slot.columns.start];
+                space = space.saturating_sub(arrangement::helper::indicator_width(table, column));
+            }
+            if is_cut && space == 0 {
+                warnings.push(StyleWarning::HiddenHeader {
+                    column: slot.columns.start,
+                });
+            }
+        }
+    }
+
+    warnings
+}
+
+/// Make sure that a dynamically arranged table fits into its width, once all columns are squeezed
+/// to a single character.
+fn check_minimum_width(
+    table: &Table,
+    display_info: &[ColumnDisplayInfo],
+) -> Result<(), LayoutError> {
+    let Some(width) = table.width().filter(|_| arrangement::is_dynamic(table)) else {
+        return Ok(());
+    };
+
+    let minimum = arrangement::helper::minimum_width(table, display_info);
+    if minimum > usize::from(width) {
+        return Err(LayoutError::TooNarrow { width, minimum });
+    }
+
+    Ok(())
+}
+
+/// Make sure that all cells, which must not be truncated, fit into their arranged column.
+fn check_never_truncate(
+    table: &Table,
+    display_info: &[ColumnDisplayInfo],
+) -> Result<(), LayoutError> {
+    let rows = grid::all_rows(table);
+    let header_offset = usize::from(table.header.is_some());
+
+    for (row_index, slots) in grid::place_cells(table).iter().enumerate() {
+        for slot in slots.iter().filter(|slot| slot.row == row_index) {
+            let cell = &rows[row_index].cells[slot.cell];
+            if !cell.never_truncate {
+                continue;
+            }
+            let Some(info) = grid::merged_info(table, display_info, slot.columns.clone()) else {
+                continue;
+            };
+
+            let width = cell.max_content_width(&table.width_table);
+            let available = usize::from(info.content_width);
+            if width > available {
+                return Err(LayoutError::CellTruncated {
+                    row: row_index.checked_sub(header_offset),
+                    column: slot.columns.start,
+                    width,
+                    available,
+                    source: rows[row_index].source.clone(),
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Render the table and compare the width of each line with the width of the table.
+///
+/// Annotations, raw lines, the title and the caption aren't part of the table's frame and are
+/// skipped.
+pub fn build_width_audit(table: &Table) -> Vec<WidthMismatch> {
+    let formatted = format(table, false).expect("Layout is only validated in strict mode");
+    let drawn = &formatted.grid;
+    let visible: Vec<&ColumnDisplayInfo> = formatted
+        .display_info
+        .iter()
+        .filter(|info| !info.is_hidden)
+        .collect();
+    let expected = arrangement::helper::count_border_columns(drawn, visible.len())
+        + visible
+            .iter()
+            .map(|info| usize::from(info.width()))
+            .sum::<usize>();
+
+    let lines = draw_borders(
+        drawn,
+        &formatted.content,
+        &formatted.details,
+        &formatted.display_info,
+    );
+    let owners = line_owners(
+        drawn,
+        &formatted.content,
+        &formatted.details,
+        &formatted.display_info,
+    );
+
+    lines
+        .iter()
+        .zip(owners.into_iter().chain(std::iter::repeat(None)))
+        .enumerate()
+        .filter(|(_, (_, owner))| {
+            !matches!(owner, Some(LineOwner::Text | LineOwner::Annotation { .. }))
+        })
+        .filter_map(|(index, (line, _))| {
+            let actual = measure_text_width(line, &drawn.width_table);
+            (actual != expected).then_some(WidthMismatch {
+                line: index,
+                expected,
+                actual,
+            })
+        })
+        .collect()
+}
+
+/// Arrange the table and check whether all content fits into the arranged columns and whether the
+/// table fits into the given width.
+pub fn check_fits(table: &Table, width: u16) -> Result<(), LayoutError> {
+    let table = &*with_decorations(table);
     let display_info = arrange_content(table);
-    let content = format_content(table, &display_info);
-    draw_borders(table, &content, &display_info).into_iter()
+    let max_content_widths = table.column_max_content_widths();
+    let visible_columns = display_info.iter().filter(|info| !info.is_hidden).count();
+    let border_columns = arrangement::helper::count_border_columns(table, visible_columns);
+
+    let mut actual = border_columns;
+    let mut required = border_columns;
+    let mut columns = Vec::new();
+    for (index, info) in display_info.iter().enumerate() {
+        if info.is_hidden {
+            continue;
+        }
+
+        let content_width = usize::from(max_content_widths[index].max(1));
+        actual += usize::from(info.width());
+        required += content_width + usize::from(info.padding.0) + usize::from(info.padding.1);
+
+        let overflow = content_width.saturating_sub(info.content_width.into());
+        if overflow > 0 {
+            columns.push(ColumnOverflow { index, overflow });
+        }
+    }
+
+    if columns.is_empty() && actual <= usize::from(width) {
+        return Ok(());
+    }
+
+    Err(LayoutError::TooWide {
+        width,
+        required,
+        columns,
+    })
 }
@@ -0,0 +1,151 @@
+#[cfg(feature = "serde")]
+use std::str::FromStr;
+
+use crate::SortOrder;
+
+/// The state of an interactive view on a table, which is kept separate from the table's data.
+///
+/// It holds the sort keys, the hidden columns, the scroll offset and the selected row.
+/// The state is applied at render time via [Table::with_view_state](crate::Table::with_view_state),
+/// so the same state can be re-applied after the data of a table has been refreshed.\
+/// With the `serde` feature, the state can be (de)serialized and loaded from JSON via
+/// [str::parse]. Missing fields fall back to their defaults.
+///
+/// ```
+/// use comfy_table::{SortOrder, Table, TableViewState};
+///
+/// let mut table = Table::new();
+/// table
+///     .set_header(vec!["Host", "Load"])
+///     .add_row(vec!["web-1", "3"])
+///     .add_row(vec!["web-2", "12"])
+///     .add_row(vec!["database", "7"]);
+///
+/// let mut state = TableViewState::new();
+/// state.add_sort_key(1, SortOrder::Descending).set_scroll_offset(1);
+///
+/// let expected = "
+/// +----------+------+
+/// | Host     | Load |
+/// +=================+
+/// | database | 7    |
+/// |----------+------|
+/// | web-1    | 3    |
+/// +----------+------+";
+/// let view = table.with_view_state(&state);
+/// assert_eq!(expected, "\n".to_string() + &view.to_string());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TableViewState {
+    sort_keys: Vec<(usize, SortOrder)>,
+    hidden_columns: Vec<usize>,
+    scroll_offset: usize,
+    selected_row: Option<usize>,
+}
+
+impl TableViewState {
+    /// Create a new state, which doesn't change the table in any way.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sort the rows by the content of the given column.
+    ///
+    /// Later keys are only used for rows, which are equal for all previous keys.
+    /// Sequences of digits are compared by their numeric value, just like
+    /// [Table::sort_rows_numeric](crate::Table::sort_rows_numeric) does.
+    pub fn add_sort_key(&mut self, column_index: usize, order: SortOrder) -> &mut Self {
+        self.sort_keys.push((column_index, order));
+
+        self
+    }
+
+    /// The sort keys in the form of (column index, order).
+    pub fn sort_keys(&self) -> &[(usize, SortOrder)] {
+        &self.sort_keys
+    }
+
+    /// Remove all sort keys, the rows are displayed in their original order.
+    pub fn clear_sort_keys(&mut self) -> &mut Self {
+        self.sort_keys.clear();
+
+        self
+    }
+
+    /// Show or hide the column with the given index.
+    pub fn set_column_visible(&mut self, column_index: usize, visible: bool) -> &mut Self {
+        self.hidden_columns.retain(|index| *index != column_index);
+        if !visible {
+            self.hidden_columns.push(column_index);
+        }
+
+        self
+    }
+
+    /// Returns whether the column with the given index is displayed.
+    pub fn is_column_visible(&self, column_index: usize) -> bool {
+        !self.hidden_columns.contains(&column_index)
+    }
+
+    /// The indices of all hidden columns.
+    pub fn hidden_columns(&self) -> &[usize] {
+        &self.hidden_columns
+    }
+
+    /// Skip the given amount of rows at the top of the (sorted) table.
+    pub fn set_scroll_offset(&mut self, offset: usize) -> &mut Self {
+        self.scroll_offset = offset;
+
+        self
+    }
+
+    /// The amount of rows, which are skipped at the top of the table.
+    pub fn scroll_offset(&self) -> usize {
+        self.scroll_offset
+    }
+
+    /// Select the row at the given position of the sorted table.
+    ///
+    /// With the `tty` feature, the selected row is displayed with the selection colors of the
+    /// table's [theme](crate::Theme) or with reversed colors.
+    pub fn set_selected_row(&mut self, row_index: usize) -> &mut Self {
+        self.selected_row = Some(row_index);
+
+        self
+    }
+
+    /// The position of the selected row in the sorted table.
+    pub fn selected_row(&self) -> Option<usize> {
+        self.selected_row
+    }
+
+    /// Remove the selection.
+    pub fn clear_selection(&mut self) -> &mut Self {
+        self.selected_row = None;
+
+        self
+    }
+}
+
+/// Load a view state from its JSON definition.
+///
+/// ```
+/// use comfy_table::{SortOrder, TableViewState};
+///
+/// let state: TableViewState = r#"{ "sort_keys": [[1, "Descending"]], "scroll_offset": 20 }"#
+///     .parse()
+///     .unwrap();
+///
+/// assert_eq!(state.sort_keys(), &[(1, SortOrder::Descending)]);
+/// assert_eq!(state.scroll_offset(), 20);
+/// ```
+#[cfg(feature = "serde")]
+impl FromStr for TableViewState {
+    type Err = serde_json::Error;
+
+    fn from_str(definition: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(definition)
+    }
+}
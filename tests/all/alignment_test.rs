@@ -45,3 +45,107 @@ fn cell_alignment() {
 +---------------------+---------------------+---------------------+";
     assert_eq!(expected, "\n".to_string() + &table.to_string());
 }
+
+/// Each line of a wrapped cell is aligned on its own instead of padding the whole block.
+/// That way, right aligned numbers line up on their last line.
+#[test]
+fn wrapped_lines_aligned_independently() {
+    let mut table = Table::new();
+    table
+        .set_width(17)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .add_row(vec!["Size", "1234567 GB"])
+        .add_row(vec!["Size", "12 GB"]);
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_cell_alignment(CellAlignment::Right);
+
+    println!("{table}");
+    let expected = "
++------+--------+
+| Size | 123456 |
+|      |   7 GB |
+|------+--------|
+| Size |  12 GB |
++------+--------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Numbers line up on their decimal point, regardless of their precision.
+#[test]
+fn decimal_alignment() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Metric", "Value"])
+        .add_row(vec!["latency", "12.5"])
+        .add_row(vec!["requests", "1200"])
+        .add_row(vec!["ratio", "-0.125"])
+        .add_row(vec![
+            Cell::new("errors"),
+            Cell::new("3").set_alignment(CellAlignment::Center),
+        ])
+        .set_footer(vec!["total", "1216.375"]);
+
+    let mut format = NumericFormat::new();
+    format.set_thousands_separator(',');
+    table.column_mut(1).unwrap().set_numeric_format(format);
+
+    println!("{table}");
+    let expected = "
++----------+-----------+
+| Metric   | Value     |
++======================+
+| latency  |    12.5   |
+|----------+-----------|
+| requests | 1,200     |
+|----------+-----------|
+| ratio    |    -0.125 |
+|----------+-----------|
+| errors   |     3     |
++======================+
+| total    | 1,216.375 |
++----------+-----------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // The content of the cells isn't changed.
+    assert_eq!(table.cell_content(1, 1), Some("1200".to_string()));
+}
+
+/// Cells line up on the first occurrence of their anchor.
+/// Lines without the anchor end, where the anchor would be. Spanned cells are aligned left.
+#[test]
+fn anchor_alignment() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Network", "Uptime"])
+        .add_row(vec!["10.0.0.0/8", "1:05:00"])
+        .add_row(vec!["192.168.1.0/24", "12:30"])
+        .add_row(vec!["::1/128", "n/a"])
+        .add_row(vec!["fe80::/10", "0:01"])
+        .add_row(vec![Cell::new("Both: 10.0/16").set_colspan(2)]);
+    table
+        .column_mut(0)
+        .unwrap()
+        .set_cell_alignment(CellAlignment::Anchor('/'));
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_cell_alignment(CellAlignment::Anchor(':'));
+
+    let expected = "
++-----------------+----------+
+|     Network     | Uptime   |
++============================+
+|    10.0.0.0/8   |  1:05:00 |
+|-----------------+----------|
+| 192.168.1.0/24  | 12:30    |
+|-----------------+----------|
+|         ::1/128 | n/a      |
+|-----------------+----------|
+|      fe80::/10  |  0:01    |
+|-----------------+----------|
+| Both: 10.0/16              |
++----------------------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
@@ -0,0 +1,52 @@
+use comfy_table::presets::UTF8_FULL;
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+fn get_table() -> Table {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Path", "Note"])
+        .add_row(vec!["C:\\Users", "a | b"])
+        .set_footer(vec!["+-+", "─ end ─"]);
+
+    table
+}
+
+/// Characters of the current preset are escaped with a backslash, just like backslashes.
+/// Characters that aren't part of the preset are kept.
+#[test]
+fn escape_border_characters() {
+    let mut table = get_table();
+    table.set_border_collision(BorderCollision::Escape);
+
+    println!("{table}");
+    let expected = r"
++-----------+---------+
+| Path      | Note    |
++=====================+
+| C:\\Users | a \| b  |
++=====================+
+| \+\-\+    | ─ end ─ |
++-----------+---------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Characters of the current preset are replaced by the substitute.
+#[test]
+fn substitute_border_characters() {
+    let mut table = get_table();
+    table
+        .load_preset(UTF8_FULL)
+        .set_border_collision(BorderCollision::Substitute('_'));
+
+    println!("{table}");
+    let expected = r"
+┌──────────┬─────────┐
+│ Path     ┆ Note    │
+╞══════════╪═════════╡
+│ C:\Users ┆ a | b   │
+╞══════════╪═════════╡
+│ +-+      ┆ _ end _ │
+└──────────┴─────────┘";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
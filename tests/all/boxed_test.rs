@@ -0,0 +1,70 @@
+use comfy_table::{presets::UTF8_FULL, *};
+use pretty_assertions::assert_eq;
+
+/// The box of a cell uses the border characters of the table.
+#[test]
+fn boxed_cell() {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec!["Host", "Load"])
+        .add_row(vec![Cell::new("web-1"), Cell::new("0.3")])
+        .add_row(vec![Cell::new("web-2"), Cell::new("17.9").set_boxed(true)]);
+
+    println!("{table}");
+    let expected = "
+┌───────┬──────────┐
+│ Host  ┆ Load     │
+╞═══════╪══════════╡
+│ web-1 ┆ 0.3      │
+├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌┤
+│ web-2 ┆ ┌──────┐ │
+│       ┆ │ 17.9 │ │
+│       ┆ └──────┘ │
+└───────┴──────────┘";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// The content of a boxed cell is wrapped inside of its box.
+#[test]
+fn boxed_cell_dynamic() {
+    let mut table = Table::new();
+    table
+        .load_preset(presets::NOTHING)
+        .add_row(vec![
+            Cell::new("Status"),
+            Cell::new("Disk almost full").set_boxed(true),
+        ])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(20);
+
+    println!("{table}");
+    let expected = "
+ Status  +--------+ 
+         | Disk   | 
+         | almost | 
+         | full   | 
+         +--------+ ";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Boxes are only drawn, if there's space for at least one character inside of them.
+/// Otherwise the content is displayed without the box.
+#[test]
+fn boxed_cell_too_narrow() {
+    let mut table = Table::new();
+    table
+        .add_row(vec![Cell::new("hello world").set_boxed(true)])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(8);
+
+    let expected = "
++------+
+| hell |
+| o    |
+| worl |
+| d    |
++------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    super::assert_table_line_width(&table, 8);
+}
@@ -399,3 +399,106 @@ fn lower_fixed_boundary_with_split() {
     println!("{expected}");
     assert_eq!(expected, "\n".to_string() + &table.to_string());
 }
+
+/// Columns with the `AtLeastHeaderWidth` constraint never wrap their header,
+/// but still grow with their content.
+#[test]
+fn at_least_header_width() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Description", "Temperature", "Unit"])
+        .add_row(vec![
+            "The temperature of the main server room",
+            "21",
+            "Celsius",
+        ])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(34);
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_constraint(AtLeastHeaderWidth);
+    table
+        .column_mut(2)
+        .unwrap()
+        .set_constraint(AtLeastHeaderWidth);
+
+    println!("{table}");
+    let expected = "
++---------+-------------+--------+
+| Descrip | Temperature | Unit   |
+| tion    |             |        |
++================================+
+| The tem | 21          | Celsiu |
+| peratur |             | s      |
+| e of    |             |        |
+| the     |             |        |
+| main    |             |        |
+| server  |             |        |
+| room    |             |        |
++---------+-------------+--------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert_table_line_width(&table, 34);
+}
+
+/// Columns with the `MinReadable` constraint keep enough space for the given amount of characters
+/// and the truncation indicator. They're hidden, if the table is too narrow for that.
+#[test]
+fn min_readable() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Description", "Size"])
+        .add_row(vec![
+            "main.rs",
+            "The entry point of the application",
+            "12 KB",
+        ])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(33);
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_no_wrap(true)
+        .set_constraint(MinReadable(10));
+
+    println!("{table}");
+    let expected = "
++---------+-------------+-------+
+| Name    | Description | Size  |
++===============================+
+| main.rs | The entry ▶ | 12 KB |
++---------+-------------+-------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    table.set_width(20);
+    println!("{table}");
+    let expected = "
++---------+-------+
+| Name    | Size  |
++=================+
+| main.rs | 12 KB |
++---------+-------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// The `MinReadable` constraint doesn't widen columns, whose content is narrower than the given
+/// amount of characters.
+#[test]
+fn min_readable_narrow_content() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Id", "Name"])
+        .add_row(vec!["ab", "main.rs"])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(40);
+    table.column_mut(0).unwrap().set_constraint(MinReadable(10));
+
+    println!("{table}");
+    let expected = "
++----+---------+
+| Id | Name    |
++==============+
+| ab | main.rs |
++----+---------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
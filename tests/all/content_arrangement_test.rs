@@ -1,4 +1,4 @@
-use comfy_table::{ColumnConstraint, ContentArrangement, Table, Width};
+use comfy_table::{Cell, ColumnConstraint, ContentArrangement, LayoutError, Table, Width};
 use pretty_assertions::assert_eq;
 
 use super::assert_table_line_width;
@@ -289,3 +289,188 @@ fn polar_python_test_tbl_width_chars() {
     assert_table_line_width(table, 72);
     assert_eq!(expected, "\n".to_string() + &table.to_string());
 }
+
+/// All columns get the same width, regardless of their content.
+#[test]
+fn equal_column_widths() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Id", "Name", "Description"])
+        .add_row(vec!["1", "Comfy", "This is a very long description"])
+        .set_width(40)
+        .set_equal_column_widths(true);
+
+    println!("{table}");
+    let expected = "
++------------+------------+------------+
+| Id         | Name       | Descriptio |
+|            |            | n          |
++======================================+
+| 1          | Comfy      | This is a  |
+|            |            | very long  |
+|            |            | descriptio |
+|            |            | n          |
++------------+------------+------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert_table_line_width(&table, 40);
+}
+
+/// The auto arrangement only wraps content as long as the table stays within its height budget.
+#[test]
+fn auto_arrangement() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2"])
+        .add_row(vec!["short", "This is a rather long text"])
+        .set_content_arrangement(ContentArrangement::Auto { max_height: 10 })
+        .set_width(60);
+
+    // Everything fits.
+    println!("{table}");
+    let expected = "
++---------+----------------------------+
+| Header1 | Header2                    |
++======================================+
+| short   | This is a rather long text |
++---------+----------------------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // Wrapping within the height budget.
+    table.set_width(25);
+    println!("{table}");
+    let expected = "
++---------+-------------+
+| Header1 | Header2     |
++=======================+
+| short   | This is a   |
+|         | rather long |
+|         | text        |
++---------+-------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // Wrapping would exceed the height budget.
+    table.set_content_arrangement(ContentArrangement::Auto { max_height: 3 });
+    println!("{table}");
+    let expected = "
++---------+-------------+
+| Header1 | Header2     |
++=======================+
+| short   | This is a ▶ |
++---------+-------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Cells, which are ignored when measuring their column, don't widen the column.
+/// Their content is wrapped into the space the other columns leave.
+#[test]
+fn ignore_width_dynamic() {
+    let mut table = Table::new();
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(40)
+        .set_header(vec!["Name", "Blob"])
+        .add_row(vec![
+            Cell::new("foo"),
+            Cell::new("A giant blob of text, which would otherwise take all the space")
+                .set_ignore_width(true),
+        ])
+        .add_row(vec!["A longer name", "short"]);
+
+    println!("{table}");
+    let expected = "
++---------------+-------+
+| Name          | Blob  |
++=======================+
+| foo           | A     |
+|               | giant |
+|               | blob  |
+|               | of    |
+|               | text, |
+|               | which |
+|               | would |
+|               | other |
+|               | wise  |
+|               | take  |
+|               | all   |
+|               | the   |
+|               | space |
+|---------------+-------|
+| A longer name | short |
++---------------+-------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Columns keep their expected width, even if their content gets narrower between renders.
+#[test]
+fn expected_width_dynamic() {
+    let build = |load: &str| {
+        let mut table = Table::new();
+        table
+            .set_content_arrangement(ContentArrangement::Dynamic)
+            .set_width(40)
+            .set_header(vec!["Host", "Load"])
+            .add_row(vec!["web-1", load]);
+        table.column_mut(1).unwrap().set_expected_width(6);
+        table
+    };
+
+    let table = build("0.5");
+    println!("{table}");
+    let expected = "
++-------+--------+
+| Host  | Load   |
++================+
+| web-1 | 0.5    |
++-------+--------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // Wider content than expected is still displayed in full.
+    let table = build("100.25%");
+    assert_eq!(table.column_display_widths(), vec![7, 9]);
+
+    // The expected width is only a hint, which yields to the available space.
+    let mut table = build("0.5");
+    table.set_width(14);
+    assert_table_line_width(&table, 14);
+}
+
+/// A table, which is narrower than its borders, padding and a single character per column,
+/// is displayed with that minimum width. Strict mode reports it instead.
+#[test]
+fn width_below_minimum() {
+    let mut table = Table::new();
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(5)
+        .set_header(vec!["Name", "Value"])
+        .add_row(vec!["cpu", "12"]);
+
+    println!("{table}");
+    let expected = "
++---+---+
+| N | V |
+| a | a |
+| m | l |
+| e | u |
+|   | e |
++=======+
+| c | 1 |
+| p | 2 |
+| u |   |
++---+---+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    table.set_strict(true);
+    let error = table.try_lines().err().unwrap();
+    assert_eq!(
+        error,
+        LayoutError::TooNarrow {
+            width: 5,
+            minimum: 9
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "Table requires a width of at least 9, but only 5 is available"
+    );
+}
@@ -0,0 +1,104 @@
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+/// The first record is the header, all other records are rows.
+#[test]
+fn from_csv() {
+    let input = "Name,Description\nfoo.txt,\"A file, with a comma\"\nbar.txt,\"Two\nlines\"\n";
+    let table = Table::from_csv(input.as_bytes()).unwrap();
+
+    println!("{table}");
+    let expected = "
++---------+----------------------+
+| Name    | Description          |
++================================+
+| foo.txt | A file, with a comma |
+|---------+----------------------|
+| bar.txt | Two                  |
+|         | lines                |
++---------+----------------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Exporting and importing a table keeps its content.
+#[test]
+fn csv_round_trip() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Quote"])
+        .add_row(vec!["Alice", "She said \"hi\""])
+        .add_row(vec!["Bob", "Multi\nline, with comma"]);
+
+    let mut output = Vec::new();
+    table.to_csv(&mut output).unwrap();
+    let loaded = Table::from_csv(output.as_slice()).unwrap();
+
+    assert_eq!(table.to_string(), loaded.to_string());
+}
+
+/// Only visible columns are exported, without any truncation.
+#[test]
+fn to_tsv() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Hidden", "Description"])
+        .add_row(vec!["foo.txt", "secret", "This is a very long description"])
+        .add_row(vec![Cell::new("Spanning both").set_colspan(3)])
+        .set_footer(vec!["Total", "", "1"])
+        .set_width(20)
+        .set_content_arrangement(ContentArrangement::Dynamic);
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_constraint(ColumnConstraint::Hidden);
+
+    let mut output = Vec::new();
+    table.to_tsv(&mut output).unwrap();
+
+    let expected = "Name\tDescription\nfoo.txt\tThis is a very long description\nSpanning both\t\n";
+    assert_eq!(expected, String::from_utf8(output).unwrap());
+}
+
+/// Unterminated quotes are reported as invalid data.
+#[test]
+fn invalid_csv() {
+    let error = Table::from_csv("Name\n\"foo".as_bytes()).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+}
+
+/// Header cells are prefixed with the label of their header group.
+#[test]
+fn csv_header_groups() {
+    let mut table = Table::new();
+    table
+        .set_header_groups(vec![("RX", 2), ("TX", 1)])
+        .set_header(vec!["bytes", "packets", "", "interface"])
+        .add_row(vec!["1024", "8", "512", "eth0"]);
+
+    let mut output = Vec::new();
+    table.to_csv(&mut output).unwrap();
+    let expected = "RX.bytes,RX.packets,TX,interface\n1024,8,512,eth0\n";
+    assert_eq!(expected, String::from_utf8(output).unwrap());
+}
+
+/// Generated headers aren't part of the data and aren't written.
+#[test]
+fn csv_generated_header() {
+    let mut table = Table::new();
+    table
+        .add_row(vec!["web-1", "up"])
+        .generate_headers(HeaderStyle::Letters);
+
+    let mut output = Vec::new();
+    table.to_csv(&mut output).unwrap();
+    assert_eq!("web-1,up\n", String::from_utf8(output).unwrap());
+
+    // Headers that are set afterwards are written again.
+    table.set_header(vec!["Host", "Status"]);
+    let mut output = Vec::new();
+    table.to_csv(&mut output).unwrap();
+    assert_eq!(
+        "Host,Status\nweb-1,up\n",
+        String::from_utf8(output).unwrap()
+    );
+}
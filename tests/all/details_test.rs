@@ -0,0 +1,88 @@
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+use crate::all::assert_table_line_width;
+
+fn get_table() -> Table {
+    let mut table = Table::new();
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec!["Level", "Time", "Message"])
+        .add_row(vec![
+            "ERROR",
+            "12:00",
+            "Connection to the database was lost, retrying in 5 seconds",
+        ])
+        .add_row(vec!["INFO", "12:01", ""])
+        .set_details_column(2);
+
+    table
+}
+
+/// The details column is displayed as a regular column, if it fits.
+#[test]
+fn details_column_fits() {
+    let mut table = get_table();
+    table.set_width(100);
+
+    println!("{table}");
+    let expected = "
++-------+-------+------------------------------------------------------------+
+| Level | Time  | Message                                                    |
++============================================================================+
+| ERROR | 12:00 | Connection to the database was lost, retrying in 5 seconds |
+|-------+-------+------------------------------------------------------------|
+| INFO  | 12:01 |                                                            |
++-------+-------+------------------------------------------------------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// The details column is moved below its rows, if it would need to be wrapped.
+#[test]
+fn details_column_overflow() {
+    let mut table = get_table();
+    table.set_width(30);
+
+    println!("{table}");
+    let expected = "
++-------+-------+
+| Level | Time  |
++===============+
+| ERROR | 12:00 |
+|   Connection  |
+|   to the      |
+|   database    |
+|   was lost,   |
+|   retrying in |
+|   5 seconds   |
+|-------+-------|
+| INFO  | 12:01 |
++-------+-------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert_table_line_width(&table, 17);
+}
+
+/// Annotations are displayed below their row without borders.
+#[test]
+fn row_annotations() {
+    let mut table = Table::new();
+    let mut row = Row::from(vec!["Job 3", "failed"]);
+    row.add_annotation("error: disk quota exceeded\n  at /var/lib/jobs");
+    table
+        .set_header(vec!["Name", "Status"])
+        .add_row(vec!["Job 2", "done"])
+        .add_row(row);
+
+    println!("{table}");
+    let expected = "
++-------+--------+
+| Name  | Status |
++================+
+| Job 2 | done   |
+|-------+--------|
+| Job 3 | failed |
+  error: disk quota exceeded
+    at /var/lib/jobs
++-------+--------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
@@ -0,0 +1,53 @@
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+/// Deterministic tables never look at the environment.
+/// Without an explicit width, dynamic arrangement falls back to the disabled arrangement.
+#[test]
+fn deterministic_table() {
+    let mut table = Table::new();
+    table
+        .set_deterministic(true)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec!["Header1", "Header2"])
+        .add_row(vec!["This is a text", "This is another text"]);
+
+    assert!(table.is_deterministic());
+    assert_eq!(table.width(), None);
+
+    println!("{table}");
+    let expected = "
++----------------+----------------------+
+| Header1        | Header2              |
++=======================================+
+| This is a text | This is another text |
++----------------+----------------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // An explicitly set width is still respected.
+    table.set_width(30);
+    assert_eq!(table.width(), Some(30));
+}
+
+/// Deterministic tables are styled with the same escape sequences as all other tables.
+#[cfg(feature = "tty")]
+#[test]
+fn deterministic_styling() {
+    let mut table = Table::new();
+    table
+        .force_no_tty()
+        .enforce_styling()
+        .set_header(vec![Cell::new("Name").add_attribute(Attribute::Bold)])
+        .add_row(vec![Cell::new("red").fg(Color::Red).bg(Color::Reset)])
+        .add_row(vec![
+            Cell::new("rgb")
+                .bg(Color::Rgb { r: 1, g: 2, b: 3 })
+                .add_attributes(vec![Attribute::Italic, Attribute::Bold]),
+        ])
+        .add_row(vec![""]);
+    table.column_mut(0).unwrap().set_bg(Color::AnsiValue(42));
+    let expected = table.to_string();
+
+    table.set_deterministic(true);
+    assert_eq!(expected, table.to_string());
+}
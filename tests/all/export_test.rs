@@ -0,0 +1,54 @@
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+fn export(table: &Table, exporter: &dyn TableExporter) -> String {
+    let mut output = Vec::new();
+    table.export(exporter, &mut output).unwrap();
+    String::from_utf8(output).unwrap()
+}
+
+/// The built-in exporters write the same output as the respective methods of the table.
+#[test]
+fn builtin_exporters() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Size"])
+        .add_row(vec!["foo, bar", "<10>"]);
+
+    assert_eq!(
+        export(&table, &MarkdownExporter),
+        table.to_markdown() + "\n"
+    );
+    assert_eq!(export(&table, &HtmlExporter), table.to_html() + "\n");
+
+    #[cfg(feature = "csv")]
+    {
+        let mut csv = Vec::new();
+        table.to_csv(&mut csv).unwrap();
+        assert_eq!(export(&table, &CsvExporter::new()).into_bytes(), csv);
+
+        let mut tsv = Vec::new();
+        table.to_tsv(&mut tsv).unwrap();
+        assert_eq!(export(&table, &CsvExporter::tsv()).into_bytes(), tsv);
+    }
+}
+
+/// Errors of the writer are passed on to the caller.
+#[test]
+fn export_error() {
+    struct Failing;
+
+    impl std::io::Write for Failing {
+        fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::other("closed"))
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut table = Table::new();
+    table.add_row(vec!["One"]);
+    assert!(table.export(&MarkdownExporter, Failing).is_err());
+}
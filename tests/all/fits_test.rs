@@ -0,0 +1,96 @@
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+fn get_table() -> Table {
+    let mut table = Table::new();
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec!["Header1", "Header2"])
+        .add_row(vec!["This is a text", "This is another text"]);
+
+    table
+}
+
+#[test]
+fn table_fits() {
+    let table = get_table();
+    assert_eq!(table.assert_fits(41), Ok(()));
+}
+
+/// Columns that need to wrap their content are reported with the amount of overflowing chars.
+#[test]
+fn table_too_wide() {
+    let table = get_table();
+
+    let error = table.assert_fits(30).unwrap_err();
+    assert_eq!(
+        error,
+        LayoutError::TooWide {
+            width: 30,
+            required: 41,
+            columns: vec![ColumnOverflow {
+                index: 1,
+                overflow: 13,
+            }],
+        }
+    );
+}
+
+/// Tables that aren't dynamically arranged don't wrap, but still have to fit into the width.
+#[test]
+fn disabled_arrangement_too_wide() {
+    let mut table = get_table();
+    table.set_content_arrangement(ContentArrangement::Disabled);
+
+    let error = table.assert_fits(30).unwrap_err();
+    assert_eq!(
+        error,
+        LayoutError::TooWide {
+            width: 30,
+            required: 41,
+            columns: Vec::new(),
+        }
+    );
+}
+
+/// Overflowing cells are reported with the width they're lacking.
+#[test]
+fn overflow_report() {
+    let mut table = Table::new();
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(30)
+        .set_header(vec!["Name", "A rather long header", "Path"])
+        .add_row(vec!["short", "fits", "/usr/local/share/applications"])
+        .add_row(vec![
+            Cell::new("A cell spanning two columns, which is long").set_colspan(2),
+        ])
+        .set_footer(vec!["A footer, which is very long"]);
+    table
+        .column_mut(2)
+        .unwrap()
+        .set_truncation(TruncationStyle::End("…".to_string()));
+    println!("{table}");
+
+    let overflows: Vec<(Option<usize>, usize, bool)> = table
+        .overflow_report()
+        .iter()
+        .map(|overflow| (overflow.row, overflow.column, overflow.truncated))
+        .collect();
+    assert_eq!(
+        overflows,
+        vec![(None, 1, false), (Some(0), 2, true), (Some(1), 0, false)]
+    );
+
+    let overflow = &table.overflow_report()[1];
+    assert_eq!(overflow.width, 29);
+    assert_eq!(overflow.width - overflow.available, 23);
+
+    // Hidden columns don't overflow.
+    table
+        .column_mut(2)
+        .unwrap()
+        .set_constraint(ColumnConstraint::Hidden);
+    table.set_width(200);
+    assert!(table.overflow_report().is_empty());
+}
@@ -0,0 +1,121 @@
+use comfy_table::presets::UTF8_FULL;
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+fn get_table() -> Table {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Item", "Price"])
+        .add_row(vec!["Apple", "1.20"])
+        .add_row(vec!["Pear", "0.80"])
+        .set_footer(vec!["Total", "2.00"]);
+
+    table
+}
+
+/// The footer uses the header's line style, unless the footer components are set.
+#[test]
+fn footer() {
+    let mut table = get_table();
+    table.load_preset(UTF8_FULL);
+
+    println!("{table}");
+    let expected = "
+┌───────┬───────┐
+│ Item  ┆ Price │
+╞═══════╪═══════╡
+│ Apple ┆ 1.20  │
+├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+│ Pear  ┆ 0.80  │
+╞═══════╪═══════╡
+│ Total ┆ 2.00  │
+└───────┴───────┘";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    table
+        .set_style(TableComponent::FooterLines, '━')
+        .set_style(TableComponent::LeftFooterIntersection, '┝')
+        .set_style(TableComponent::MiddleFooterIntersections, '┿')
+        .set_style(TableComponent::RightFooterIntersection, '┥');
+    println!("{table}");
+    let expected = "
+┌───────┬───────┐
+│ Item  ┆ Price │
+╞═══════╪═══════╡
+│ Apple ┆ 1.20  │
+├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+│ Pear  ┆ 0.80  │
+┝━━━━━━━┿━━━━━━━┥
+│ Total ┆ 2.00  │
+└───────┴───────┘";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // Footer components aren't part of presets.
+    assert_eq!(UTF8_FULL, table.current_style_as_preset());
+}
+
+/// The footer is considered, when arranging the columns.
+#[test]
+fn footer_dynamic_arrangement() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Item", "Price"])
+        .add_row(vec!["Apple", "1.20"])
+        .set_footer(vec!["The total amount", "1.20"])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(18);
+
+    println!("{table}");
+    let expected = "
++--------+-------+
+| Item   | Price |
++================+
+| Apple  | 1.20  |
++================+
+| The    | 1.20  |
+| total  |       |
+| amount |       |
++--------+-------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    super::assert_table_line_width(&table, 18);
+}
+
+/// Tables without rows still display the footer and other outputs include the footer as well.
+#[test]
+fn footer_outputs() {
+    let mut table = Table::new();
+    table.set_header(vec!["Item"]).set_footer(vec!["Total"]);
+
+    println!("{table}");
+    let expected = "
++-------+
+| Item  |
++=======+
+| Total |
++-------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    let table = get_table();
+    let rendered = table.render();
+    assert_eq!(rendered.footer_lines(), Some(7..8));
+    assert_eq!(rendered.layout().hit_test(7, 3), None);
+
+    let lines: Vec<String> = table
+        .render_rows_iter(vec![vec!["Apple", "1.20"], vec!["Pear", "0.80"]])
+        .collect();
+    assert_eq!(table.to_string(), lines.join("\n"));
+
+    let expected = "
+| Item | Price |
+| --- | --- |
+| Apple | 1.20 |
+| Pear | 0.80 |
+| Total | 2.00 |";
+    assert_eq!(expected, "\n".to_string() + &table.to_markdown());
+
+    assert!(
+        table
+            .to_html()
+            .contains("  </tbody>\n  <tfoot>\n    <tr>\n      <td>Total</td>")
+    );
+}
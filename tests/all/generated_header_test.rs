@@ -0,0 +1,48 @@
+use comfy_table::{HeaderStyle, Table};
+use pretty_assertions::assert_eq;
+
+fn header(table: &Table) -> Vec<String> {
+    table
+        .header()
+        .unwrap()
+        .cell_iter()
+        .map(|cell| cell.content())
+        .collect()
+}
+
+#[test]
+fn letters() {
+    let mut table = Table::new();
+    table
+        .add_row((0..28).map(|index| index.to_string()).collect::<Vec<_>>())
+        .generate_headers(HeaderStyle::Letters);
+
+    let header = header(&table);
+    assert_eq!(header.len(), 28);
+    assert_eq!(header[..3], ["A", "B", "C"]);
+    assert_eq!(header[25..], ["Z", "AA", "AB"]);
+    assert!(table.is_header_generated());
+}
+
+#[test]
+fn numbered() {
+    let mut table = Table::new();
+    table
+        .add_row(vec!["web-1", "up"])
+        .add_row(vec!["web-2", "up", "maintenance"])
+        .generate_headers(HeaderStyle::Numbered("col_".to_string()));
+
+    let expected = "
++-------+-------+-------------+
+| col_1 | col_2 | col_3       |
++=============================+
+| web-1 | up    |             |
+|-------+-------+-------------|
+| web-2 | up    | maintenance |
++-------+-------+-------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // Headers that are set explicitly aren't generated.
+    table.set_header(vec!["Host", "Status", "Note"]);
+    assert!(!table.is_header_generated());
+}
@@ -0,0 +1,82 @@
+use comfy_table::presets::UTF8_FULL;
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+/// Group labels are centered above their columns and separated from the header with proper
+/// intersections. Columns after the last group don't have a label.
+#[test]
+fn header_groups() {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header_groups(vec![("RX", 2), ("TX", 2)])
+        .set_header(vec!["bytes", "packets", "bytes", "packets", "errors"])
+        .add_row(vec!["1024", "8", "512", "4", "0"]);
+
+    println!("{table}");
+    let expected = "
+┌─────────────────┬─────────────────┬────────┐
+│        RX       ┆        TX       ┆        │
+├╌╌╌╌╌╌╌┬╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┬╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌┤
+│ bytes ┆ packets ┆ bytes ┆ packets ┆ errors │
+╞═══════╪═════════╪═══════╪═════════╪════════╡
+│ 1024  ┆ 8       ┆ 512   ┆ 4       ┆ 0      │
+└───────┴─────────┴───────┴─────────┴────────┘";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // The groups don't belong to any row.
+    let rendered = table.render();
+    assert_eq!(rendered.row_lines(0), Some(5..6));
+}
+
+/// Columns are widened, if a label doesn't fit above them.
+/// The labels stay aligned with their columns, when the dynamic arrangement resizes the table.
+#[test]
+fn header_groups_with_dynamic_arrangement() {
+    let mut table = Table::new();
+    table
+        .set_header_groups(vec![(Cell::new("Received packets"), 2)])
+        .set_header(vec!["A", "B", "Some longer header"])
+        .add_row(vec!["1", "2", "3"]);
+
+    println!("{table}");
+    let expected = "
++------------------+--------------------+
+| Received packets |                    |
+|---------+--------+--------------------|
+| A       | B      | Some longer header |
++=======================================+
+| 1       | 2      | 3                  |
++---------+--------+--------------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(30);
+    println!("{table}");
+    let expected = "
++------------------+---------+
+| Received packets |         |
+|---------+--------+---------|
+| A       | B      | Some    |
+|         |        | longer  |
+|         |        | header  |
++============================+
+| 1       | 2      | 3       |
++---------+--------+---------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Header groups are only drawn above the first streamed row.
+#[test]
+fn header_groups_stream() {
+    let rows = || vec![Row::from(vec!["1", "2"]), Row::from(vec!["3", "4"])];
+    let mut table = Table::new();
+    table
+        .set_header_groups(vec![("Group", 2)])
+        .set_header(vec!["A", "B"]);
+    let lines: Vec<String> = table.render_rows_iter(rows()).collect();
+
+    table.add_rows(rows());
+    assert_eq!(table.to_string(), lines.join("\n"));
+}
@@ -0,0 +1,67 @@
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+fn get_table() -> Table {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["name", "size\nunit"])
+        .add_row(vec!["notes.txt", "4 KiB"])
+        .set_header_translator(|key| match key {
+            "name" => "Nom".to_string(),
+            "size\nunit" => "Taille\n(unité)".to_string(),
+            other => other.to_string(),
+        });
+    table.column_mut(0).unwrap().set_header_icon("📄", "#");
+
+    table
+}
+
+/// The header is translated before icons are added.
+#[test]
+fn translated_header() {
+    let table = get_table();
+    println!("{table}");
+    let expected = "
++-----------+---------+
+| 📄 Nom    | Taille  |
+|           | (unité) |
++=====================+
+| notes.txt | 4 KiB   |
++-----------+---------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// The original keys are kept in the table itself.
+#[test]
+fn header_keys_are_kept() {
+    let table = get_table();
+
+    let header: Vec<String> = table
+        .header()
+        .unwrap()
+        .cell_iter()
+        .map(Cell::content)
+        .collect();
+    assert_eq!(header, vec!["name", "size\nunit"]);
+    assert!(table.to_markdown().contains("Nom"));
+
+    #[cfg(feature = "csv")]
+    {
+        let mut csv = Vec::new();
+        table.to_csv(&mut csv).unwrap();
+        assert!(
+            String::from_utf8(csv)
+                .unwrap()
+                .starts_with("name,\"size\nunit\"")
+        );
+    }
+}
+
+/// Removing the translator displays the keys again.
+#[test]
+fn remove_header_translator() {
+    let mut table = get_table();
+    table.remove_header_translator();
+
+    assert!(table.to_string().contains("name"));
+}
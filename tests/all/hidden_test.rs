@@ -1,6 +1,8 @@
 use comfy_table::*;
 use pretty_assertions::assert_eq;
 
+use super::assert_table_line_width;
+
 fn get_table() -> Table {
     let mut table = Table::new();
     table
@@ -123,3 +125,102 @@ fn only_hidden_columns() {
 └┘";
     assert_eq!(expected, "\n".to_string() + &table.to_string());
 }
+
+fn get_priority_table() -> Table {
+    let mut table = Table::new();
+    table
+        .load_preset(presets::UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec!["Name", "Description", "Owner", "Notes"])
+        .add_row(vec![
+            "comfy-table",
+            "Build beautiful terminal tables",
+            "nukesor",
+            "Written in Rust",
+        ]);
+
+    table.column_mut(2).unwrap().set_hide_priority(1);
+    table.column_mut(3).unwrap().set_hide_priority(0);
+
+    table
+}
+
+/// Columns with the lowest priority are hidden first, until all columns are readable.
+#[test]
+fn hide_priority_columns() {
+    let mut table = get_priority_table();
+    table.set_width(22);
+    println!("{table}");
+    let expected = "
+┌──────┬──────┬──────┐
+│ Name ┆ Desc ┆ Owne │
+│      ┆ ript ┆ r    │
+│      ┆ ion  ┆      │
+╞══════╪══════╪══════╡
+│ comf ┆ Buil ┆ nuke │
+│ y-ta ┆ d    ┆ sor  │
+│ ble  ┆ beau ┆      │
+│      ┆ tifu ┆      │
+│      ┆ l    ┆      │
+│      ┆ term ┆      │
+│      ┆ inal ┆      │
+│      ┆ tabl ┆      │
+│      ┆ es   ┆      │
+└──────┴──────┴──────┘";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert_table_line_width(&table, 22);
+}
+
+/// All prioritized columns are hidden, if the table is still too narrow.
+#[test]
+fn hide_all_priority_columns() {
+    let mut table = get_priority_table();
+    table.set_width(16);
+    println!("{table}");
+    let expected = "
+┌───────┬──────┐
+│ Name  ┆ Desc │
+│       ┆ ript │
+│       ┆ ion  │
+╞═══════╪══════╡
+│ comfy ┆ Buil │
+│ -tabl ┆ d    │
+│ e     ┆ beau │
+│       ┆ tifu │
+│       ┆ l    │
+│       ┆ term │
+│       ┆ inal │
+│       ┆ tabl │
+│       ┆ es   │
+└───────┴──────┘";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Nothing is hidden, as long as all columns fit.
+#[test]
+fn hide_priority_wide_table() {
+    let mut table = get_priority_table();
+    table.set_width(100);
+
+    assert!(table.to_string().contains("Notes"));
+    assert!(table.to_string().contains("Owner"));
+}
+
+/// Columns are hidden by their priority, until the table fits into its width with at least a
+/// single character per column. Columns without any content to squeeze are hidden as well.
+#[test]
+fn hide_priority_below_minimum_width() {
+    let mut table = Table::new();
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(9)
+        .add_row(vec!["a", "b", "c"]);
+    table.column_mut(1).unwrap().set_hide_priority(0);
+
+    println!("{table}");
+    let expected = "
++---+---+
+| a | c |
++---+---+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
@@ -0,0 +1,132 @@
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+/// Spans, hidden columns and absolute widths are converted into HTML.
+#[test]
+fn html() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Hidden", "Size", "Unit"])
+        .add_row(vec![
+            Cell::new("a & b").set_rowspan(2),
+            Cell::new("hidden"),
+            Cell::new("10").set_alignment(CellAlignment::Right),
+            Cell::new("MB"),
+        ])
+        .add_row(vec![
+            Cell::new("multi\nline"),
+            Cell::new("spanned").set_colspan(2),
+        ]);
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_constraint(ColumnConstraint::Hidden);
+    table
+        .column_mut(2)
+        .unwrap()
+        .set_constraint(ColumnConstraint::Absolute(Width::Fixed(10)));
+
+    println!("{}", table.to_html());
+    let expected = "
+<table>
+  <colgroup>
+    <col>
+    <col style=\"width: 10ch\">
+    <col>
+  </colgroup>
+  <thead>
+    <tr>
+      <th>Name</th>
+      <th>Size</th>
+      <th>Unit</th>
+    </tr>
+  </thead>
+  <tbody>
+    <tr>
+      <td rowspan=\"2\">a &amp; b</td>
+      <td style=\"text-align: right\">10</td>
+      <td>MB</td>
+    </tr>
+    <tr>
+      <td colspan=\"2\">spanned</td>
+    </tr>
+  </tbody>
+</table>";
+    assert_eq!(expected, "\n".to_string() + &table.to_html());
+}
+
+/// Colors and attributes of cells are converted into inline styles.
+#[cfg(feature = "tty")]
+#[test]
+fn html_styling() {
+    let mut table = Table::new();
+    table.add_row(vec![
+        Cell::new("one")
+            .fg(Color::Red)
+            .bg(Color::Rgb {
+                r: 0,
+                g: 128,
+                b: 255,
+            })
+            .add_attribute(Attribute::Bold),
+        Cell::new("two").fg(Color::AnsiValue(196)),
+    ]);
+
+    let expected = "
+<table>
+  <tbody>
+    <tr>
+      <td style=\"color: red; background-color: #0080ff; font-weight: bold\">one</td>
+      <td style=\"color: #ff0000\">two</td>
+    </tr>
+  </tbody>
+</table>";
+    assert_eq!(expected, "\n".to_string() + &table.to_html());
+}
+
+/// Header groups are placed in an additional row of the `<thead>` and get their own `<colgroup>`.
+#[test]
+fn html_header_groups() {
+    let mut table = Table::new();
+    table
+        .set_header_groups(vec![("RX", 2), ("TX", 1)])
+        .set_header(vec!["bytes", "packets", "bytes", "interface"])
+        .add_row(vec!["1024", "8", "512", "eth0"]);
+
+    println!("{}", table.to_html());
+    let expected = "
+<table>
+  <colgroup>
+    <col>
+    <col>
+  </colgroup>
+  <colgroup>
+    <col>
+  </colgroup>
+  <colgroup>
+    <col>
+  </colgroup>
+  <thead>
+    <tr>
+      <th colspan=\"2\" scope=\"colgroup\">RX</th>
+      <th scope=\"colgroup\">TX</th>
+      <th></th>
+    </tr>
+    <tr>
+      <th>bytes</th>
+      <th>packets</th>
+      <th>bytes</th>
+      <th>interface</th>
+    </tr>
+  </thead>
+  <tbody>
+    <tr>
+      <td>1024</td>
+      <td>8</td>
+      <td>512</td>
+      <td>eth0</td>
+    </tr>
+  </tbody>
+</table>";
+    assert_eq!(expected, "\n".to_string() + &table.to_html());
+}
@@ -8,7 +8,7 @@ fn get_preset_table() -> Table {
     table.set_width(85);
 
     let mut row = Row::new();
-    row.add_cell(Cell::new(format!(
+    row.add_cell(Cell::raw(format!(
         "hello{}cell1",
         console::style("123\n456").dim().blue()
     )));
@@ -17,7 +17,7 @@ fn get_preset_table() -> Table {
     table.add_row(row);
 
     let mut row = Row::new();
-    row.add_cell(Cell::new(
+    row.add_cell(Cell::raw(
         format!(r"cell sys-devices-pci00:00-0000:000:07:00.1-usb2-2\x2d1-2\x2d1.3-2\x2d1.3:1.0-host2-target2:0:0-2:0:0:1-block-sdb{}", console::style(".device").bold().red())
     ));
     row.add_cell(Cell::new(
@@ -96,3 +96,25 @@ fn styled_text_only_table() {
 └─────────────────────────────────────────┴─────────────────────────────────────────┘";
     assert_eq!(expected, "\n".to_string() + &table.to_string());
 }
+
+/// The fragments of a composite cell are wrapped as a single text.
+#[test]
+fn composite_cell() {
+    let mut table = Table::new();
+    table
+        .add_row(vec![Cell::composite(vec![
+            Cell::new("3 passed").fg(Color::Green),
+            Cell::new(", "),
+            Cell::new("1 failed").fg(Color::Red),
+        ])])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(14);
+
+    println!("{table}");
+    let expected = "
++------------+
+| \u{1b}[38;5;10m3\u{1b}[0m \u{1b}[38;5;10mpassed\u{1b}[39m,\u{1b}[0m  |
+| \u{1b}[38;5;10m\u{1b}[39m\u{1b}[38;5;9m1\u{1b}[0m \u{1b}[38;5;10m\u{1b}[39m\u{1b}[38;5;9mfailed\u{1b}[39m\u{1b}[0m   |
++------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
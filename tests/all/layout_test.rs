@@ -0,0 +1,163 @@
+use comfy_table::presets::NOTHING;
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+/// Map positions of a rendered table back to its cells.
+#[test]
+fn hit_test() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2"])
+        .add_row(vec!["One", "Two\nlines"])
+        .add_row(vec!["Three", "Four"]);
+
+    println!("{table}");
+    let expected = "
++---------+---------+
+| Header1 | Header2 |
++===================+
+| One     | Two     |
+|         | lines   |
+|---------+---------|
+| Three   | Four    |
++---------+---------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    let layout = table.layout();
+    // Borders and the header don't belong to any cell.
+    assert_eq!(layout.hit_test(0, 2), None);
+    assert_eq!(layout.hit_test(1, 2), None);
+    assert_eq!(layout.hit_test(2, 2), None);
+    assert_eq!(layout.hit_test(3, 0), None);
+    assert_eq!(layout.hit_test(3, 10), None);
+    assert_eq!(layout.hit_test(3, 20), None);
+    assert_eq!(layout.hit_test(5, 2), None);
+    assert_eq!(layout.hit_test(7, 2), None);
+
+    // Content and padding belong to the cell.
+    assert_eq!(layout.hit_test(3, 1), Some((0, 0)));
+    assert_eq!(layout.hit_test(3, 9), Some((0, 0)));
+    assert_eq!(layout.hit_test(4, 11), Some((0, 1)));
+    assert_eq!(layout.hit_test(4, 13), Some((0, 1)));
+    assert_eq!(layout.hit_test(6, 4), Some((1, 0)));
+    assert_eq!(layout.hit_test(6, 19), Some((1, 1)));
+
+    // Positions outside of the table.
+    assert_eq!(layout.hit_test(6, 21), None);
+    assert_eq!(layout.hit_test(20, 2), None);
+}
+
+/// Hidden columns and annotations are respected.
+#[test]
+fn hit_test_hidden_column_and_annotations() {
+    let mut table = Table::new();
+    let mut row = Row::from(vec!["One", "Two", "Three"]);
+    row.add_annotation("Note");
+    table
+        .load_preset(NOTHING)
+        .add_row(row)
+        .add_row(vec!["Four", "Five", "Six"]);
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_constraint(ColumnConstraint::Hidden);
+
+    println!("{table}");
+    let expected = "
+ One   Three 
+  Note
+ Four  Six   ";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    let layout = table.layout();
+    assert_eq!(layout.hit_test(0, 0), Some((0, 0)));
+    assert_eq!(layout.hit_test(0, 5), Some((0, 0)));
+    assert_eq!(layout.hit_test(0, 6), Some((0, 2)));
+    assert_eq!(layout.hit_test(1, 3), None);
+    assert_eq!(layout.hit_test(2, 4), Some((1, 0)));
+    assert_eq!(layout.hit_test(2, 12), Some((1, 2)));
+    assert_eq!(layout.hit_test(2, 13), None);
+}
+
+/// Tables are only harmonized, if all of them have the same header and the same amount of
+/// visible columns.
+#[test]
+fn vertical_stack() {
+    let mut first = Table::new();
+    first.add_row(vec!["a", "Some longer text"]);
+    let mut second = Table::new();
+    second.add_row(vec!["A wide cell", "b"]);
+    let mut third = Table::new();
+    third.add_row(vec!["one", "two", "three"]);
+
+    let separate = format!("{first}\n\n{second}\n\n{third}");
+    assert_eq!(layout::vertical(&[&first, &second, &third], true), separate);
+    assert_eq!(
+        layout::vertical(&[&first, &second], false),
+        format!("{first}\n\n{second}")
+    );
+
+    // Hidden columns don't count.
+    second.add_row(vec!["", "", "hidden"]);
+    second
+        .column_mut(2)
+        .unwrap()
+        .set_constraint(ColumnConstraint::Hidden);
+    let expected = "
++-------------+------------------+
+| a           | Some longer text |
++-------------+------------------+
+
++-------------+------------------+
+| A wide cell | b                |
+|-------------+------------------|
+|             |                  |
++-------------+------------------+";
+    assert_eq!(
+        expected,
+        "\n".to_string() + &layout::vertical(&[&first, &second], true)
+    );
+}
+
+/// Tables with different headers aren't harmonized, even if they have the same amount of columns.
+/// Columns with their own constraint keep it.
+#[test]
+fn vertical_stack_schemas() {
+    let mut cpu = Table::new();
+    cpu.set_header(vec!["CPU", "Load"])
+        .add_row(vec!["core0", "12%"]);
+    let mut disks = Table::new();
+    disks
+        .set_header(vec!["Disk", "Usage"])
+        .add_row(vec!["/dev/sda", "80%"]);
+    assert_eq!(
+        layout::vertical(&[&cpu, &disks], true),
+        format!("{cpu}\n\n{disks}")
+    );
+
+    let mut other_cpu = Table::new();
+    other_cpu
+        .set_header(vec!["CPU", "Load"])
+        .add_row(vec!["core10", "100%"]);
+    cpu.column_mut(0)
+        .unwrap()
+        .set_constraint(ColumnConstraint::UpperBoundary(Width::Fixed(5)));
+
+    println!("{}", layout::vertical(&[&cpu, &other_cpu], true));
+    let expected = "
++-------+------+
+| CPU   | Load |
++==============+
+| core0 | 12%  |
++-------+------+
+
++--------+------+
+| CPU    | Load |
++===============+
+| core10 | 100% |
++--------+------+";
+    assert_eq!(
+        expected,
+        "\n".to_string() + &layout::vertical(&[&cpu, &other_cpu], true)
+    );
+}
@@ -0,0 +1,45 @@
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+/// Each row is displayed on a single line, including the footer.
+/// Spanned cells are repeated, hidden columns and empty cells are skipped.
+#[test]
+fn linear_output() {
+    let mut table = Table::new();
+    table
+        .set_title("Interfaces")
+        .set_header(vec!["Name", "State", "Traffic", "Driver"])
+        .add_row(vec![
+            Cell::new("eth0").set_rowspan(2),
+            Cell::new("up"),
+            Cell::new("12 MB\n(daily)"),
+            Cell::new("e1000"),
+        ])
+        .add_row(vec!["down", "", "e1000"])
+        .set_footer(vec!["Total", "", "12 MB"])
+        .set_caption("Updated every minute");
+    table
+        .column_mut(3)
+        .unwrap()
+        .set_constraint(ColumnConstraint::Hidden);
+
+    let expected = "
+Interfaces
+Name: eth0; State: up; Traffic: 12 MB (daily)
+Name: eth0; State: down
+Name: Total; Traffic: 12 MB
+Updated every minute";
+    assert_eq!(expected, "\n".to_string() + &table.to_linear_string());
+}
+
+/// Without a header, the values aren't labeled.
+#[test]
+fn linear_output_without_header() {
+    let mut table = Table::new();
+    table.add_row(vec!["One", "Two"]).add_row(vec!["Three"]);
+
+    let expected = "
+One; Two
+Three";
+    assert_eq!(expected, "\n".to_string() + &table.to_linear_string());
+}
@@ -0,0 +1,102 @@
+use comfy_table::{ColumnConstraint::*, StyleWarning, Table, TruncationStyle, Width::*};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn hidden_header() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Status", "Region", ""])
+        .add_row(vec!["web-1", "up", "eu", "x"]);
+    // Only the indicator is left of the header.
+    let column = table.column_mut(1).unwrap();
+    column.set_no_wrap(true).set_constraint(Absolute(Fixed(3)));
+    // The header is wrapped instead.
+    let column = table.column_mut(2).unwrap();
+    column.set_constraint(Absolute(Fixed(3)));
+    // Empty headers don't need any space.
+    let column = table.column_mut(3).unwrap();
+    column.set_truncation(TruncationStyle::End("...".to_string()));
+
+    assert_eq!(
+        table.lint_styles(),
+        vec![StyleWarning::HiddenHeader { column: 1 }]
+    );
+    assert_eq!(
+        table.lint_styles()[0].to_string(),
+        "Column 1 has no space to display its header"
+    );
+
+    table
+        .column_mut(2)
+        .unwrap()
+        .set_truncation(TruncationStyle::End("...".to_string()))
+        .set_constraint(Absolute(Fixed(7)));
+    assert_eq!(table.lint_styles().len(), 1);
+    table
+        .column_mut(2)
+        .unwrap()
+        .set_constraint(Absolute(Fixed(5)));
+    assert_eq!(
+        table.lint_styles(),
+        vec![
+            StyleWarning::HiddenHeader { column: 1 },
+            StyleWarning::HiddenHeader { column: 2 },
+        ]
+    );
+
+    // Hidden columns aren't displayed at all.
+    table.column_mut(1).unwrap().set_constraint(Hidden);
+    table.column_mut(2).unwrap().set_constraint(Hidden);
+    assert!(table.lint_styles().is_empty());
+}
+
+#[cfg(feature = "tty")]
+#[test]
+fn same_colors() {
+    use comfy_table::{Cell, Color, Row, Theme};
+
+    let mut theme = Theme::default();
+    theme
+        .set_header_fg(Color::White)
+        .set_header_bg(Color::White);
+
+    let mut row = Row::from(vec![
+        Cell::new("Readable").bg(Color::Blue),
+        Cell::new("Hidden"),
+    ]);
+    row.set_fg(Color::Blue);
+
+    let mut table = Table::new();
+    table
+        .apply_theme(&theme)
+        .set_header(vec![Cell::new("Name").fg(Color::Black), Cell::new("Value")])
+        .set_zebra_striping(Color::Grey, Color::Black)
+        .add_row(row)
+        .add_row(vec![Cell::new("Striped").fg(Color::Black), Cell::new("ok")]);
+
+    let warnings = table.lint_styles();
+    assert_eq!(
+        warnings,
+        vec![
+            StyleWarning::SameColors {
+                row: None,
+                column: 1,
+                color: Color::White,
+            },
+            StyleWarning::SameColors {
+                row: Some(0),
+                column: 0,
+                color: Color::Blue,
+            },
+            StyleWarning::SameColors {
+                row: Some(1),
+                column: 0,
+                color: Color::Black,
+            },
+        ]
+    );
+    assert_eq!(
+        warnings[1].to_string(),
+        "Cell at row 0, column 0 has the same text and background color Blue"
+    );
+}
@@ -0,0 +1,46 @@
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+/// Tables can be rendered as GitHub-flavored Markdown.
+#[test]
+fn markdown() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Left", "Center", "Right", "Default"])
+        .add_row(vec!["a|b", "multi\nline", "", "d"])
+        .add_row(vec![Cell::new("spanned").set_colspan(2)]);
+
+    let alignments = [
+        CellAlignment::Left,
+        CellAlignment::Center,
+        CellAlignment::Right,
+    ];
+    for (column, alignment) in table.column_iter_mut().zip(alignments) {
+        column.set_cell_alignment(alignment);
+    }
+
+    println!("{}", table.to_markdown());
+    let expected = "
+| Left | Center | Right | Default |
+| :--- | :---: | ---: | --- |
+| a\\|b | multi<br>line | | d |
+| spanned | | | |";
+    assert_eq!(expected, "\n".to_string() + &table.to_markdown());
+}
+
+/// Markdown tables always have a header and hidden columns are omitted.
+#[test]
+fn markdown_without_header() {
+    let mut table = Table::new();
+    table.add_row(vec!["a", "b", "c"]);
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_constraint(ColumnConstraint::Hidden);
+
+    let expected = "
+| | |
+| --- | --- |
+| a | c |";
+    assert_eq!(expected, "\n".to_string() + &table.to_markdown());
+}
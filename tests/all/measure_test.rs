@@ -0,0 +1,42 @@
+use comfy_table::{Table, WidthTable, measure};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn width_matches_table() {
+    let content = "Wide 宽字符 text";
+    let mut table = Table::new();
+    table.add_row(vec![content]);
+
+    let table_width = table.column_max_content_widths()[0] as usize;
+    assert_eq!(measure::width(content), table_width);
+    assert_eq!(measure::width(content), 16);
+}
+
+#[test]
+fn width_with_custom_widths() {
+    let widths = WidthTable::new().set_width('宽'..='宽', 1);
+    assert_eq!(measure::width_with("宽字", &widths), 3);
+}
+
+#[test]
+fn truncate_visible() {
+    assert_eq!(measure::truncate_visible("Hello world", 0), "");
+    assert_eq!(measure::truncate_visible("Hello world", 5), "Hello");
+    assert_eq!(measure::truncate_visible("Hello world", 20), "Hello world");
+    // Wide characters are never split in half.
+    assert_eq!(measure::truncate_visible("宽字符", 5), "宽字");
+
+    let widths = WidthTable::new().set_width('\u{4E00}'..='\u{9FFF}', 1);
+    assert_eq!(measure::truncate_visible_with("宽字符", 2, &widths), "宽字");
+}
+
+#[cfg(feature = "custom_styling")]
+#[test]
+fn ansi_aware() {
+    let styled = "\u{1b}[31mHello world\u{1b}[0m";
+    assert_eq!(measure::width(styled), 11);
+    assert_eq!(
+        measure::truncate_visible(styled, 5),
+        "\u{1b}[31mHello\u{1b}[0m"
+    );
+}
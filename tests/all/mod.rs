@@ -3,25 +3,60 @@ use unicode_width::UnicodeWidthStr;
 
 mod add_predicate;
 mod alignment_test;
+mod border_collision_test;
+mod boxed_test;
 #[cfg(feature = "tty")]
 mod combined_test;
 mod constraints_test;
 mod content_arrangement_test;
 mod counts;
+#[cfg(feature = "csv")]
+mod csv_test;
 mod custom_delimiter_test;
+mod details_test;
+mod deterministic_test;
 mod edge_cases;
+mod export_test;
+mod fits_test;
+mod footer_test;
+mod generated_header_test;
+mod header_group_test;
+mod header_translator_test;
 mod hidden_test;
+mod html_test;
 #[cfg(feature = "custom_styling")]
 mod inner_style_test;
+mod layout_test;
+mod linear_test;
+mod lint_test;
+mod markdown_test;
+mod measure_test;
 mod modifiers_test;
+mod nested_test;
+mod never_truncate_test;
 mod padding_test;
 mod presets_test;
 mod property_test;
+mod raw_line_test;
+mod rendered_test;
+mod row_group_test;
+mod row_template_test;
 mod simple_test;
+mod skeleton_test;
+mod sort_test;
+mod span_test;
+mod stream_test;
 #[cfg(feature = "tty")]
 mod styling_test;
+mod theme_test;
+mod title_test;
 mod truncation;
+mod truncation_style_test;
 mod utf_8_characters;
+mod validation_test;
+mod view_state_test;
+mod width_audit_test;
+mod wrap_policy_test;
 
 pub fn assert_table_line_width(table: &Table, count: usize) {
     for line in table.lines() {
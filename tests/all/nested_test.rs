@@ -0,0 +1,59 @@
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+fn details() -> Table {
+    let mut details = Table::new();
+    details
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .add_row(vec!["Vendor", "ACME Corporation"])
+        .add_row(vec!["Firmware", "1.2.3"]);
+
+    details
+}
+
+/// A nested table is displayed with its natural width, if there's enough space.
+#[test]
+fn nested_table() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Device", "Details"])
+        .add_row(vec![Cell::new("eth0"), Cell::from_table(details())]);
+
+    println!("{table}");
+    let expected = "
++--------+---------------------------------+
+| Device | Details                         |
++==========================================+
+| eth0   | +----------+------------------+ |
+|        | | Vendor   | ACME Corporation | |
+|        | |----------+------------------| |
+|        | | Firmware | 1.2.3            | |
+|        | +----------+------------------+ |
++--------+---------------------------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// A nested table shrinks to the width of its column.
+#[test]
+fn nested_table_dynamic() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Device", "Details"])
+        .add_row(vec![Cell::new("eth0"), Cell::from_table(details())])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(36);
+
+    println!("{table}");
+    let expected = "
++--------+-------------------------+
+| Device | Details                 |
++==================================+
+| eth0   | +----------+----------+ |
+|        | | Vendor   | ACME Cor | |
+|        | |          | poration | |
+|        | |----------+----------| |
+|        | | Firmware | 1.2.3    | |
+|        | +----------+----------+ |
++--------+-------------------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
@@ -0,0 +1,104 @@
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+fn get_table() -> Table {
+    let mut table = Table::new();
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_strict(true)
+        .set_width(30)
+        .set_header(vec!["Id", "Description"])
+        .add_row(vec![
+            Cell::new("a1b2c3d4-e5f6-a7b8").set_never_truncate(true),
+            Cell::new("This is a long description"),
+        ]);
+
+    table
+}
+
+/// Columns with cells that must not be truncated get enough space for the whole cell.
+#[test]
+fn never_truncate_gets_space() {
+    let table = get_table();
+
+    let lines: Vec<String> = table.try_lines().unwrap().collect();
+    println!("{}", lines.join("\n"));
+    let expected = "
++--------------------+-------+
+| Id                 | Descr |
+|                    | iptio |
+|                    | n     |
++============================+
+| a1b2c3d4-e5f6-a7b8 | This  |
+|                    | is a  |
+|                    | long  |
+|                    | descr |
+|                    | iptio |
+|                    | n     |
++--------------------+-------+";
+    assert_eq!(expected, "\n".to_string() + &lines.join("\n"));
+}
+
+/// The row's max height doesn't cut off cells that must not be truncated.
+#[test]
+fn never_truncate_ignores_max_height() {
+    let mut table = Table::new();
+    let mut row = Row::from(vec![
+        Cell::new("one\ntwo\nthree").set_never_truncate(true),
+        Cell::new("one\ntwo\nthree"),
+    ]);
+    row.max_height(1);
+    table.add_row(row);
+
+    println!("{table}");
+    let expected = "
++-------+-------+
+| one   | on... |
+| two   |       |
+| three |       |
++-------+-------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Strict tables return an error, if a cell cannot be displayed without truncation.
+#[test]
+fn never_truncate_strict_error() {
+    let mut table = get_table();
+    table.set_width(15);
+
+    let error = table.try_lines().err().unwrap();
+    assert_eq!(
+        error,
+        LayoutError::CellTruncated {
+            row: Some(0),
+            column: 0,
+            width: 18,
+            available: 7,
+            source: None,
+        }
+    );
+
+    // Without strict mode, the table is rendered anyway.
+    table.set_strict(false);
+    assert!(table.try_lines().is_ok());
+}
+
+/// The source of a row is included in the error.
+#[test]
+fn never_truncate_error_source() {
+    let mut row = Row::from(vec![Cell::new("a1b2c3d4-e5f6").set_never_truncate(true)]);
+    row.set_source("data.csv:1287");
+
+    let mut table = Table::new();
+    table
+        .set_strict(true)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(10)
+        .add_row(row);
+
+    let error = table.try_lines().err().unwrap();
+    assert_eq!(
+        error.to_string(),
+        "Cell at row 0, column 0 (data.csv:1287) must not be truncated, but needs a width of 13 with only 6 available"
+    );
+}
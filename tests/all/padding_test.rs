@@ -34,3 +34,61 @@ fn custom_padding() {
 +-------------------+-----------+-----------+";
     assert_eq!(expected, "\n".to_string() + &table.to_string());
 }
+
+/// The default padding applies to existing and to columns that're added later on.
+#[test]
+fn default_padding() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2"])
+        .set_default_padding((0, 0))
+        .add_row(vec!["One", "Two", "Three"]);
+
+    println!("{table}");
+    let expected = "
++-------+-------+-----+
+|Header1|Header2|     |
++=====================+
+|One    |Two    |Three|
++-------+-------+-----+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Cells can override the padding of their column.
+#[test]
+fn cell_padding() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2"])
+        .add_row(vec![
+            Cell::new("One").set_padding((0, 0)),
+            Cell::new("Two").set_padding((3, 1)),
+        ])
+        .add_row(vec!["Three", "Four"]);
+
+    println!("{table}");
+    let expected = "
++---------+---------+
+| Header1 | Header2 |
++===================+
+|One      |   Two   |
+|---------+---------|
+| Three   | Four    |
++---------+---------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // The column is widened, if the cell's padding needs more space.
+    table.add_row(vec![Cell::new("Header2").set_padding((2, 2))]);
+    println!("{table}");
+    let expected = "
++-----------+---------+
+| Header1   | Header2 |
++=====================+
+|One        |   Two   |
+|-----------+---------|
+| Three     | Four    |
+|-----------+---------|
+|  Header2  |         |
++-----------+---------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
@@ -235,3 +235,26 @@ fn test_no_vertical_styling() {
     println!("{expected}");
     assert_eq!(expected, "\n".to_string() + &table.trim_fmt());
 }
+
+/// Box-drawing characters are replaced with ASCII characters in copy-friendly output.
+#[test]
+fn copy_friendly() {
+    let mut table = get_preset_table();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(modifiers::UTF8_ROUND_CORNERS);
+
+    let expected = "
++-------+-------+
+| Hello | there |
++=======+=======+
+| a     | b     |
++-------+-------+
+| c     | d     |
++-------+-------+";
+    println!("{}", table.to_copy_friendly_string());
+    assert_eq!(
+        expected,
+        "\n".to_string() + &table.to_copy_friendly_string()
+    );
+}
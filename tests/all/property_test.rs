@@ -348,6 +348,8 @@ fn enforce_constraints(
                 ColumnConstraint::Hidden => panic!("This shouldn't happen"),
                 // No need to check, if the column can be as wide as the content.
                 ColumnConstraint::ContentWidth => continue,
+                // These constraints aren't generated for random tables.
+                ColumnConstraint::AtLeastHeaderWidth | ColumnConstraint::MinReadable(_) => continue,
                 // Absolute width is defined.
                 ColumnConstraint::Absolute(absolute) => {
                     let mut expected = absolute_width(table, absolute);
@@ -356,7 +358,7 @@ fn enforce_constraints(
                     if expected < 3 {
                         expected = 3;
                     }
-                    if actual != expected.into() {
+                    if actual != usize::from(expected) {
                         return build_error(
                             &formatted,
                             &format!(
@@ -0,0 +1,82 @@
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+/// Raw lines are drawn verbatim above the next row or below the last row.
+#[test]
+fn raw_lines() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2"])
+        .add_raw_line("\u{c}")
+        .add_row(vec!["One", "Two"])
+        .add_raw_line("a raw line, which is wider than the table")
+        .set_footer(vec!["Total", "2"]);
+
+    println!("{table}");
+    let expected = "
++---------+---------+
+| Header1 | Header2 |
++===================+
+\u{c}
+| One     | Two     |
+a raw line, which is wider than the table
++===================+
+| Total   | 2       |
++---------+---------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // Raw lines don't belong to any row.
+    let rendered = table.render();
+    assert_eq!(rendered.row_lines(0), Some(4..5));
+    assert_eq!(rendered.footer_lines(), Some(7..8));
+}
+
+/// Raw lines are drawn below the header, if there aren't any rows.
+#[test]
+fn raw_lines_without_rows() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2"])
+        .add_raw_line("no data\nat all");
+
+    println!("{table}");
+    let expected = "
++---------+---------+
+| Header1 | Header2 |
+no data
+at all
++===================+
++---------+---------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Raw lines that repeat a separator are collapsed, while identical rows are kept.
+#[test]
+fn collapse_separators() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2"])
+        .add_raw_line("+===================+")
+        .add_row(vec!["One", "Two"])
+        .add_raw_line("|---------+---------|")
+        .add_row(vec!["One", "Two"]);
+    let uncollapsed = table.lines().count();
+
+    table.set_collapse_separators(true);
+    println!("{table}");
+    let expected = "
++---------+---------+
+| Header1 | Header2 |
++===================+
+| One     | Two     |
+|---------+---------|
+| One     | Two     |
++---------+---------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert_eq!(table.lines().count(), uncollapsed - 2);
+
+    // The owners of the remaining lines are still known.
+    let rendered = table.render();
+    assert_eq!(rendered.row_lines(0), Some(3..4));
+    assert_eq!(rendered.row_lines(1), Some(5..6));
+}
@@ -0,0 +1,99 @@
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+/// The rendered table knows its dimensions and the lines of each row.
+#[test]
+fn rendered_table() {
+    let mut annotated = Row::from(vec!["Three", "hidden", "Four\nlines"]);
+    annotated.add_annotation("Note");
+
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Hidden", "Header2"])
+        .add_row(vec!["One", "hidden", "Two"])
+        .add_row(annotated);
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_constraint(ColumnConstraint::Hidden);
+
+    let rendered = table.render();
+    println!("{rendered}");
+    let expected = "
++---------+---------+
+| Header1 | Header2 |
++===================+
+| One     | Two     |
+|---------+---------|
+| Three   | Four    |
+|         | lines   |
+  Note
++---------+---------+";
+    assert_eq!(expected, "\n".to_string() + &rendered.to_string());
+    assert_eq!(rendered.lines().len(), rendered.height());
+    assert_eq!(rendered.height(), 9);
+
+    assert_eq!(rendered.column_widths(), &[9, 0, 9]);
+    assert_eq!(rendered.header_lines(), Some(1..2));
+    assert_eq!(rendered.row_lines(0), Some(3..4));
+    assert_eq!(rendered.row_lines(1), Some(5..8));
+    assert_eq!(rendered.row_lines(2), None);
+    assert_eq!(rendered.layout(), &table.layout());
+}
+
+/// Tables without a header don't have header lines.
+#[test]
+fn rendered_table_without_header() {
+    let mut table = Table::new();
+    table.add_row(vec!["One"]);
+
+    let rendered = table.render();
+    assert_eq!(rendered.header_lines(), None);
+    assert_eq!(rendered.row_lines(0), Some(1..2));
+}
+
+/// The arranged columns match the widths of the rendered table.
+#[test]
+fn arranged_columns() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Hidden", "Header2"])
+        .add_row(vec!["One", "hidden", "This is some longer text"])
+        .set_width(30)
+        .set_content_arrangement(ContentArrangement::Dynamic);
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_constraint(ColumnConstraint::Hidden);
+    table.column_mut(2).unwrap().set_padding((2, 1));
+
+    let columns = table.arranged_columns();
+    let widths: Vec<u16> = columns.iter().map(ArrangedColumn::width).collect();
+    assert_eq!(widths, table.render().column_widths());
+    assert!(columns[1].is_hidden());
+    assert_eq!(columns[2].padding(), (2, 1));
+    assert_eq!(columns[2].content_width() + 3, columns[2].width());
+}
+
+/// A measured table renders exactly like the original table with the same width.
+#[test]
+fn measured_table() {
+    let mut table = Table::new();
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![Cell::new("Header1"), Cell::new("Header2")])
+        .add_row(vec!["Some long content", "Two\nlines"])
+        .add_row(vec![Cell::new("Spanned cell with content").set_colspan(2)]);
+    table.column_mut(1).unwrap().set_header_icon("↑", "^");
+
+    let mut measured = table.measure();
+    for width in [10, 20, 30, 80] {
+        let output = measured.rerender_with_width(width);
+        table.set_width(width);
+        assert_eq!(output, table.to_string());
+        assert_eq!(
+            measured.column_display_widths(),
+            table.column_display_widths()
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use comfy_table::presets::{NOTHING, UTF8_FULL};
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+fn get_table() -> Table {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Price"])
+        .add_row_group("Fruits", vec![vec!["Apple", "1.20"], vec!["Pear", "0.80"]])
+        .add_row_group("Vegetables", vec![vec!["Leek", "2.10"]])
+        .add_row_group("Empty", Vec::<Row>::new());
+
+    table
+}
+
+/// Each group is introduced by its label and separated from the other groups.
+#[test]
+fn row_groups() {
+    let mut table = get_table();
+    table.load_preset(UTF8_FULL);
+    println!("{table}");
+    let expected = "
+┌───────┬───────┐
+│ Name  ┆ Price │
+╞═══════╪═══════╡
+│ Fruits        │
+├╌╌╌╌╌╌╌┬╌╌╌╌╌╌╌┤
+│ Apple ┆ 1.20  │
+├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+│ Pear  ┆ 0.80  │
+├╌╌╌╌╌╌╌┴╌╌╌╌╌╌╌┤
+│ Vegetables    │
+├╌╌╌╌╌╌╌┬╌╌╌╌╌╌╌┤
+│ Leek  ┆ 2.10  │
+└───────┴───────┘";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // The labels don't belong to any row.
+    let rendered = table.render();
+    assert_eq!(rendered.row_lines(0), Some(5..6));
+    assert_eq!(rendered.row_lines(2), Some(11..12));
+    assert!(table.audit_widths().is_empty());
+}
+
+/// Presets without any lines only display the labels.
+#[test]
+fn row_groups_without_lines() {
+    let mut table = get_table();
+    table.load_preset(NOTHING);
+    println!("{table}");
+    let expected = "
+ Name   Price 
+ Fruits       
+ Apple  1.20  
+ Pear   0.80  
+ Vegetables   
+ Leek   2.10  ";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Rows that are added after a group belong to that group. Long labels are wrapped.
+#[test]
+fn row_groups_without_header() {
+    let mut table = Table::new();
+    table
+        .add_row(vec!["Ungrouped", "0"])
+        .add_row_group("A rather long label for a group", vec![vec!["One", "1"]])
+        .add_row(vec!["Two", "2"]);
+    println!("{table}");
+    let expected = "
++-----------+---+
+| Ungrouped | 0 |
+|-----------+---|
+| A rather long |
+| label for a   |
+| group         |
+|-----------+---|
+| One       | 1 |
+|-----------+---|
+| Two       | 2 |
++-----------+---+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
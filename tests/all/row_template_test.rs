@@ -0,0 +1,61 @@
+use comfy_table::{Cell, CellAlignment, RowTemplate, Table};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn row_template() {
+    let mut template = RowTemplate::new();
+    template
+        .set_column(0, |cell| cell.set_alignment(CellAlignment::Center))
+        .set_column(2, |cell| Cell::new(format!("{} €", cell.content())));
+
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Item", "Amount", "Price"])
+        .add_row(vec!["Unformatted", "1", "0.50"])
+        .set_row_template(template)
+        .add_row(vec!["Apple", "3", "1.20"])
+        .add_rows(vec![vec!["Pear", "12", "0.80"]])
+        .add_row(vec![Cell::new("Gift").set_colspan(2), Cell::new("0.00")]);
+
+    assert!(table.row_template().unwrap().has_column(2));
+    table.remove_row_template().add_row(vec!["Plain", "1", "2"]);
+
+    let expected = "
++-------------+--------+--------+
+| Item        | Amount | Price  |
++===============================+
+| Unformatted | 1      | 0.50   |
+|-------------+--------+--------|
+|    Apple    | 3      | 1.20 € |
+|-------------+--------+--------|
+|     Pear    | 12     | 0.80 € |
+|-------------+--------+--------|
+|         Gift         | 0.00 € |
+|-------------+--------+--------|
+| Plain       | 1      | 2      |
++-------------+--------+--------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn replace_column_formatter() {
+    let mut template = RowTemplate::new();
+    template
+        .set_column(0, |cell| cell.set_alignment(CellAlignment::Center))
+        .set_column(0, |cell| cell.set_alignment(CellAlignment::Right));
+    template.remove_column(1);
+
+    let mut table = Table::new();
+    table
+        .set_row_template(template)
+        .add_row(vec!["A", "Wide"])
+        .add_row(vec!["Wide", "B"]);
+
+    let expected = "
++------+------+
+|    A | Wide |
+|------+------|
+| Wide | B    |
++------+------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
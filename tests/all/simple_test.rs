@@ -100,3 +100,35 @@ fn lines() {
 
     assert_eq!(actual.collect::<Vec<String>>(), expected);
 }
+
+/// Header icons are displayed in front of the header and are part of the column's width.
+#[test]
+fn header_icon() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Alerts", "Status"])
+        .add_row(vec!["2", "ok"]);
+    table.column_mut(0).unwrap().set_header_icon("⚠", "!");
+    table.column_mut(1).unwrap().set_header_icon("✅", "OK");
+
+    println!("{table}");
+    let expected = "
++----------+-----------+
+| ⚠ Alerts | ✅ Status |
++======================+
+| 2        | ok        |
++----------+-----------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    println!("{}", table.to_copy_friendly_string());
+    let expected = "
++----------+-----------+
+| ! Alerts | OK Status |
++======================+
+| 2        | ok        |
++----------+-----------+";
+    assert_eq!(
+        expected,
+        "\n".to_string() + &table.to_copy_friendly_string()
+    );
+}
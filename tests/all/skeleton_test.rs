@@ -0,0 +1,48 @@
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+/// The skeleton has the same column widths as the rendered table, but blank rows.
+#[test]
+fn skeleton() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Status"])
+        .add_row(vec!["database-primary", "ok"]);
+
+    let skeleton: Vec<String> = table.render_skeleton(2).collect();
+    println!("{}", skeleton.join("\n"));
+    let expected = "
++------------------+--------+
+| Name             | Status |
++===========================+
+|                  |        |
+|------------------+--------|
+|                  |        |
++------------------+--------+";
+    assert_eq!(expected, "\n".to_string() + &skeleton.join("\n"));
+
+    // The skeleton lines have the same width as the actual table.
+    let line = table.lines().next().unwrap();
+    assert_eq!(line, skeleton[0]);
+}
+
+/// Dynamic arrangement is respected by the skeleton.
+#[test]
+fn skeleton_dynamic_arrangement() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Description"])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(25)
+        .add_row(vec!["Comfy", "This is a very long description"]);
+
+    let skeleton: Vec<String> = table.render_skeleton(1).collect();
+    println!("{}", skeleton.join("\n"));
+    let expected = "
++-------+---------------+
+| Name  | Description   |
++=======================+
+|       |               |
++-------+---------------+";
+    assert_eq!(expected, "\n".to_string() + &skeleton.join("\n"));
+}
@@ -0,0 +1,110 @@
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+/// Rows can be sorted by different columns, while the header stays in place.
+#[test]
+fn sort_rows() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Size"])
+        .add_row(vec!["b", "9"])
+        .add_row(vec!["a", "10"])
+        .add_row(vec!["c"]);
+
+    table.sort_rows_numeric(1, SortOrder::Descending);
+    println!("{table}");
+    let expected = "
++------+------+
+| Name | Size |
++=============+
+| a    | 10   |
+|------+------|
+| b    | 9    |
+|------+------|
+| c    |      |
++------+------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // Plain text comparison sorts "10" before "9".
+    table.sort_rows(1, SortOrder::Ascending);
+    let names: Vec<String> = table.column_values(0).map(Cell::content).collect();
+    assert_eq!(names, vec!["c", "a", "b"]);
+
+    table.sort_rows(0, SortOrder::Ascending);
+    let names: Vec<String> = table.column_values(0).map(Cell::content).collect();
+    assert_eq!(names, vec!["a", "b", "c"]);
+}
+
+/// Rows are sorted within their row group.
+/// Raw lines stay in place, the rows above and below them are sorted separately.
+#[test]
+fn sort_rows_in_groups() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Product name"])
+        .add_row_group("Fruits", vec![vec!["Zucchini"], vec!["Apple"]])
+        .add_row_group("Vegetables", vec![vec!["Leek"], vec!["Banana"]])
+        .add_raw_line("--- page 2 ---")
+        .add_row(vec!["Pear"])
+        .add_row(vec!["Cherry"]);
+
+    table.sort_rows(0, SortOrder::Ascending);
+    let names: Vec<String> = table.column_values(0).map(Cell::content).collect();
+    assert_eq!(
+        names,
+        vec!["Apple", "Zucchini", "Banana", "Leek", "Cherry", "Pear"]
+    );
+
+    println!("{table}");
+    let expected = "
++--------------+
+| Product name |
++==============+
+| Fruits       |
+|--------------|
+| Apple        |
+|--------------|
+| Zucchini     |
+|--------------|
+| Vegetables   |
+|--------------|
+| Banana       |
+|--------------|
+| Leek         |
+|--------------|
+--- page 2 ---
+| Cherry       |
+|--------------|
+| Pear         |
++--------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Rows are sorted by the cells, which are displayed in the given column.
+/// Tables with cells that span multiple rows aren't sorted.
+#[test]
+fn sort_rows_with_spans() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Kind", "Size"])
+        .add_row(vec!["b", "file", "2"])
+        .add_row(vec![Cell::new("c").set_colspan(2), Cell::new("1")])
+        .add_row(vec!["a", "dir", "3"]);
+
+    table.sort_rows_numeric(2, SortOrder::Ascending);
+    let names: Vec<String> = table.column_values(0).map(Cell::content).collect();
+    assert_eq!(names, vec!["b", "a"]);
+    assert_eq!(table.cell_content(0, 0), Some("c".to_string()));
+
+    // The spanned cell is compared in both of its columns.
+    table.sort_rows(1, SortOrder::Descending);
+    assert_eq!(table.cell_content(0, 1), Some("file".to_string()));
+    assert_eq!(table.cell_content(1, 1), Some("dir".to_string()));
+    assert_eq!(table.cell_content(2, 1), Some("c".to_string()));
+
+    table.add_row(vec![Cell::new("z").set_rowspan(2), Cell::new("dir")]);
+    table.add_row(vec!["link", "0"]);
+    let expected = table.to_string();
+    table.sort_rows(0, SortOrder::Ascending);
+    assert_eq!(expected, table.to_string());
+}
@@ -0,0 +1,227 @@
+use comfy_table::presets::UTF8_FULL;
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+use super::assert_table_line_width;
+
+fn get_table() -> Table {
+    let mut table = Table::new();
+    table
+        .set_header(vec![
+            Cell::new("Interface"),
+            Cell::new("RX stats (pkts/bytes)").set_colspan(2),
+            Cell::new("TX stats (pkts/bytes)").set_colspan(2),
+        ])
+        .add_row(vec!["eth0", "1024", "2 MB", "512", "1 MB"])
+        .add_row(vec![
+            Cell::new("wlan0\nwireless").set_rowspan(2),
+            Cell::new("down").set_colspan(4),
+        ])
+        .add_row(vec!["1", "2", "3", "4"]);
+
+    table
+}
+
+/// Cells can span multiple columns and rows.
+#[test]
+fn colspan_and_rowspan() {
+    let table = get_table();
+
+    println!("{table}");
+    let expected = "
++-----------+-----------------------+-----------------------+
+| Interface | RX stats (pkts/bytes) | TX stats (pkts/bytes) |
++===========================================================+
+| eth0      | 1024      | 2 MB      | 512       | 1 MB      |
+|-----------+-----------+-----------+-----------+-----------|
+| wlan0     | down                                          |
+|           |-----------+-----------+-----------+-----------|
+| wireless  | 1         | 2         | 3         | 4         |
++-----------+-----------+-----------+-----------+-----------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Merged cells are drawn with the matching intersections.
+#[test]
+fn spans_utf8_borders() {
+    let mut table = get_table();
+    table.load_preset(UTF8_FULL);
+
+    println!("{table}");
+    let expected = "
+┌───────────┬───────────────────────┬───────────────────────┐
+│ Interface ┆ RX stats (pkts/bytes) ┆ TX stats (pkts/bytes) │
+╞═══════════╪═══════════╪═══════════╪═══════════╪═══════════╡
+│ eth0      ┆ 1024      ┆ 2 MB      ┆ 512       ┆ 1 MB      │
+├╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌┴╌╌╌╌╌╌╌╌╌╌╌┴╌╌╌╌╌╌╌╌╌╌╌┴╌╌╌╌╌╌╌╌╌╌╌┤
+│ wlan0     ┆ down                                          │
+│           ├╌╌╌╌╌╌╌╌╌╌╌┬╌╌╌╌╌╌╌╌╌╌╌┬╌╌╌╌╌╌╌╌╌╌╌┬╌╌╌╌╌╌╌╌╌╌╌┤
+│ wireless  ┆ 1         ┆ 2         ┆ 3         ┆ 4         │
+└───────────┴───────────┴───────────┴───────────┴───────────┘";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Merged cells are wrapped according to the combined width of their columns.
+#[test]
+fn spans_dynamic_arrangement() {
+    let mut table = get_table();
+    table
+        .set_width(40)
+        .set_content_arrangement(ContentArrangement::Dynamic);
+
+    println!("{table}");
+    let expected = "
++-------+---------------+--------------+
+| Inter | RX stats      | TX stats     |
+| face  | (pkts/bytes)  | (pkts/bytes) |
++======================================+
+| eth0  | 1024  | 2 MB  | 512   | 1 MB |
+|-------+-------+-------+-------+------|
+| wlan0 | down                         |
+|       |-------+-------+-------+------|
+| wirel | 1     | 2     | 3     | 4    |
+| ess   |       |       |       |      |
++-------+-------+-------+-------+------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert_table_line_width(&table, 40);
+}
+
+/// A cell spanning multiple rows, which doesn't fit into these rows, makes the last row grow.
+#[test]
+fn rowspan_grows_last_row() {
+    let mut table = Table::new();
+    table
+        .add_row(vec![
+            Cell::new("one\ntwo\nthree\nfour").set_rowspan(2),
+            Cell::new("a"),
+        ])
+        .add_row(vec!["b"]);
+
+    println!("{table}");
+    let expected = "
++-------+---+
+| one   | a |
+|       |---|
+| two   | b |
+| three |   |
+| four  |   |
++-------+---+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Cells are addressed by the columns and rows they're displayed in, just like hit testing does.
+#[test]
+fn cell_coordinates_with_spans() {
+    let mut table = get_table();
+
+    assert_eq!(table.cell(1, 3).unwrap().content(), "down");
+    assert_eq!(table.cell(2, 0).unwrap().content(), "wlan0\nwireless");
+    assert_eq!(table.cell(2, 1).unwrap().content(), "1");
+    // The content is looked up the same way.
+    assert_eq!(table.cell_content(1, 3), Some("down".to_string()));
+    assert_eq!(
+        table.cell_content(2, 0),
+        Some("wlan0\nwireless".to_string())
+    );
+    assert_eq!(table.cell_content(2, 4), Some("4".to_string()));
+
+    // Line 7 is the second line of the last row, column 15 is in the second column.
+    let layout = table.layout();
+    let (row, column) = layout.hit_test(7, 15).unwrap();
+    assert_eq!(table.cell(row, column).unwrap().content(), "1");
+
+    table.set_cell(2, 4, "four");
+    *table.cell_mut(1, 0).unwrap() = Cell::new("lo");
+    assert_eq!(table.cell_content(2, 3), Some("four".to_string()));
+    assert_eq!(table.cell_content(1, 0), Some("lo".to_string()));
+}
+
+/// The positions of spanned cells are looked up again, after the cells of the table changed.
+#[test]
+fn cell_coordinates_after_changes() {
+    let mut table = get_table();
+    assert_eq!(table.cell_content(2, 1), Some("1".to_string()));
+
+    // Replacing a cell with the same spans doesn't move any cell.
+    table.set_cell(2, 0, Cell::new("wlan1").set_rowspan(2));
+    assert_eq!(table.cell_content(2, 0), Some("wlan1".to_string()));
+    assert_eq!(table.cell_content(2, 1), Some("1".to_string()));
+
+    // Cells are moved, if the spans of a cell change.
+    table.set_cell(1, 0, "wlan0");
+    assert_eq!(table.cell_content(2, 0), Some("1".to_string()));
+    table.set_cell(2, 0, Cell::new("one").set_colspan(2));
+    assert_eq!(table.cell_content(2, 1), Some("one".to_string()));
+    assert_eq!(table.cell_content(2, 2), Some("2".to_string()));
+
+    *table.cell_mut(0, 0).unwrap() = Cell::new("eth0").set_colspan(3);
+    assert_eq!(table.cell_content(0, 2), Some("eth0".to_string()));
+    assert_eq!(table.cell_content(0, 3), Some("1024".to_string()));
+
+    table.add_row(vec![Cell::new("last").set_colspan(5)]);
+    assert_eq!(table.cell_content(3, 4), Some("last".to_string()));
+}
+
+/// On tables with spans, only cells that are displayed can be replaced.
+/// Positions outside of all cells are left alone instead of shifting the spans.
+#[test]
+fn set_cell_without_cell_with_spans() {
+    let mut table = get_table();
+    let expected = table.to_string();
+
+    assert!(table.cell(2, 5).is_none());
+    table.set_cell(2, 5, "five").set_cell(3, 0, "missing");
+    assert!(table.cell(2, 5).is_none());
+    assert_eq!(table.column_count(), 5);
+    assert_eq!(expected, table.to_string());
+}
+
+/// The values of a column are the cells, which are displayed in that column.
+/// Cells that span multiple columns don't belong to any single column.
+#[test]
+fn column_values_with_spans() {
+    let table = get_table();
+
+    let values =
+        |column| -> Vec<String> { table.column_values(column).map(Cell::content).collect() };
+    assert_eq!(values(0), vec!["eth0", "wlan0\nwireless"]);
+    assert_eq!(values(1), vec!["1024", "1"]);
+    assert_eq!(values(4), vec!["1 MB", "4"]);
+    // Both agree with the coordinates of `Table::cell`.
+    assert_eq!(table.cell(2, 1).unwrap().content(), "1");
+}
+
+/// Numeric formats and header icons apply to the cells, which are displayed in their column.
+/// Cells that span multiple columns aren't formatted.
+#[test]
+fn column_decorations_with_spans() {
+    let mut table = Table::new();
+    table
+        .set_header(vec![Cell::new("Item").set_colspan(2), Cell::new("Price")])
+        .add_row(vec![Cell::new("12.25").set_colspan(2), Cell::new("2.5")])
+        .add_row(vec!["1", "3", "1200"]);
+
+    let mut format = NumericFormat::new();
+    format.set_precision(3);
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_numeric_format(format.clone())
+        .set_header_icon("#", "#");
+    table
+        .column_mut(2)
+        .unwrap()
+        .set_numeric_format(format)
+        .set_header_icon("$", "$");
+
+    println!("{table}");
+    let expected = "
++-----------+----------+
+| Item      | $ Price  |
++======================+
+| 12.25     |    2.500 |
+|---+-------+----------|
+| 1 | 3.000 | 1200.000 |
++---+-------+----------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
@@ -0,0 +1,405 @@
+use comfy_table::presets::{NOTHING, UTF8_FULL};
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+fn get_rows() -> Vec<Row> {
+    let mut annotated = Row::from(vec!["Three", "Four\nlines", "Five"]);
+    annotated.add_annotation("Note");
+
+    vec![
+        Row::from(vec!["One", "Two", "Some longer text"]),
+        annotated,
+        Row::from(vec![Cell::new("Spanned").set_colspan(2), Cell::new("Six")]),
+        Row::from(vec!["Seven", "Eight", "Nine"]),
+    ]
+}
+
+/// Streamed rows are rendered exactly like a table, which contains all of these rows.
+fn assert_stream_matches(mut table: Table) {
+    table.add_rows(get_rows());
+    let expected = table.to_string();
+    let lines: Vec<String> = table.render_rows_iter(get_rows()).collect();
+
+    assert_eq!(expected, lines.join("\n"));
+}
+
+#[test]
+fn stream_rows() {
+    for preset in [presets::ASCII_FULL, UTF8_FULL, NOTHING] {
+        let mut table = Table::new();
+        table
+            .load_preset(preset)
+            .set_header(vec!["Header1", "Header2", "Header3"]);
+        assert_stream_matches(table);
+    }
+}
+
+/// The columns are arranged once, based on the content of the table.
+#[test]
+fn stream_rows_with_dynamic_arrangement() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2", "Header3"])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(30);
+    let lines: Vec<String> = table.render_rows_iter(get_rows()).collect();
+
+    let expected = "
++---------+---------+--------+
+| Header1 | Header2 | Header |
+|         |         | 3      |
++============================+
+| One     | Two     | Some   |
+|         |         | longer |
+|         |         | text   |
+|---------+---------+--------|
+| Three   | Four    | Five   |
+|         | lines   |        |
+  Note
+|---------+---------+--------|
+| Spanned           | Six    |
+|---------+---------+--------|
+| Seven   | Eight   | Nine   |
++---------+---------+--------+";
+    assert_eq!(expected, "\n".to_string() + &lines.join("\n"));
+}
+
+/// Tables without a header and without rows can be streamed as well.
+#[test]
+fn stream_rows_edge_cases() {
+    assert_stream_matches(Table::new());
+
+    let mut table = Table::new();
+    table.set_header(vec!["Header1", "Header2"]);
+    let lines: Vec<String> = table.render_rows_iter(Vec::<Row>::new()).collect();
+    assert_eq!(table.to_string(), lines.join("\n"));
+}
+
+/// The rows of a table, which is streamed, don't pull in the raw lines of the table's own rows.
+#[test]
+fn stream_rows_ignore_raw_lines() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2", "Header3"])
+        .add_raw_line("Raw line");
+    let lines: Vec<String> = table.render_rows_iter(get_rows()).collect();
+    assert!(!lines.iter().any(|line| line == "Raw line"));
+}
+
+/// The title is only drawn above the first row and the caption below the last one.
+#[test]
+fn stream_rows_with_title_and_caption() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2", "Header3"])
+        .set_title("Title")
+        .set_caption("Caption");
+    assert_stream_matches(table.clone());
+
+    table.set_footer(vec!["Total"]);
+    assert_stream_matches(table.clone());
+
+    let lines: Vec<String> = table.render_rows_iter(Vec::<Row>::new()).collect();
+    assert_eq!(table.to_string(), lines.join("\n"));
+}
+
+/// Tables written to a writer contain the same lines as the displayed table.
+#[test]
+fn write_table_to_writers() {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec!["First", "Second", "Third"])
+        .add_rows(get_rows());
+    let expected = table.to_string() + "\n";
+
+    let mut output = std::io::BufWriter::new(Vec::new());
+    table.write_to(&mut output).unwrap();
+    assert_eq!(
+        expected,
+        String::from_utf8(output.into_inner().unwrap()).unwrap()
+    );
+
+    let mut output = String::new();
+    table.write_to_fmt(&mut output).unwrap();
+    assert_eq!(expected, output);
+}
+
+/// Pages repeat the header and share the column widths of the whole table.
+#[test]
+fn render_pages() {
+    let mut table = Table::new();
+    table
+        .set_title("Inventory")
+        .set_header(vec!["Item", "Count"])
+        .add_row(vec!["Apples", "3"])
+        .add_raw_line("--- fresh ---")
+        .add_row_group(
+            "Vegetables",
+            vec![vec!["Leek", "1"], vec!["A long item", "2"]],
+        )
+        .set_footer(vec!["Total", "6"])
+        .set_caption("End of inventory");
+
+    let pages: Vec<String> = table.render_pages(2).collect();
+    for page in &pages {
+        println!("{page}\n");
+    }
+    let expected = vec![
+        "
+       Inventory
++-------------+-------+
+| Item        | Count |
++=====================+
+| Apples      | 3     |
+|-------------+-------|
+--- fresh ---
+| Vegetables          |
+|-------------+-------|
+| Leek        | 1     |
++-------------+-------+",
+        "
++-------------+-------+
+| Item        | Count |
++=====================+
+| A long item | 2     |
++=====================+
+| Total       | 6     |
++-------------+-------+
+    End of inventory",
+    ];
+    assert_eq!(
+        expected,
+        pages
+            .iter()
+            .map(|page| "\n".to_string() + page)
+            .collect::<Vec<_>>()
+    );
+
+    // A table without rows is a single page.
+    let mut empty = Table::new();
+    empty.set_header(vec!["Item", "Count"]);
+    assert_eq!(
+        empty.render_pages(10).collect::<Vec<_>>(),
+        vec![empty.to_string()]
+    );
+}
+
+/// A repeated footer is displayed on every page.
+#[test]
+fn render_pages_repeat_footer() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Item", "Count"])
+        .add_row(vec!["Apples", "3"])
+        .add_row(vec!["Leek", "1"])
+        .set_footer(vec!["Total", "4"])
+        .set_repeat_footer(true);
+
+    let pages: Vec<String> = table.render_pages(1).collect();
+    let expected = "
++--------+-------+
+| Item   | Count |
++================+
+| Apples | 3     |
++================+
+| Total  | 4     |
++--------+-------+";
+    assert_eq!(expected, "\n".to_string() + &pages[0]);
+    assert_eq!(pages.len(), 2);
+    assert!(pages[1].contains("| Total  | 4     |"));
+}
+
+/// All pages but the last one end with the continuation border, if it's set.
+#[test]
+fn render_pages_continuation_border() {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_style(TableComponent::ContinuationBorder, '╌')
+        .set_header(vec!["Item", "Count"])
+        .add_row(vec!["Apples", "3"])
+        .add_row(vec![Cell::new("Leek, one of them").set_colspan(2)])
+        .add_row(vec!["Pears", "5"]);
+
+    let pages: Vec<String> = table.render_pages(2).collect();
+    let expected = [
+        "
+┌──────────┬────────┐
+│ Item     ┆ Count  │
+╞══════════╪════════╡
+│ Apples   ┆ 3      │
+├╌╌╌╌╌╌╌╌╌╌┴╌╌╌╌╌╌╌╌┤
+│ Leek, one of them │
+└╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┘",
+        "
+┌──────────┬────────┐
+│ Item     ┆ Count  │
+╞══════════╪════════╡
+│ Pears    ┆ 5      │
+└──────────┴────────┘",
+    ];
+    for (expected, page) in expected.iter().zip(&pages) {
+        assert_eq!(*expected, "\n".to_string() + page);
+    }
+
+    // The intersections can be replaced as well.
+    table.set_style(TableComponent::ContinuationBorderIntersections, '╌');
+    let pages: Vec<String> = table.render_pages(1).collect();
+    assert!(pages[0].ends_with("└╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┘"));
+}
+
+/// Tables rendered with a deadline consist of the same lines, regardless of how often the
+/// rendering has been interrupted.
+#[test]
+fn render_with_deadline() {
+    use std::time::Duration;
+
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2", "Header3"])
+        .set_title("Title")
+        .add_raw_line("Before the first row")
+        .add_row_group("Group", get_rows())
+        .add_raw_line("After the group")
+        .add_rows(get_rows())
+        .add_raw_line("After the last row")
+        .set_caption("Caption");
+
+    // Without any budget, each call renders a single row.
+    let mut partial = table.render_with_deadline(Duration::ZERO);
+    let mut lines = partial.lines().to_vec();
+    let mut calls = 1;
+    while let Some(continuation) = partial.continuation() {
+        assert_eq!(continuation.rendered_rows(), calls);
+        assert_eq!(continuation.remaining_rows(), 8 - calls);
+        partial = continuation.clone().resume(Duration::ZERO);
+        lines.extend_from_slice(partial.lines());
+        calls += 1;
+    }
+    assert_eq!(calls, 8);
+    assert_eq!(table.to_string(), lines.join("\n"));
+
+    table.set_footer(vec!["Total"]);
+    let partial = table.render_with_deadline(Duration::from_secs(60));
+    assert!(partial.is_complete());
+    assert_eq!(table.to_string(), partial.lines().join("\n"));
+
+    // Empty tables are complete right away.
+    let partial = Table::new().render_with_deadline(Duration::ZERO);
+    assert!(partial.is_complete());
+    assert_eq!(Table::new().to_string(), partial.lines().join("\n"));
+}
+
+/// Tables with cells that span multiple rows are rendered in one go, as they can't be split
+/// between their rows.
+#[test]
+fn render_with_deadline_rowspan() {
+    use std::time::Duration;
+
+    let mut table = Table::new();
+    table
+        .set_header(vec!["A", "B"])
+        .add_row(vec![Cell::new("x").set_rowspan(2), Cell::new("y")])
+        .add_row(vec!["z"]);
+
+    let partial = table.render_with_deadline(Duration::ZERO);
+    assert!(partial.is_complete());
+    let expected = "
++---+---+
+| A | B |
++=======+
+| x | y |
+|   |---|
+|   | z |
++---+---+";
+    assert_eq!(expected, "\n".to_string() + &partial.lines().join("\n"));
+    assert_eq!(table.to_string(), partial.lines().join("\n"));
+}
+
+/// Streamed rows are decorated just like the rows of the table itself.
+#[cfg(feature = "tty")]
+#[test]
+fn stream_rows_decorated() {
+    let mut table = Table::new();
+    table
+        .force_no_tty()
+        .enforce_styling()
+        .set_header(vec!["Host", "Port"])
+        .set_zebra_striping(Color::Black, Color::DarkGrey);
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_validator(|content| content.parse::<u16>().is_ok());
+
+    let rows = vec![
+        Row::from(vec!["web-1", "443"]),
+        Row::from(vec![Cell::new("web-2"), Cell::new("http")]),
+        Row::from(vec![
+            Cell::new("web-3"),
+            Cell::new("https").fg(Color::Green),
+        ]),
+    ];
+    table.add_rows(rows.clone());
+    let lines: Vec<String> = table.render_rows_iter(rows).collect();
+    assert_eq!(table.to_string(), lines.join("\n"));
+}
+
+/// Numbers in streamed rows are formatted like the numbers in the rows of the table itself.
+#[test]
+fn stream_rows_numeric_format() {
+    let mut table = Table::new();
+    table.set_header(vec!["Metric", "Value"]);
+
+    let mut format = NumericFormat::new();
+    format.set_thousands_separator(',');
+    table.column_mut(1).unwrap().set_numeric_format(format);
+
+    let rows = vec![
+        Row::from(vec!["latency", "12.5"]),
+        Row::from(vec!["requests", "1200"]),
+        Row::from(vec!["ratio", "-0.125"]),
+    ];
+    table.add_rows(rows.clone());
+    let lines: Vec<String> = table.render_rows_iter(rows).collect();
+    assert_eq!(table.to_string(), lines.join("\n"));
+}
+
+/// Border characters in streamed rows are resolved like in the rows of the table itself.
+#[test]
+fn stream_rows_border_collisions() {
+    use std::time::Duration;
+
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Pattern", "Matches"])
+        .set_border_collision(BorderCollision::Escape);
+
+    let rows = vec![Row::from(vec!["a|b", "2"]), Row::from(vec!["+-+", "none"])];
+    table.add_rows(rows.clone());
+    let lines: Vec<String> = table.render_rows_iter(rows).collect();
+    assert_eq!(table.to_string(), lines.join("\n"));
+
+    let partial = table.render_with_deadline(Duration::from_secs(60));
+    assert_eq!(table.to_string(), partial.lines().join("\n"));
+}
+
+/// The background colors of columns are painted through streamed rows as well.
+#[cfg(feature = "tty")]
+#[test]
+fn stream_rows_column_background() {
+    let mut table = Table::new();
+    table
+        .force_no_tty()
+        .enforce_styling()
+        .set_header(vec!["Host", "Load", "Memory"]);
+    table.column_mut(1).unwrap().set_bg(Color::DarkGrey);
+
+    let rows = vec![
+        Row::from(vec!["web-1", "0.3", "12%"]),
+        Row::from(vec![Cell::new("web-2"), Cell::new("0.9").bg(Color::Red)]),
+    ];
+    table.add_rows(rows.clone());
+    let lines: Vec<String> = table.render_rows_iter(rows).collect();
+    assert_eq!(table.to_string(), lines.join("\n"));
+}
@@ -85,3 +85,232 @@ fn styled_text_only_table() {
 
     assert_eq!(expected, "\n".to_string() + &table.to_string());
 }
+
+/// The styling can be exported as spans of the plain text.
+#[test]
+fn style_spans() {
+    let mut table = get_preset_table();
+    table.enforce_styling();
+
+    let (text, spans) = table.to_string_with_spans();
+    let mut plain = get_preset_table();
+    plain.force_no_tty();
+    assert_eq!(text, plain.to_string());
+
+    let styled: Vec<(&str, Option<Color>)> = spans
+        .iter()
+        .map(|span| (&text[span.range.clone()], span.fg))
+        .collect();
+    assert_eq!(
+        &styled[..5],
+        &[
+            (" Header1             ", None),
+            (" Header2              ", Some(Color::Green)),
+            (" Header3                       ", None),
+            (" This is a bold text ", None),
+            (" This is a green text ", Some(Color::Green)),
+        ]
+    );
+    assert_eq!(spans[0].attributes, vec![Attribute::Bold]);
+    assert_eq!(spans[2].bg, Some(Color::Black));
+}
+
+/// Only the text of cells is part of the spans, if only the text is styled.
+#[test]
+fn style_spans_text_only() {
+    let mut table = Table::new();
+    let mut row = Row::from(vec![Cell::new("Error").fg(Color::Red), Cell::new("plain")]);
+    row.add_annotation(Cell::new("Check the logs").fg(Color::Yellow));
+    table.add_row(row).style_text_only();
+
+    let (text, spans) = table.to_string_with_spans();
+    println!("{text}");
+    let expected = "
++-------+-------+
+| Error | plain |
+  Check the logs
++-------+-------+";
+    assert_eq!(expected, "\n".to_string() + &text);
+
+    let styled: Vec<(&str, Option<Color>)> = spans
+        .iter()
+        .map(|span| (&text[span.range.clone()], span.fg))
+        .collect();
+    assert_eq!(
+        styled,
+        vec![
+            ("Error", Some(Color::Red)),
+            ("Check the logs", Some(Color::Yellow)),
+        ]
+    );
+}
+
+/// Copy-friendly output never contains any styling.
+#[test]
+fn copy_friendly_without_styling() {
+    let mut table = get_preset_table();
+    table.enforce_styling();
+
+    let output = table.to_copy_friendly_string();
+    assert!(!output.contains('\x1b'));
+    assert!(output.is_ascii());
+}
+
+/// The styling of rows and the zebra striping apply to all cells of a row, unless the cells are
+/// styled themselves.
+#[test]
+fn row_styling_and_zebra_striping() {
+    let mut error = Row::from(vec![
+        Cell::new("Error"),
+        Cell::new("failed").fg(Color::Yellow),
+    ]);
+    error.set_fg(Color::Red).add_attribute(Attribute::Bold);
+
+    let mut table = Table::new();
+    table
+        .set_zebra_striping(Color::Black, Color::DarkGrey)
+        .add_row(vec!["One", "Two"])
+        .add_row(error)
+        .add_row(vec![Cell::new("Three"), Cell::new("Four").bg(Color::Blue)]);
+
+    let (text, spans) = table.to_string_with_spans();
+    let styled: Vec<(&str, Option<Color>, Option<Color>)> = spans
+        .iter()
+        .map(|span| (text[span.range.clone()].trim(), span.fg, span.bg))
+        .collect();
+    assert_eq!(
+        styled,
+        vec![
+            ("One", None, Some(Color::Black)),
+            ("Two", None, Some(Color::Black)),
+            ("Error", Some(Color::Red), Some(Color::DarkGrey)),
+            ("failed", Some(Color::Yellow), Some(Color::DarkGrey)),
+            ("Three", None, Some(Color::Black)),
+            ("Four", None, Some(Color::Blue)),
+        ]
+    );
+    assert_eq!(spans[2].attributes, vec![Attribute::Bold]);
+    assert_eq!(spans[3].attributes, vec![Attribute::Bold]);
+    assert!(spans[4].attributes.is_empty());
+}
+
+/// Cells rejected by the validator of their column are displayed in red, unless they have their
+/// own foreground color.
+#[test]
+fn invalid_cells() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Host", "Port"])
+        .add_row(vec!["web-1", "443"])
+        .add_row(vec!["web-2", "http"])
+        .add_row(vec![
+            Cell::new("web-3"),
+            Cell::new("https").fg(Color::Green),
+        ]);
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_validator(|content| content.parse::<u16>().is_ok());
+
+    // Cells with their own color keep it.
+    let (text, spans) = table.to_string_with_spans();
+    let styled: Vec<(&str, Option<Color>)> = spans
+        .iter()
+        .map(|span| (text[span.range.clone()].trim(), span.fg))
+        .collect();
+    assert_eq!(
+        styled,
+        vec![("http", Some(Color::Red)), ("https", Some(Color::Green))]
+    );
+}
+
+/// The header is styled by the theme, even if it's set after the theme has been applied.
+#[test]
+fn theme_header_styling() {
+    let mut theme = Theme::new(presets::UTF8_FULL);
+    theme
+        .set_header_fg(Color::Cyan)
+        .add_header_attribute(Attribute::Bold)
+        .set_zebra_striping(Color::Black, Color::DarkGrey);
+
+    let mut table = Table::new();
+    table
+        .apply_theme(&theme)
+        .set_header(vec![Cell::new("Name"), Cell::new("Size").fg(Color::Green)])
+        .add_row(vec!["foo", "10"]);
+
+    let (text, spans) = table.to_string_with_spans();
+    let styled: Vec<(&str, Option<Color>, Option<Color>)> = spans
+        .iter()
+        .map(|span| (text[span.range.clone()].trim(), span.fg, span.bg))
+        .collect();
+    assert_eq!(
+        styled,
+        vec![
+            ("Name", Some(Color::Cyan), None),
+            ("Size", Some(Color::Green), None),
+            ("foo", None, Some(Color::Black)),
+            ("10", None, Some(Color::Black)),
+        ]
+    );
+    assert_eq!(spans[0].attributes, vec![Attribute::Bold]);
+    assert_eq!(spans[1].attributes, vec![Attribute::Bold]);
+}
+
+/// The same table can be rendered once styled and once without styling,
+/// without changing the table itself.
+#[test]
+fn style_overrides() {
+    let mut table = Table::new();
+    table.enforce_styling().add_row(vec![
+        Cell::new("Green").fg(Color::Green),
+        Cell::new("Plain"),
+    ]);
+
+    let mut plain = StyleOverrides::new();
+    plain
+        .set_styling(false)
+        .set_preset(presets::ASCII_BORDERS_ONLY);
+    let output = table.with_style_overrides(&plain, |table| table.to_string());
+    println!("{output}");
+    let expected = "
++---------------+
+| Green   Plain |
++---------------+";
+    assert_eq!(expected, "\n".to_string() + &output);
+
+    let styled = "
++-------+-------+
+|\u{1b}[38;5;10m Green \u{1b}[39m| Plain |
++-------+-------+";
+    assert_eq!(styled, "\n".to_string() + &table.to_string());
+}
+
+/// The background of a column is painted through all lines of its rows, including missing cells,
+/// unless the cells have a background of their own.
+#[test]
+fn column_background() {
+    let mut table = Table::new();
+    table
+        .load_preset(presets::ASCII_BORDERS_ONLY_CONDENSED)
+        .force_no_tty()
+        .enforce_styling()
+        .set_header(vec!["Name", "Notes"])
+        .add_row(vec!["One", "Two\nlines"])
+        .add_row(vec![Cell::new("Two").bg(Color::Red)])
+        .add_row(vec![Cell::new("Three")]);
+    table.column_mut(0).unwrap().set_bg(Color::Blue);
+    table.column_mut(1).unwrap().set_bg(Color::Green);
+    assert_eq!(table.column(0).unwrap().bg(), Some(Color::Blue));
+
+    let expected = "
++---------------+
+|\u{1b}[48;5;12m Name  \u{1b}[49m \u{1b}[48;5;10m Notes \u{1b}[49m|
++===============+
+|\u{1b}[48;5;12m One   \u{1b}[49m \u{1b}[48;5;10m Two   \u{1b}[49m|
+|\u{1b}[48;5;12m       \u{1b}[49m \u{1b}[48;5;10m lines \u{1b}[49m|
+|\u{1b}[48;5;9m Two   \u{1b}[49m \u{1b}[48;5;10m       \u{1b}[49m|
+|\u{1b}[48;5;12m Three \u{1b}[49m \u{1b}[48;5;10m       \u{1b}[49m|
++---------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
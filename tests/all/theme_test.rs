@@ -0,0 +1,80 @@
+use comfy_table::presets::UTF8_FULL;
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+/// Applying a theme loads its preset and pads existing and new columns.
+#[test]
+fn apply_theme() {
+    let mut theme = Theme::new(UTF8_FULL);
+    theme.set_padding((0, 1));
+
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2"])
+        .apply_theme(&theme)
+        .add_row(vec!["One", "Two", "Three"]);
+
+    println!("{table}");
+    let expected = "
+┌────────┬────────┬──────┐
+│Header1 ┆Header2 ┆      │
+╞════════╪════════╪══════╡
+│One     ┆Two     ┆Three │
+└────────┴────────┴──────┘";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert_eq!(table.theme(), Some(&theme));
+}
+
+/// Themes are loaded from their JSON definition. Missing fields fall back to their defaults.
+/// Colors use the same JSON representation, even if they're reexported from crossterm.
+#[cfg(all(feature = "serde", feature = "tty"))]
+#[test]
+fn theme_from_json() {
+    use std::str::FromStr;
+
+    let theme: Theme = r#"{
+        "preset": "││──╞═╪╡┆╌┼├┤┬┴┌┐└┘",
+        "header_fg": "Cyan",
+        "header_attributes": ["Bold"],
+        "zebra_striping": [{ "Rgb": { "r": 0, "g": 0, "b": 0 } }, "DarkGrey"]
+    }"#
+    .parse()
+    .unwrap();
+
+    let mut expected = Theme::new(UTF8_FULL);
+    expected
+        .set_header_fg(Color::Cyan)
+        .add_header_attribute(Attribute::Bold)
+        .set_zebra_striping(Color::Rgb { r: 0, g: 0, b: 0 }, Color::DarkGrey);
+    assert_eq!(theme, expected);
+
+    assert_eq!(Theme::from_str("{}").unwrap(), Theme::default());
+    assert!(Theme::from_str(r#"{ "padding": "none" }"#).is_err());
+}
+
+/// Ready-made themes can be overridden after they've been applied.
+/// Their selection colors are used for the row that's selected via a view state.
+#[cfg(feature = "tty")]
+#[test]
+fn ready_made_theme() {
+    let mut table = Table::new();
+    table
+        .apply_theme(&themes::OCEAN)
+        .load_preset(presets::ASCII_BORDERS_ONLY_CONDENSED)
+        .set_zebra_striping(Color::Reset, Color::Reset)
+        .enforce_styling()
+        .add_row(vec!["One"])
+        .add_row(vec!["Two"]);
+
+    let mut state = TableViewState::new();
+    state.set_selected_row(1);
+
+    let view = table.with_view_state(&state);
+    println!("{view}");
+    let expected = "
++-----+
+|\u{1b}[49m One \u{1b}[49m|
+|\u{1b}[48;5;14m\u{1b}[38;5;0m Two \u{1b}[49m\u{1b}[39m|
++-----+";
+    assert_eq!(expected, "\n".to_string() + &view.to_string());
+}
@@ -0,0 +1,67 @@
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+/// Titles and captions span the full width of the table and are centered by default.
+#[test]
+fn title_and_caption() {
+    let mut table = Table::new();
+    table
+        .set_title("Inventory")
+        .set_header(vec!["Header1", "Header2"])
+        .add_row(vec!["One", "Two"])
+        .set_caption(Cell::new("2 items").set_alignment(CellAlignment::Left));
+
+    println!("{table}");
+    let expected = "
+      Inventory
++---------+---------+
+| Header1 | Header2 |
++===================+
+| One     | Two     |
++---------+---------+
+2 items";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // The title doesn't belong to any row.
+    let rendered = table.render();
+    assert_eq!(rendered.row_lines(0), Some(4..5));
+}
+
+/// Titles, which are wider than the table, are wrapped.
+/// The title stays centered, when the width of the table changes.
+#[test]
+fn title_with_dynamic_width() {
+    let mut table = Table::new();
+    table
+        .set_title("A rather long title of the table")
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec!["Header1", "Header2"])
+        .add_row(vec!["Some long content", "Two"])
+        .set_width(20);
+
+    println!("{table}");
+    let expected = "
+ A rather long title
+    of the table
++---------+--------+
+| Header1 | Header |
+|         | 2      |
++==================+
+| Some    | Two    |
+| long    |        |
+| content |        |
++---------+--------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    table.set_width(40);
+    println!("{table}");
+    let expected = "
+   A rather long title of the
+             table
++-------------------+---------+
+| Header1           | Header2 |
++=============================+
+| Some long content | Two     |
++-------------------+---------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
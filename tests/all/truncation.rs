@@ -1,4 +1,6 @@
-use comfy_table::{ColumnConstraint::*, ContentArrangement, Row, Table, Width::*};
+use comfy_table::{
+    Cell, ColumnConstraint::*, ContentArrangement, Row, Table, TruncationStyle, Width::*,
+};
 use pretty_assertions::assert_eq;
 
 use crate::all::assert_table_line_width;
@@ -194,3 +196,116 @@ fn table_with_composite_utf8_emojis() {
     assert_table_line_width(&table, 15);
     assert_eq!(expected, "\n".to_string() + &table.to_string());
 }
+
+/// Columns without wrapping cut off their content and mark the cut with an indicator.
+#[test]
+fn no_wrap_column() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Path", "Size"])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(25)
+        .add_row(vec!["/usr/local/share/applications", "12 KB"])
+        .add_row(vec!["/tmp\n/var/tmp", "1 KB"]);
+    table.column_mut(0).unwrap().set_no_wrap(true);
+
+    println!("{table}");
+    let expected = "
++---------------+-------+
+| Path          | Size  |
++=======================+
+| /usr/local/s▶ | 12 KB |
+|---------------+-------|
+| /tmp▶         | 1 KB  |
++---------------+-------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+    assert_table_line_width(&table, 25);
+
+    // The full content is still available.
+    assert_eq!(
+        table.cell_content(0, 0),
+        Some("/usr/local/share/applications".to_string())
+    );
+}
+
+/// Rows with a min height are topped up with empty lines, also if cells span multiple columns.
+#[test]
+fn table_with_min_height() {
+    let mut first_row = Row::from(vec!["One", "Two"]);
+    first_row.min_height(2);
+    let mut second_row = Row::from(vec![Cell::new("Spanning").set_colspan(2)]);
+    second_row.min_height(3);
+
+    let mut table = Table::new();
+    table.add_row(first_row).add_row(second_row);
+
+    println!("{table}");
+    let expected = "
++-----+-----+
+| One | Two |
+|     |     |
+|-----+-----|
+| Spanning  |
+|           |
+|           |
++-----------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Double-width characters, which would be cut in half, are dropped as a whole.
+/// The remaining space is padded, so the column stays aligned.
+#[test]
+fn double_width_cut_boundary() {
+    let content = "漢字漢字漢字漢字漢字";
+    let styles = [
+        None,
+        Some(TruncationStyle::End("…".to_string())),
+        Some(TruncationStyle::Start("…".to_string())),
+        Some(TruncationStyle::Middle("…".to_string())),
+    ];
+
+    let mut tables = Vec::new();
+    for style in styles {
+        let mut table = Table::new();
+        table.add_row(vec![content, "x"]);
+        let column = table.column_mut(0).unwrap();
+        column.set_constraint(Absolute(Fixed(10)));
+        match style {
+            Some(style) => column.set_truncation(style),
+            None => column.set_no_wrap(true),
+        };
+        assert_table_line_width(&table, 16);
+        tables.push(table.to_string());
+    }
+
+    // Cells, which are cut off by the max height of their row.
+    let mut row = Row::from(vec![content, "x"]);
+    row.max_height(2);
+    let mut table = Table::new();
+    table.add_row(row);
+    table
+        .column_mut(0)
+        .unwrap()
+        .set_constraint(Absolute(Fixed(10)));
+    assert_table_line_width(&table, 16);
+    tables.push(table.to_string());
+
+    let expected = "
++----------+---+
+| 漢字漢▶  | x |
++----------+---+
++----------+---+
+| 漢字漢…  | x |
++----------+---+
++----------+---+
+| …字漢字  | x |
++----------+---+
++----------+---+
+| 漢字…字  | x |
++----------+---+
++----------+---+
+| 漢字漢字 | x |
+| 漢字...  |   |
++----------+---+";
+    assert_eq!(expected, "\n".to_string() + &tables.join("\n"));
+}
@@ -0,0 +1,72 @@
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+fn table(style: TruncationStyle) -> Table {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Path", "Size"])
+        .add_row(vec!["/home/alice/projects/comfy-table/Cargo.toml", "2 KB"])
+        .add_row(vec!["multiple\nlines", "1 KB"])
+        .add_row(vec!["short", "3 KB"]);
+    table
+        .column_mut(0)
+        .unwrap()
+        .set_constraint(ColumnConstraint::UpperBoundary(Width::Fixed(16)))
+        .set_truncation(style);
+
+    table
+}
+
+#[test]
+fn truncate_end() {
+    let table = table(TruncationStyle::End("...".to_string()));
+
+    println!("{table}");
+    let expected = "
++----------------+------+
+| Path           | Size |
++=======================+
+| /home/alice... | 2 KB |
+|----------------+------|
+| multiple lines | 1 KB |
+|----------------+------|
+| short          | 3 KB |
++----------------+------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn truncate_start() {
+    let table = table(TruncationStyle::Start("…".to_string()));
+
+    println!("{table}");
+    let expected = "
++----------------+------+
+| Path           | Size |
++=======================+
+| …le/Cargo.toml | 2 KB |
+|----------------+------|
+| multiple lines | 1 KB |
+|----------------+------|
+| short          | 3 KB |
++----------------+------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+#[test]
+fn truncate_middle() {
+    let table = table(TruncationStyle::Middle("…".to_string()));
+
+    println!("{table}");
+    let expected = "
++----------------+------+
+| Path           | Size |
++=======================+
+| /home/a…o.toml | 2 KB |
+|----------------+------|
+| multiple lines | 1 KB |
+|----------------+------|
+| short          | 3 KB |
++----------------+------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
@@ -107,3 +107,62 @@ fn zwj_utf8_word_splitting() {
     println!("{expected}");
     assert_eq!(expected, "\n".to_string() + &table.to_string());
 }
+
+/// A custom width table changes how wide characters are assumed to be.
+#[test]
+fn custom_width_table() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2"])
+        .add_row(vec!["✅✅✅✅✅✅", "text"]);
+
+    println!("{table}");
+    let expected = "
++--------------+---------+
+| Header1      | Header2 |
++========================+
+| ✅✅✅✅✅✅ | text    |
++--------------+---------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    // Assume that the terminal displays this emoji with a width of 1.
+    table.set_width_table(WidthTable::new().set_width('✅'..='✅', 1));
+
+    println!("{table}");
+    let expected = "
++---------+---------+
+| Header1 | Header2 |
++===================+
+| ✅✅✅✅✅✅  | text    |
++---------+---------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Emoji presentation sequences can be configured to be displayed with a width of 1.
+#[test]
+fn emoji_presentation_width() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1"])
+        .add_row(vec!["\u{2764}\u{FE0F}\u{2764}\u{FE0F}"]);
+
+    println!("{table}");
+    let expected = "
++---------+
+| Header1 |
++=========+
+| ❤️❤️    |
++---------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+
+    table.set_width_table(WidthTable::new().set_emoji_presentation_width(1));
+
+    println!("{table}");
+    let expected = "
++---------+
+| Header1 |
++=========+
+| ❤️❤️      |
++---------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
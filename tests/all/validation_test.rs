@@ -0,0 +1,47 @@
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+/// Only rows are validated. Spanning cells are validated by their first column.
+#[test]
+fn validation_errors() {
+    let mut invalid = Row::from(vec![Cell::new("web-3"), Cell::new("down").set_colspan(2)]);
+    invalid.set_source("hosts.csv:4");
+
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Host", "Port", "Status"])
+        .add_row(vec!["web-1", "443", "up"])
+        .add_row(vec!["web-2", "https", "up"])
+        .add_row(invalid)
+        .set_footer(vec!["Total", "none", "3"]);
+    table
+        .column_mut(1)
+        .unwrap()
+        .set_validator(|content| content.parse::<u16>().is_ok());
+
+    let errors = table.validation_errors();
+    assert_eq!(
+        errors,
+        vec![
+            ValidationError {
+                row: 1,
+                column: 1,
+                content: "https".to_string(),
+                source: None,
+            },
+            ValidationError {
+                row: 2,
+                column: 1,
+                content: "down".to_string(),
+                source: Some("hosts.csv:4".to_string()),
+            },
+        ]
+    );
+    assert_eq!(
+        errors[1].to_string(),
+        "Cell at row 2, column 1 (hosts.csv:4) has an invalid value: \"down\""
+    );
+
+    table.column_mut(1).unwrap().remove_validator();
+    assert!(table.validation_errors().is_empty());
+}
@@ -0,0 +1,100 @@
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+fn table() -> Table {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Team", "Points", "Games"])
+        .add_row(vec!["Lions", "9", "4"])
+        .add_row(vec!["Bears", "12", "5"])
+        .add_row(vec!["Eagles", "9", "3"])
+        .add_row(vec!["Wolves", "3", "4"]);
+
+    table
+}
+
+/// Later sort keys only decide between rows, which are equal for all previous keys.
+/// The table itself keeps its original order.
+#[test]
+fn sort_by_multiple_keys() {
+    let table = table();
+    let mut state = TableViewState::new();
+    state
+        .add_sort_key(1, SortOrder::Descending)
+        .add_sort_key(2, SortOrder::Ascending);
+
+    let view = table.with_view_state(&state);
+    println!("{view}");
+    let expected = "
++--------+--------+-------+
+| Team   | Points | Games |
++=========================+
+| Bears  | 12     | 5     |
+|--------+--------+-------|
+| Eagles | 9      | 3     |
+|--------+--------+-------|
+| Lions  | 9      | 4     |
+|--------+--------+-------|
+| Wolves | 3      | 4     |
++--------+--------+-------+";
+    assert_eq!(expected, "\n".to_string() + &view.to_string());
+    assert_eq!(table.cell_content(0, 0), Some("Lions".to_string()));
+}
+
+/// Rows before the scroll offset are skipped together with the raw lines between them.
+#[test]
+fn hide_columns_and_scroll() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Team", "Points", "Games"])
+        .add_raw_line("  -- season start --")
+        .add_row(vec!["Lions", "9", "4"])
+        .add_row(vec!["Bears", "12", "5"])
+        .add_raw_line("  -- relegation --")
+        .add_row(vec!["Wolves", "3", "4"]);
+
+    let mut state = TableViewState::new();
+    state.set_column_visible(2, false).set_scroll_offset(1);
+    assert!(!state.is_column_visible(2));
+
+    let view = table.with_view_state(&state);
+    println!("{view}");
+    let expected = "
++--------+--------+
+| Team   | Points |
++=================+
+| Bears  | 12     |
+|--------+--------|
+  -- relegation --
+| Wolves | 3      |
++--------+--------+";
+    assert_eq!(expected, "\n".to_string() + &view.to_string());
+
+    // Scrolling past the end only leaves the header.
+    state.set_scroll_offset(10).set_column_visible(2, true);
+    assert_eq!(table.with_view_state(&state).row_count(), 0);
+}
+
+/// View states are loaded from their JSON definition. Missing fields fall back to their defaults.
+#[cfg(feature = "serde")]
+#[test]
+fn view_state_from_json() {
+    let state: TableViewState = r#"{
+        "sort_keys": [[0, "Ascending"]],
+        "hidden_columns": [2],
+        "selected_row": 1
+    }"#
+    .parse()
+    .unwrap();
+
+    let mut expected = TableViewState::new();
+    expected
+        .add_sort_key(0, SortOrder::Ascending)
+        .set_column_visible(2, false)
+        .set_selected_row(1);
+    assert_eq!(state, expected);
+    assert_eq!(
+        "{}".parse::<TableViewState>().unwrap(),
+        TableViewState::new()
+    );
+}
@@ -0,0 +1,42 @@
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+/// Tables with ordinary content don't have any mismatches.
+/// Annotations, raw lines, the title and the caption aren't checked.
+#[test]
+fn audit_without_mismatches() {
+    let mut row = Row::from(vec!["Some content", "数据"]);
+    row.add_annotation("An annotation, which is wider than the table");
+
+    let mut table = Table::new();
+    table
+        .set_title("A title, which is wider than the table")
+        .set_header(vec!["Header1", "Header2"])
+        .add_row(row)
+        .add_raw_line("--- raw ---")
+        .add_row(vec!["Ünïcödé", "🦀"])
+        .set_caption("caption");
+
+    assert_eq!(table.audit_widths(), Vec::new());
+}
+
+/// Characters of the preset are expected to be a single column wide.
+/// Wider characters result in mismatches of all lines they're used in.
+#[test]
+fn audit_with_wide_border() {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Header1", "Header2"])
+        .add_row(vec!["One", "Two"])
+        .set_style(TableComponent::HeaderLines, '＝');
+
+    println!("{table}");
+    assert_eq!(
+        table.audit_widths(),
+        vec![WidthMismatch {
+            line: 2,
+            expected: 21,
+            actual: 39,
+        }]
+    );
+}
@@ -0,0 +1,98 @@
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+fn table(content: &str, policy: WrapPolicy) -> Table {
+    let mut table = Table::new();
+    table
+        .set_header(vec!["Name", "Address"])
+        .add_row(vec!["home", content])
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(26);
+    table.column_mut(1).unwrap().set_wrap_policy(policy);
+
+    table
+}
+
+/// Long words are split after the last break character that fits into the line.
+#[test]
+fn break_on() {
+    let table = table(
+        "2001:0db8:85a3:0000:0000:8a2e:0370:7334",
+        WrapPolicy::BreakOn(&[':']),
+    );
+
+    println!("{table}");
+    let expected = "
++------+-----------------+
+| Name | Address         |
++========================+
+| home | 2001:0db8:85a3: |
+|      | 0000:0000:8a2e: |
+|      | 0370:7334       |
++------+-----------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Lines are filled completely, ignoring the delimiter.
+#[test]
+fn break_anywhere() {
+    let table = table("a very long address", WrapPolicy::BreakAnywhere);
+
+    println!("{table}");
+    let expected = "
++------+-----------------+
+| Name | Address         |
++========================+
+| home | a very long add |
+|      | ress            |
++------+-----------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// The column is kept wide enough for its longest word.
+#[test]
+fn no_break() {
+    let mut table = table("at unbreakable", WrapPolicy::NoBreak);
+    table.add_row(vec!["Some longer name in the first column", "-"]);
+
+    println!("{table}");
+    let expected = "
++----------+-------------+
+| Name     | Address     |
++========================+
+| home     | at          |
+|          | unbreakable |
+|----------+-------------|
+| Some     | -           |
+| longer   |             |
+| name in  |             |
+| the      |             |
+| first    |             |
+| column   |             |
++----------+-------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
+
+/// Words are broken at soft hyphens, which are displayed as `-` at the end of the line.
+/// Soft hyphens, at which no word is broken, aren't displayed.
+#[test]
+fn soft_hyphens() {
+    let mut table = Table::new();
+    table.set_header(vec!["Setting", "Value"]).add_row(vec![
+        "max\u{AD}connection\u{AD}pool\u{AD}size",
+        "20 con\u{AD}nections",
+    ]);
+    table
+        .column_mut(0)
+        .unwrap()
+        .set_constraint(ColumnConstraint::Absolute(Width::Fixed(16)));
+
+    let expected = "
++----------------+----------------+
+| Setting        | Value          |
++=================================+
+| maxconnection- | 20 connections |
+| poolsize       |                |
++----------------+----------------+";
+    assert_eq!(expected, "\n".to_string() + &table.to_string());
+}
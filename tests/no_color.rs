@@ -0,0 +1,40 @@
+//! The `NO_COLOR` environment variable applies to the whole process.
+//! These tests run in their own binary, so they don't change the output of all other tests.
+#![cfg(feature = "tty")]
+
+use comfy_table::presets::NOTHING;
+use comfy_table::*;
+use pretty_assertions::assert_eq;
+
+/// Deterministic tables keep their colors, even if colors are disabled via `NO_COLOR`.
+/// All other tables lose their colors, which is reported by the style lints.
+#[test]
+fn deterministic_table_ignores_no_color() {
+    // Safety: This is the only test of this binary, so no other thread reads the environment.
+    unsafe { std::env::set_var("NO_COLOR", "1") };
+
+    let mut table = Table::new();
+    table
+        .load_preset(NOTHING)
+        .enforce_styling()
+        .add_row(vec![Cell::new("x").fg(Color::Red)]);
+
+    // Crossterm drops the colors of other tables.
+    assert_eq!("\u{1b}[m x \u{1b}[m", table.to_string());
+
+    table.set_deterministic(true);
+    assert_eq!("\u{1b}[38;5;9m x \u{1b}[39m", table.to_string());
+
+    table.add_row(vec![
+        Cell::new("y")
+            .bg(Color::Rgb { r: 1, g: 2, b: 3 })
+            .add_attribute(Attribute::Bold),
+    ]);
+    let expected = "\u{1b}[38;5;9m x \u{1b}[39m\n\u{1b}[48;2;1;2;3m\u{1b}[1m y \u{1b}[0m";
+    assert_eq!(expected, table.to_string());
+
+    // Only tables, whose colors are actually dropped, are linted.
+    assert!(table.lint_styles().is_empty());
+    table.set_deterministic(false);
+    assert_eq!(table.lint_styles(), vec![StyleWarning::ColorsDisabled]);
+}